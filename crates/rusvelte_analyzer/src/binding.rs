@@ -136,6 +136,10 @@ impl Binding {
         self.kind
     }
 
+    pub(crate) fn set_kind(&mut self, kind: BindingKind) {
+        self.kind = kind;
+    }
+
     pub fn is_init_by_state(&self) -> bool {
         self.kind == BindingKind::State
     }
@@ -151,6 +155,11 @@ impl Binding {
     pub fn declaration_kind(&self) -> DeclarationKind {
         self.declaration_kind
     }
+
+    /// Whether the binding always refers to the function it declares
+    pub fn is_function(&self) -> bool {
+        self.declaration_kind == DeclarationKind::Function && !self.binding_flags.is_reassigned()
+    }
 }
 
 #[derive(Debug, Default)]
@@ -187,4 +196,8 @@ impl BindingTable {
     pub fn get_name(&self, symbol_id: SymbolId) -> &str {
         &self.declarations[symbol_id].name
     }
+
+    pub fn iter(&self) -> impl Iterator<Item = (SymbolId, &Binding)> {
+        self.declarations.iter_enumerated()
+    }
 }
@@ -0,0 +1,64 @@
+use oxc_span::Span;
+
+#[derive(thiserror::Error, Clone)]
+pub struct AnalyzerError {
+    pub kind: AnalyzerErrorKind,
+    pub span: Span,
+}
+
+impl AnalyzerError {
+    pub fn new(span: Span, kind: AnalyzerErrorKind) -> Self {
+        Self { span, kind }
+    }
+}
+
+impl std::fmt::Debug for AnalyzerError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "[{}, {}]: {}", self.span.start, self.span.end, self.kind)
+    }
+}
+
+impl std::fmt::Display for AnalyzerError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "Error[{}, {}]: {}",
+            self.span.start, self.span.end, self.kind
+        )
+    }
+}
+
+#[derive(Debug, thiserror::Error, Clone)]
+pub enum AnalyzerErrorKind {
+    // From svelte
+    #[error("`$effect()` can only be used as an expression statement")]
+    EffectInvalidPlacement,
+    #[error("`{rune}` must be called with {args}")]
+    RuneInvalidArgumentsLength {
+        rune: &'static str,
+        args: &'static str,
+    },
+    #[error("Cannot pass arguments to `{0}`")]
+    RuneInvalidArguments(&'static str),
+    #[error("`$props()` assignment must not contain nested properties or computed keys")]
+    PropsInvalidPattern,
+    #[error("Can only bind to an Identifier or MemberExpression")]
+    BindInvalidExpression,
+    #[error("`bind:{0}` is not a valid binding")]
+    BindInvalidName(String),
+    #[error("`bind:{name}` can only be used with {elements}")]
+    BindInvalidTarget { name: String, elements: String },
+    #[error("Can only bind to state or props")]
+    BindInvalidValue,
+    #[error("Cannot bind to constant")]
+    ConstantBinding,
+    #[error("An element can only have one 'animate' directive")]
+    AnimationDuplicate,
+    #[error("An element that uses the `animate:` directive must be the only child of a keyed `{{#each ...}}` block")]
+    AnimationInvalidPlacement,
+    #[error("An element that uses the `animate:` directive must be the immediate child of a keyed each block")]
+    AnimationMissingKey,
+    // Not supported yet
+    #[error("`let:` directives are not supported, pass a snippet to the component instead")]
+    LetDirectiveUnsupported,
+}
@@ -1,4 +1,5 @@
 use binding::{Binding, BindingTable};
+use error::AnalyzerError;
 use node::AstNodes;
 use oxc_index::Idx;
 use reference::ReferenceTable;
@@ -7,20 +8,26 @@ use rusvelte_ast::{
     ast_kind::{AstKind, SvelteAstKind},
     visit::Visit,
 };
+use rusvelte_utils::hash::hash;
 use scope::{scope_builder::ScopeBuilderReturn, ScopeTable};
 
+pub use options::{CompileOptions, CssHashArgs, CssHashGetter, CssMode, Generate, Namespace};
 pub use oxc_syntax::{
     node::NodeId,
+    reference::ReferenceId,
     scope::{ScopeFlags, ScopeId},
     symbol::{SymbolFlags, SymbolId},
 };
 use state::State;
 
 pub mod binding;
+pub mod error;
 pub mod node;
 pub mod reference;
+pub mod rune;
 pub mod scope;
 
+mod options;
 mod state;
 mod visit_js;
 mod visit_svelte;
@@ -31,17 +38,16 @@ pub struct Analysis<'a> {
     pub references: ReferenceTable,
     pub nodes: AstNodes<'a>,
     pub used_event_attribute: bool,
-}
-
-#[derive(Debug)]
-pub struct CompileOptions {
-    pub component_name: String,
-}
-
-impl CompileOptions {
-    pub fn new(component_name: String) -> Self {
-        Self { component_name }
-    }
+    /// The compile options merged with the ones declared by `<svelte:options>`
+    pub options: CompileOptions,
+    /// The name of the component function
+    pub name: String,
+    /// The class name used to scope the styles, empty if the component doesn't have a `<style>` element
+    pub css_hash: String,
+    pub errors: Vec<AnalyzerError>,
+    /// Whether the component is compiled in runes mode, either forced by the options or
+    /// inferred from its use of runes
+    pub runes: bool,
 }
 
 #[derive(Debug)]
@@ -49,7 +55,6 @@ pub struct Analyzer<'a> {
     current_scope_id: ScopeId,
     current_node_id: NodeId,
     next_node_id: NodeId,
-    #[allow(dead_code)]
     compile_options: CompileOptions,
     state: State,
     scopes: ScopeTable,
@@ -58,16 +63,22 @@ pub struct Analyzer<'a> {
     #[allow(dead_code)]
     references: ReferenceTable,
     use_event_attribute: bool,
+    errors: Vec<AnalyzerError>,
+    runes: bool,
 }
 
 impl<'a> Analyzer<'a> {
-    pub fn new(compile_options: CompileOptions, root: &Root<'a>) -> Self {
+    pub fn new(mut compile_options: CompileOptions, root: &Root<'a>) -> Self {
+        if let Some(svelte_options) = &root.options {
+            compile_options.merge_svelte_options(svelte_options);
+        }
         let ScopeBuilderReturn {
             scopes,
             nodes,
             binding_table,
             reference_table,
-        } = scope::scope_builder::ScopeBuilder::default().build(root);
+            runes,
+        } = scope::scope_builder::ScopeBuilder::new(compile_options.runes).build(root);
         Self {
             compile_options,
             state: State::default(),
@@ -79,6 +90,8 @@ impl<'a> Analyzer<'a> {
             current_node_id: NodeId::new(0),
             next_node_id: NodeId::new(1),
             use_event_attribute: false,
+            errors: vec![],
+            runes,
         }
     }
 
@@ -88,14 +101,38 @@ impl<'a> Analyzer<'a> {
             nodes,
             binding_table: symbols,
             reference_table: references,
-        } = scope::scope_builder::ScopeBuilder::default().build(root);
+            ..
+        } = scope::scope_builder::ScopeBuilder::new(self.compile_options.runes).build(root);
         self.visit_root(root);
+        let name = self.compile_options.name();
+        let css_hash = root
+            .css
+            .as_ref()
+            .map(|css| {
+                let filename = self.compile_options.filename.as_deref();
+                let args = CssHashArgs {
+                    name: &name,
+                    filename,
+                    css: css.content.styles,
+                    hash,
+                };
+                match self.compile_options.css_hash {
+                    Some(css_hash) => css_hash(args),
+                    None => format!("svelte-{}", hash(args.css)),
+                }
+            })
+            .unwrap_or_default();
         Analysis {
             scopes,
             nodes,
             symbols,
             references,
             used_event_attribute: self.use_event_attribute,
+            options: self.compile_options,
+            name,
+            css_hash,
+            errors: self.errors,
+            runes: self.runes,
         }
     }
 
@@ -0,0 +1,172 @@
+use rusvelte_ast::ast::SvelteOptions;
+
+/// Which kind of JavaScript output the compiler should emit.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum Generate {
+    /// Emit code that is designed to run in the browser.
+    #[default]
+    Client,
+    /// Emit code that is suitable for server-side rendering.
+    Server,
+    /// Don't emit any JavaScript, useful when only the warnings are needed.
+    False,
+}
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum CssMode {
+    /// The styles are included in the `head` when the component is mounted.
+    Injected,
+    /// The styles are returned in the compile result so that they can be statically extracted.
+    #[default]
+    External,
+}
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum Namespace {
+    #[default]
+    Html,
+    Svg,
+    Mathml,
+}
+
+impl Namespace {
+    pub fn from_name(value: &str) -> Option<Self> {
+        match value {
+            "html" => Some(Self::Html),
+            "svg" => Some(Self::Svg),
+            "mathml" => Some(Self::Mathml),
+            _ => None,
+        }
+    }
+}
+
+/// The arguments passed to [`CompileOptions::css_hash`].
+#[derive(Debug, Clone, Copy)]
+pub struct CssHashArgs<'s> {
+    pub name: &'s str,
+    pub filename: Option<&'s str>,
+    pub css: &'s str,
+    /// The hash function used by the default implementation.
+    pub hash: fn(&str) -> String,
+}
+
+pub type CssHashGetter = fn(CssHashArgs<'_>) -> String;
+
+#[derive(Debug, Clone)]
+pub struct CompileOptions {
+    /// The name of the component function. If empty, it is inferred from `filename`.
+    pub component_name: String,
+    /// Used for debugging hints and sourcemaps.
+    pub filename: Option<String>,
+    /// Used to make sure that `filename` doesn't leak filesystem info.
+    pub root_dir: Option<String>,
+    pub generate: Generate,
+    /// Adds extra code for runtime checks and debugging information.
+    pub dev: bool,
+    /// Makes the component hot-reloadable, only applied when `generate` is [`Generate::Client`].
+    pub hmr: bool,
+    pub css: CssMode,
+    /// Generates the scoping class name from the styles. Defaults to `svelte-{hash(css)}`.
+    pub css_hash: Option<CssHashGetter>,
+    /// The namespace of the component, can be overridden by `<svelte:options namespace>`.
+    pub namespace: Namespace,
+    /// Forces runes mode on or off. If `None`, it is inferred from the component code.
+    pub runes: Option<bool>,
+    /// Keeps the HTML comments in the output.
+    pub preserve_comments: bool,
+    /// Keeps the whitespace as typed instead of collapsing it.
+    pub preserve_whitespace: bool,
+    /// Imports `svelte/internal/disclose-version` so that the running Svelte version is exposed on `window`.
+    pub disclose_version: bool,
+    /// Compiles the component to a custom element.
+    pub custom_element: bool,
+}
+
+impl Default for CompileOptions {
+    fn default() -> Self {
+        Self {
+            component_name: String::new(),
+            filename: None,
+            root_dir: None,
+            generate: Generate::default(),
+            dev: false,
+            hmr: false,
+            css: CssMode::default(),
+            css_hash: None,
+            namespace: Namespace::default(),
+            runes: None,
+            preserve_comments: false,
+            preserve_whitespace: false,
+            disclose_version: true,
+            custom_element: false,
+        }
+    }
+}
+
+impl CompileOptions {
+    pub fn new(component_name: String) -> Self {
+        Self {
+            component_name,
+            ..Default::default()
+        }
+    }
+
+    /// Applies the options declared by `<svelte:options>`, which take precedence over the compile options.
+    pub fn merge_svelte_options(&mut self, svelte_options: &SvelteOptions) {
+        if let Some(runes) = svelte_options.runes {
+            self.runes = Some(runes);
+        }
+        if let Some(namespace) = svelte_options.namespace.and_then(Namespace::from_name) {
+            self.namespace = namespace;
+        }
+        if svelte_options.css == Some("injected") {
+            self.css = CssMode::Injected;
+        }
+        if let Some(preserve_whitespace) = svelte_options.preserve_whitespace {
+            self.preserve_whitespace = preserve_whitespace;
+        }
+    }
+
+    /// The filename relative to `root_dir`, with forward slashes as path separators.
+    pub fn relative_filename(&self) -> Option<String> {
+        let filename = self.filename.as_ref()?.replace('\\', "/");
+        let Some(root_dir) = &self.root_dir else {
+            return Some(filename);
+        };
+        let root_dir = root_dir.replace('\\', "/");
+        let root_dir = root_dir.trim_end_matches('/');
+        if let Some(relative) = filename
+            .strip_prefix(root_dir)
+            .and_then(|relative| relative.strip_prefix('/'))
+        {
+            return Some(relative.to_string());
+        }
+        Some(filename)
+    }
+
+    /// The component name, inferred from `filename` if `component_name` is empty.
+    pub fn name(&self) -> String {
+        if !self.component_name.is_empty() {
+            return self.component_name.clone();
+        }
+        let Some(filename) = &self.filename else {
+            return "Component".to_string();
+        };
+
+        let mut parts = filename.split(['/', '\\']).collect::<Vec<_>>();
+        let basename = parts.pop().unwrap_or_default();
+        let last_dir = parts.last().copied().filter(|dir| !dir.is_empty());
+        let mut name = basename.replacen(".svelte", "", 1);
+        if let Some(last_dir) = last_dir {
+            if name == "index" && last_dir != "src" {
+                name = last_dir.to_string();
+            }
+        }
+
+        let mut chars = name.chars();
+        match chars.next() {
+            Some(first) => first.to_uppercase().chain(chars).collect(),
+            None => "Component".to_string(),
+        }
+    }
+}
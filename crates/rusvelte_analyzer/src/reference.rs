@@ -88,6 +88,10 @@ impl ReferenceTable {
         &self.references[reference_id]
     }
 
+    pub fn iter(&self) -> impl Iterator<Item = &Reference> {
+        self.references.iter()
+    }
+
     pub fn unresolved_references(&self) -> impl Iterator<Item = &Reference> {
         self.references
             .iter()
@@ -0,0 +1,93 @@
+use rusvelte_ast::js_ast::{CallExpression, Expression};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Rune {
+    State,
+    StateRaw,
+    StateSnapshot,
+    Props,
+    Bindable,
+    Derived,
+    DerivedBy,
+    Effect,
+    EffectPre,
+    EffectTracking,
+    EffectRoot,
+    Inspect,
+    InspectWith,
+    InspectTrace,
+    Host,
+}
+
+impl Rune {
+    pub fn from_name(name: &str) -> Option<Self> {
+        let rune = match name {
+            "$state" => Self::State,
+            "$state.raw" => Self::StateRaw,
+            "$state.snapshot" => Self::StateSnapshot,
+            "$props" => Self::Props,
+            "$bindable" => Self::Bindable,
+            "$derived" => Self::Derived,
+            "$derived.by" => Self::DerivedBy,
+            "$effect" => Self::Effect,
+            "$effect.pre" => Self::EffectPre,
+            "$effect.tracking" => Self::EffectTracking,
+            "$effect.root" => Self::EffectRoot,
+            "$inspect" => Self::Inspect,
+            "$inspect().with" => Self::InspectWith,
+            "$inspect.trace" => Self::InspectTrace,
+            "$host" => Self::Host,
+            _ => return None,
+        };
+        Some(rune)
+    }
+
+    pub fn name(self) -> &'static str {
+        match self {
+            Self::State => "$state",
+            Self::StateRaw => "$state.raw",
+            Self::StateSnapshot => "$state.snapshot",
+            Self::Props => "$props",
+            Self::Bindable => "$bindable",
+            Self::Derived => "$derived",
+            Self::DerivedBy => "$derived.by",
+            Self::Effect => "$effect",
+            Self::EffectPre => "$effect.pre",
+            Self::EffectTracking => "$effect.tracking",
+            Self::EffectRoot => "$effect.root",
+            Self::Inspect => "$inspect",
+            Self::InspectWith => "$inspect().with",
+            Self::InspectTrace => "$inspect.trace",
+            Self::Host => "$host",
+        }
+    }
+}
+
+/// Returns the rune called by the expression, e.g. `$derived.by` for `$derived.by(() => count * 2)`
+pub fn get_rune(call: &CallExpression) -> Option<Rune> {
+    let name = match &call.callee {
+        Expression::Identifier(ident) => ident.name.to_string(),
+        Expression::StaticMemberExpression(member) => match &member.object {
+            Expression::Identifier(object) => format!("{}.{}", object.name, member.property.name),
+            // `$inspect(value).with(callback)`
+            Expression::CallExpression(object) => match &object.callee {
+                Expression::Identifier(ident) => {
+                    format!("{}().{}", ident.name, member.property.name)
+                }
+                _ => return None,
+            },
+            _ => return None,
+        },
+        _ => return None,
+    };
+    Rune::from_name(&name)
+}
+
+/// Same as [`get_rune`] but for any expression
+pub fn get_expression_rune(expr: &Expression) -> Option<Rune> {
+    if let Expression::CallExpression(call) = expr {
+        get_rune(call)
+    } else {
+        None
+    }
+}
@@ -1,10 +1,16 @@
 use oxc_ecmascript::BoundNames;
-use rusvelte_ast::js_ast::{
-    BindingRestElement, CatchParameter, Class, FormalParameter, Function, ImportDeclaration,
-    VariableDeclarator,
+use rusvelte_ast::{
+    ast::{EachBlock, SnippetBlock},
+    js_ast::{
+        BindingPattern, BindingPatternKind, BindingRestElement, CatchParameter, Class,
+        FormalParameter, Function, ImportDeclaration, VariableDeclarator,
+    },
 };
 
-use crate::binding::{BindingKind, DeclarationKind};
+use crate::{
+    binding::{BindingKind, DeclarationKind},
+    rune::{get_expression_rune, Rune},
+};
 
 use super::scope_builder::ScopeBuilder;
 
@@ -22,6 +28,41 @@ impl<'a> Binder<'a> for VariableDeclarator<'a> {
     }
 }
 
+/// `let { a, b = $bindable(), ...rest } = $props()` declares each property with its own kind,
+/// the kind passed to `bind` is ignored.
+pub(super) struct PropsDeclarator<'r, 'a>(pub &'r VariableDeclarator<'a>);
+
+impl<'a> Binder<'a> for PropsDeclarator<'_, 'a> {
+    fn bind(&self, builder: &mut ScopeBuilder<'a>, _kind: BindingKind) {
+        let declarator = self.0;
+        let mut declare = |pattern: &BindingPattern<'a>, kind: BindingKind| {
+            pattern.bound_names(&mut |ident| {
+                let symbol_id = builder.declare(&ident.name, kind, declarator.kind.into());
+                ident.symbol_id.set(Some(symbol_id));
+            });
+        };
+        let BindingPatternKind::ObjectPattern(pattern) = &declarator.id.kind else {
+            // `let props = $props()`
+            declare(&declarator.id, BindingKind::RestProp);
+            return;
+        };
+        for property in pattern.properties.iter() {
+            let kind = match &property.value.kind {
+                BindingPatternKind::AssignmentPattern(assignment)
+                    if get_expression_rune(&assignment.right) == Some(Rune::Bindable) =>
+                {
+                    BindingKind::BindableProp
+                }
+                _ => BindingKind::Prop,
+            };
+            declare(&property.value, kind);
+        }
+        if let Some(rest) = &pattern.rest {
+            declare(&rest.argument, BindingKind::RestProp);
+        }
+    }
+}
+
 impl<'a> Binder<'a> for Function<'a> {
     fn bind(&self, builder: &mut ScopeBuilder<'a>, kind: BindingKind) {
         if let Some(ident) = &self.id {
@@ -76,3 +117,44 @@ impl<'a> Binder<'a> for Class<'a> {
         ident.symbol_id.set(Some(symbol_id));
     }
 }
+
+/// The `then` value and `catch` error of an `{#await}` block
+impl<'a> Binder<'a> for BindingPattern<'a> {
+    fn bind(&self, builder: &mut ScopeBuilder<'a>, kind: BindingKind) {
+        self.bound_names(&mut |ident| {
+            let symbol_id = builder.declare(&ident.name, kind, DeclarationKind::Const);
+            ident.symbol_id.set(Some(symbol_id));
+        });
+    }
+}
+
+impl<'a> Binder<'a> for EachBlock<'a> {
+    fn bind(&self, builder: &mut ScopeBuilder<'a>, kind: BindingKind) {
+        self.context.bound_names(&mut |ident| {
+            let symbol_id = builder.declare(&ident.name, kind, DeclarationKind::Const);
+            ident.symbol_id.set(Some(symbol_id));
+        });
+        if !matches!(self.context.kind, BindingPatternKind::BindingIdentifier(_)) {
+            builder.declare("$$item", BindingKind::Template, DeclarationKind::Synthetic);
+        }
+        if let Some(index) = self.index {
+            let kind = if self.is_keyed() {
+                BindingKind::Template
+            } else {
+                BindingKind::Normal
+            };
+            builder.declare(index, kind, DeclarationKind::Const);
+        }
+    }
+}
+
+impl<'a> Binder<'a> for SnippetBlock<'a> {
+    fn bind(&self, builder: &mut ScopeBuilder<'a>, kind: BindingKind) {
+        for parameter in self.parameters.iter() {
+            parameter.bound_names(&mut |ident| {
+                let symbol_id = builder.declare(&ident.name, kind, DeclarationKind::Let);
+                ident.symbol_id.set(Some(symbol_id));
+            });
+        }
+    }
+}
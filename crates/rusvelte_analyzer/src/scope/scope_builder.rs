@@ -1,18 +1,23 @@
 use std::cell::Cell;
+use std::collections::HashSet;
+
+use oxc_ecmascript::BoundNames;
 
 use oxc_span::CompactStr;
 use oxc_syntax::reference::ReferenceId;
 use oxc_syntax::symbol::SymbolId;
 use oxc_syntax::{node::NodeId, scope::ScopeId};
 use rusvelte_ast::ast::Root;
-use rusvelte_ast::ast_kind::AstKind;
+use rusvelte_ast::ast_kind::{AstKind, JsAstKind, SvelteAstKind};
+use rusvelte_ast::js_ast::{Declaration, Expression, Program, Statement, VariableDeclarationKind};
 use rusvelte_ast::traits::extract_identifier::ExtractIdentifier;
-use rusvelte_ast::traits::unwrap_pattern::UnwrapPattern;
+use rusvelte_ast::traits::unwrap_pattern::{UnwrapPattern, UnwrapPatternItem};
 use rusvelte_ast::visit::Visit;
 
 use crate::binding::{BindingFlags, BindingKind, BindingTable, DeclarationKind};
 use crate::node::AstNodes;
 use crate::reference::ReferenceTable;
+use crate::rune::Rune;
 
 use super::ScopeTable;
 
@@ -24,6 +29,8 @@ pub struct ScopeBuilder<'a> {
     pub current_node_id: NodeId,
     pub current_scope_id: ScopeId,
     pub updates: Vec<(ScopeId, BindingFlags, ReferenceId)>,
+    /// Forces runes mode on or off, inferred from the references to runes when `None`
+    pub runes: Option<bool>,
 }
 
 impl Default for ScopeBuilder<'_> {
@@ -39,6 +46,7 @@ impl Default for ScopeBuilder<'_> {
             current_node_id: NodeId::new(0),
             current_scope_id,
             updates: Vec::new(),
+            runes: None,
         }
     }
 }
@@ -48,11 +56,30 @@ pub struct ScopeBuilderReturn<'a> {
     pub nodes: AstNodes<'a>,
     pub binding_table: BindingTable,
     pub reference_table: ReferenceTable,
+    /// Whether the component is compiled in runes mode
+    pub runes: bool,
 }
 
 impl<'a> ScopeBuilder<'a> {
+    pub fn new(runes: Option<bool>) -> Self {
+        Self {
+            runes,
+            ..Self::default()
+        }
+    }
+
     pub fn build(mut self, root: &Root<'a>) -> ScopeBuilderReturn<'a> {
         self.visit_root(root);
+        // the runes are never declared, so they are the unresolved references named after a rune
+        let runes = self.runes.unwrap_or_else(|| {
+            self.reference_table
+                .unresolved_references()
+                .any(|reference| Rune::from_name(reference.name()).is_some())
+        });
+        if let (false, Some(instance)) = (runes, &root.instance) {
+            self.declare_legacy_reactive(&instance.content);
+        }
+        self.declare_store_subscriptions();
 
         for reference in self.reference_table.unresolved_references_mut() {
             let symbol_id = self
@@ -72,11 +99,160 @@ impl<'a> ScopeBuilder<'a> {
             }
         }
 
+        if !runes {
+            if let Some(instance) = &root.instance {
+                self.declare_legacy_props(&instance.content);
+            }
+            self.declare_legacy_state();
+        }
+
         ScopeBuilderReturn {
             scopes: self.scopes,
             nodes: self.nodes,
             binding_table: self.binding_table,
             reference_table: self.reference_table,
+            runes,
+        }
+    }
+
+    /// `$: doubled = count * 2` implicitly declares `doubled` when the component doesn't
+    fn declare_legacy_reactive(&mut self, program: &Program<'a>) {
+        let root_scope_id = self.scopes.root_scope_id();
+        for statement in program.body.iter() {
+            let Statement::LabeledStatement(labeled) = statement else {
+                continue;
+            };
+            if labeled.label.name != "$" {
+                continue;
+            }
+            let Statement::ExpressionStatement(body) = &labeled.body else {
+                continue;
+            };
+            let Expression::AssignmentExpression(assignment) = &body.expression else {
+                continue;
+            };
+            for item in assignment.left.unwrap_pattern() {
+                let UnwrapPatternItem::IdentifierReference(ident) = item else {
+                    continue;
+                };
+                if ident.name.starts_with('$')
+                    || self.scopes.bindings[root_scope_id].contains_key(ident.name.as_str())
+                {
+                    continue;
+                }
+                self.declare_in_scope(
+                    &ident.name,
+                    BindingKind::LegacyReactive,
+                    DeclarationKind::Let,
+                    root_scope_id,
+                );
+            }
+        }
+    }
+
+    /// Outside of runes mode, the `export let` declarations of the instance script are the
+    /// props of the component
+    fn declare_legacy_props(&mut self, program: &Program<'a>) {
+        let root_scope_id = self.scopes.root_scope_id();
+        for statement in program.body.iter() {
+            let Statement::ExportNamedDeclaration(export) = statement else {
+                continue;
+            };
+            let Some(Declaration::VariableDeclaration(declaration)) = &export.declaration else {
+                continue;
+            };
+            if declaration.kind == VariableDeclarationKind::Const {
+                continue;
+            }
+            declaration.bound_names(&mut |ident| {
+                if let Some(symbol_id) =
+                    self.scopes.bindings[root_scope_id].get(ident.name.as_str())
+                {
+                    self.binding_table
+                        .get_binding_mut(*symbol_id)
+                        .set_kind(BindingKind::BindableProp);
+                }
+            });
+        }
+    }
+
+    /// Outside of runes mode, the top level `let`s which are updated and read by the template or
+    /// a `$:` statement are implicitly reactive
+    fn declare_legacy_state(&mut self) {
+        let root_scope_id = self.scopes.root_scope_id();
+        let reactive_symbols: HashSet<SymbolId> = self
+            .reference_table
+            .iter()
+            .filter(|reference| self.is_reactive_reference(reference.node_id()))
+            .filter_map(|reference| reference.symbol_id())
+            .collect();
+        let symbol_ids: Vec<SymbolId> = self.scopes.bindings[root_scope_id]
+            .values()
+            .copied()
+            .filter(|symbol_id| reactive_symbols.contains(symbol_id))
+            .collect();
+        for symbol_id in symbol_ids {
+            let binding = self.binding_table.get_binding_mut(symbol_id);
+            if binding.kind() == BindingKind::Normal
+                && matches!(
+                    binding.declaration_kind(),
+                    DeclarationKind::Let | DeclarationKind::Var
+                )
+                && binding.binding_flags.is_updated()
+            {
+                binding.set_kind(BindingKind::State);
+            }
+        }
+    }
+
+    /// Whether the reference is read by the template or a `$:` statement, outside of a function
+    fn is_reactive_reference(&self, node_id: NodeId) -> bool {
+        for ancestor_id in self.nodes.ancestors(node_id) {
+            match self.nodes.node(ancestor_id).kind {
+                AstKind::Js(JsAstKind::Function(_) | JsAstKind::ArrowFunctionExpression(_)) => {
+                    return false
+                }
+                AstKind::Js(JsAstKind::LabeledStatement(labeled)) if labeled.label.name == "$" => {
+                    return true
+                }
+                AstKind::Svelte(SvelteAstKind::Script(_)) => return false,
+                AstKind::Svelte(SvelteAstKind::Fragment(_)) => return true,
+                _ => {}
+            }
+        }
+        false
+    }
+
+    /// Declares `$count` when a component references it while `count` is declared at its top
+    /// level, reading `$count` then subscribes to the `count` store.
+    fn declare_store_subscriptions(&mut self) {
+        let root_scope_id = self.scopes.root_scope_id();
+        let names: Vec<CompactStr> = self
+            .reference_table
+            .unresolved_references()
+            .filter(|reference| {
+                let name = reference.name();
+                // `$$props`, `$$slots` and the runes aren't store subscriptions
+                let Some(store_name) = name.strip_prefix('$') else {
+                    return false;
+                };
+                !store_name.is_empty()
+                    && !store_name.starts_with('$')
+                    && Rune::from_name(name).is_none()
+                    && self.scopes.bindings[root_scope_id].contains_key(store_name)
+            })
+            .map(|reference| reference.name().into())
+            .collect();
+        for name in names {
+            // referenced more than once
+            if !self.scopes.bindings[root_scope_id].contains_key(&name) {
+                self.declare_in_scope(
+                    &name,
+                    BindingKind::StoreSub,
+                    DeclarationKind::Synthetic,
+                    root_scope_id,
+                );
+            }
         }
     }
 
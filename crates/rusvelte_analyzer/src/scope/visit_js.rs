@@ -6,9 +6,15 @@ use rusvelte_ast::{
     visit::JsVisit,
 };
 
-use crate::binding::BindingKind;
+use crate::{
+    binding::BindingKind,
+    rune::{get_expression_rune, Rune},
+};
 
-use super::{binder::Binder, scope_builder::ScopeBuilder};
+use super::{
+    binder::{Binder, PropsDeclarator},
+    scope_builder::ScopeBuilder,
+};
 
 impl<'a> JsVisit<'a> for ScopeBuilder<'a> {
     fn enter_node(&mut self, kind: JsAstKind<'a>) {
@@ -36,19 +42,17 @@ impl<'a> JsVisit<'a> for ScopeBuilder<'a> {
                         Some(is_const_tag)
                     })
                     .unwrap_or(false);
-                let binding_kind = decl.init.as_ref().map_or(BindingKind::Normal, |expr| {
-                    if let Expression::CallExpression(expr) = expr {
-                        if expr.callee_name().map_or(false, |name| name == "$state") {
-                            BindingKind::State
-                        } else {
-                            BindingKind::Normal
-                        }
-                    } else if is_in_const_tag {
-                        BindingKind::Template
-                    } else {
-                        BindingKind::Normal
+                let binding_kind = match decl.init.as_ref().and_then(get_expression_rune) {
+                    Some(Rune::State) => BindingKind::State,
+                    Some(Rune::StateRaw) => BindingKind::RawState,
+                    Some(Rune::Derived | Rune::DerivedBy) => BindingKind::Derived,
+                    Some(Rune::Props) => {
+                        PropsDeclarator(decl).bind(self, BindingKind::Prop);
+                        return;
                     }
-                });
+                    _ if is_in_const_tag => BindingKind::Template,
+                    _ => BindingKind::Normal,
+                };
                 decl.bind(self, binding_kind);
             }
             JsAstKind::ImportDeclaration(decl) => {
@@ -58,7 +62,16 @@ impl<'a> JsVisit<'a> for ScopeBuilder<'a> {
                 param.bind(self, BindingKind::Normal);
             }
             JsAstKind::BindingRestElement(rest) => {
-                rest.bind(self, BindingKind::Normal);
+                // the rest elements of destructured declarations are bound with their declarator
+                let is_rest_parameter =
+                    self.nodes
+                        .parent_node(self.current_node_id)
+                        .is_some_and(|parent| {
+                            matches!(parent.kind, AstKind::Js(JsAstKind::FormalParameters(_)))
+                        });
+                if is_rest_parameter {
+                    rest.bind(self, BindingKind::Normal);
+                }
             }
             JsAstKind::Class(it) => {
                 it.bind(self, BindingKind::Normal);
@@ -1,10 +1,13 @@
+use oxc_syntax::scope::ScopeFlags;
 use rusvelte_ast::{
     ast::*,
     ast_kind::{AstKind, SvelteAstKind},
     visit::{walk::*, JsVisit, Visit},
 };
 
-use super::scope_builder::ScopeBuilder;
+use crate::binding::{BindingKind, DeclarationKind};
+
+use super::{binder::Binder, scope_builder::ScopeBuilder};
 
 impl<'a> Visit<'a> for ScopeBuilder<'a> {
     fn enter_svelte_node(&mut self, kind: SvelteAstKind<'a>) {
@@ -20,6 +23,10 @@ impl<'a> Visit<'a> for ScopeBuilder<'a> {
 
         self.current_node_id = self.nodes.add_root_node(kind, self.current_scope_id);
         self.current_scope_id = self.scopes.add_scope(None, self.current_node_id, false);
+        // keep the order of `walk_root` so that the node ids match in the analyzer
+        if let Some(options) = &root.options {
+            self.visit_svelte_options(options);
+        }
         if let Some(module) = &root.module {
             self.visit_script(module);
         }
@@ -28,6 +35,9 @@ impl<'a> Visit<'a> for ScopeBuilder<'a> {
         }
 
         self.visit_fragment(&root.fragment);
+        if let Some(css) = &root.css {
+            self.visit_style_sheet(css);
+        }
 
         self.leave_svelte_node(kind);
     }
@@ -37,11 +47,81 @@ impl<'a> Visit<'a> for ScopeBuilder<'a> {
         self.extend_updates(&directive.expression);
     }
 
+    fn visit_each_block(&mut self, it: &EachBlock<'a>) {
+        let kind = SvelteAstKind::EachBlock(self.alloc(it));
+        self.enter_svelte_node(kind);
+        self.visit_expression(&it.expression);
+        // the context, the index and the key live in their own scope
+        self.enter_scope(ScopeFlags::empty(), &it.scope_id);
+        it.bind(self, BindingKind::Each);
+        self.visit_binding_pattern(&it.context);
+        if let Some(key) = &it.key {
+            self.visit_expression(key);
+        }
+        self.visit_fragment(&it.body);
+        self.leave_scope();
+        if let Some(fallback) = &it.fallback {
+            self.visit_fragment(fallback);
+        }
+        self.leave_svelte_node(kind);
+    }
+
+    fn visit_await_block(&mut self, it: &AwaitBlock<'a>) {
+        let kind = SvelteAstKind::AwaitBlock(self.alloc(it));
+        self.enter_svelte_node(kind);
+        self.visit_expression(&it.expression);
+        if let Some(pending) = &it.pending {
+            self.visit_fragment(pending);
+        }
+        self.enter_scope(ScopeFlags::empty(), &it.then_scope_id);
+        if let Some(value) = &it.value {
+            value.bind(self, BindingKind::Template);
+            self.visit_binding_pattern(value);
+        }
+        if let Some(then) = &it.then {
+            self.visit_fragment(then);
+        }
+        self.leave_scope();
+        self.enter_scope(ScopeFlags::empty(), &it.catch_scope_id);
+        if let Some(error) = &it.error {
+            error.bind(self, BindingKind::Template);
+            self.visit_binding_pattern(error);
+        }
+        if let Some(catch) = &it.catch {
+            self.visit_fragment(catch);
+        }
+        self.leave_scope();
+        self.leave_svelte_node(kind);
+    }
+
+    fn visit_snippet_block(&mut self, it: &SnippetBlock<'a>) {
+        let kind = SvelteAstKind::SnippetBlock(self.alloc(it));
+        self.enter_svelte_node(kind);
+        // the snippet itself is visible to the whole fragment it is declared in
+        let symbol_id = self.declare(
+            &it.expression.name,
+            BindingKind::Normal,
+            DeclarationKind::Function,
+        );
+        it.expression.symbol_id.set(Some(symbol_id));
+        self.visit_binding_identifier(&it.expression);
+        self.enter_scope(ScopeFlags::empty(), &it.scope_id);
+        it.bind(self, BindingKind::Snippet);
+        for parameter in it.parameters.iter() {
+            self.visit_binding_pattern(parameter);
+        }
+        self.visit_fragment(&it.body);
+        self.leave_scope();
+        self.leave_svelte_node(kind);
+    }
+
     fn visit_fragment(&mut self, fragment: &Fragment<'a>) {
         let kind = SvelteAstKind::Fragment(self.alloc(fragment));
         self.enter_svelte_node(kind);
         self.enter_scope_internal(&fragment.scope_id, fragment.metadata.borrow().transparent);
-        walk_fragment_nodes(self, &fragment.nodes);
+        for node in fragment.nodes.iter() {
+            self.visit_fragment_node(node);
+        }
         self.leave_scope();
         self.leave_svelte_node(kind);
     }
@@ -1,7 +1,12 @@
 use oxc_syntax::scope::{ScopeFlags, ScopeId};
 use rusvelte_ast::{ast_kind::*, js_ast::*, js_walk::walk::*, visit::JsVisit};
 
-use crate::{binding::BindingKind, Analyzer};
+use crate::{
+    binding::BindingKind,
+    error::{AnalyzerError, AnalyzerErrorKind},
+    rune::{get_rune, Rune},
+    Analyzer,
+};
 
 impl<'a> JsVisit<'a> for Analyzer<'a> {
     fn enter_node(&mut self, _kind: JsAstKind<'a>) {
@@ -36,6 +41,9 @@ impl<'a> JsVisit<'a> for Analyzer<'a> {
     }
 
     fn visit_call_expression(&mut self, it: &CallExpression<'a>) {
+        if let Some(rune) = get_rune(it) {
+            self.validate_rune_call(rune, it);
+        }
         walk_call_expression(self, it);
         if let Some(expr_metadata) = self.state.expression_metadata() {
             if !expr_metadata.borrow().dependencies.is_empty() {
@@ -44,3 +52,73 @@ impl<'a> JsVisit<'a> for Analyzer<'a> {
         }
     }
 }
+
+impl<'a> Analyzer<'a> {
+    fn validate_rune_call(&mut self, rune: Rune, call: &CallExpression<'a>) {
+        let args_len = call.arguments.len();
+        let kind = match rune {
+            Rune::Effect | Rune::EffectPre => {
+                // the call is visited before being entered, so the current node is its parent
+                let parent = self.nodes.node(self.current_node_id).kind;
+                if !matches!(parent, AstKind::Js(JsAstKind::ExpressionStatement(_))) {
+                    self.errors.push(AnalyzerError::new(
+                        call.span,
+                        AnalyzerErrorKind::EffectInvalidPlacement,
+                    ));
+                }
+                (args_len != 1).then_some(AnalyzerErrorKind::RuneInvalidArgumentsLength {
+                    rune: rune.name(),
+                    args: "exactly one argument",
+                })
+            }
+            Rune::EffectRoot | Rune::InspectWith => {
+                (args_len != 1).then_some(AnalyzerErrorKind::RuneInvalidArgumentsLength {
+                    rune: rune.name(),
+                    args: "exactly one argument",
+                })
+            }
+            Rune::Inspect => {
+                (args_len == 0).then_some(AnalyzerErrorKind::RuneInvalidArgumentsLength {
+                    rune: rune.name(),
+                    args: "one or more arguments",
+                })
+            }
+            Rune::EffectTracking => {
+                (args_len != 0).then_some(AnalyzerErrorKind::RuneInvalidArguments(rune.name()))
+            }
+            Rune::Props => {
+                if let AstKind::Js(JsAstKind::VariableDeclarator(decl)) =
+                    self.nodes.node(self.current_node_id).kind
+                {
+                    self.validate_props_pattern(&decl.id);
+                }
+                (args_len != 0).then_some(AnalyzerErrorKind::RuneInvalidArguments(rune.name()))
+            }
+            _ => None,
+        };
+        if let Some(kind) = kind {
+            self.errors.push(AnalyzerError::new(call.span, kind));
+        }
+    }
+
+    /// Each destructured prop is read from its own key, so `let { a: { b }, [key]: c } = $props()`
+    /// can't be compiled
+    fn validate_props_pattern(&mut self, pattern: &BindingPattern<'a>) {
+        let BindingPatternKind::ObjectPattern(pattern) = &pattern.kind else {
+            return;
+        };
+        for property in pattern.properties.iter() {
+            let value = match &property.value.kind {
+                BindingPatternKind::AssignmentPattern(assignment) => &assignment.left,
+                _ => &property.value,
+            };
+            if property.computed || !matches!(value.kind, BindingPatternKind::BindingIdentifier(_))
+            {
+                self.errors.push(AnalyzerError::new(
+                    property.span,
+                    AnalyzerErrorKind::PropsInvalidPattern,
+                ));
+            }
+        }
+    }
+}
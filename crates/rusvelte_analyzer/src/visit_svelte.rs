@@ -1,5 +1,6 @@
 use std::rc::Rc;
 
+use oxc_syntax::scope::ScopeFlags;
 use rusvelte_ast::{
     ast::*,
     ast_kind::{AstKind, SvelteAstKind},
@@ -7,7 +8,13 @@ use rusvelte_ast::{
     visit::{walk::*, JsVisit, Visit},
 };
 
-use crate::Analyzer;
+use rusvelte_utils::{binding_property::get_binding_property, regex_pattern::REGEX_NOT_WHITESPACE};
+
+use crate::{
+    binding::{BindingKind, DeclarationKind},
+    error::{AnalyzerError, AnalyzerErrorKind},
+    Analyzer, NodeId,
+};
 
 impl<'a> Visit<'a> for Analyzer<'a> {
     fn enter_svelte_node(&mut self, kind: SvelteAstKind<'a>) {
@@ -33,6 +40,214 @@ impl<'a> Visit<'a> for Analyzer<'a> {
         self.leave_svelte_node(kind);
     }
 
+    fn visit_render_tag(&mut self, it: &RenderTag<'a>) {
+        let kind = SvelteAstKind::RenderTag(self.alloc(it));
+        self.enter_svelte_node(kind);
+        // snippets declared by the component never change, anything else might
+        let dynamic = match &it.expression.call().callee {
+            Expression::Identifier(ident) => self
+                .find_binding(self.current_scope_id, &ident.name)
+                .map_or(true, |(_, binding)| binding.kind() != BindingKind::Normal),
+            _ => true,
+        };
+        it.dynamic.set(dynamic);
+        self.mark_subtree_dynamic();
+        match &it.expression {
+            RenderTagExpression::CallExpression(call) => self.visit_call_expression(call),
+            RenderTagExpression::ChainExpression(chain) => self.visit_chain_expression(chain),
+        }
+        self.leave_svelte_node(kind);
+    }
+
+    fn visit_html_tag(&mut self, it: &HtmlTag<'a>) {
+        walk_html_tag(self, it);
+        // the content is only known once it is parsed at runtime
+        self.mark_subtree_dynamic();
+    }
+
+    fn visit_component(&mut self, it: &Component<'a>) {
+        let kind = SvelteAstKind::Component(self.alloc(it));
+        self.enter_svelte_node(kind);
+        // in runes mode, `<Child>` is replaced when `Child` is a state or a prop, and so is
+        // `<ui.Button>` when `ui` is declared
+        let object_name = it.name.split('.').next().unwrap_or(it.name);
+        let dynamic = self.runes
+            && self
+                .find_binding(self.current_scope_id, object_name)
+                .is_some_and(|(_, binding)| {
+                    binding.kind() != BindingKind::Normal || it.name.contains('.')
+                });
+        it.dynamic.set(dynamic);
+        self.mark_subtree_dynamic();
+        for attribute in it.attributes.iter() {
+            self.visit_attribute(attribute);
+        }
+        self.visit_fragment(&it.fragment);
+        self.leave_svelte_node(kind);
+    }
+
+    fn visit_if_block(&mut self, it: &IfBlock<'a>) {
+        let kind = SvelteAstKind::IfBlock(self.alloc(it));
+        self.enter_svelte_node(kind);
+        self.mark_subtree_dynamic();
+        self.visit_expression(&it.test);
+        self.visit_fragment(&it.consequent);
+        if let Some(alternate) = &it.alternate {
+            self.visit_fragment(alternate);
+        }
+        self.leave_svelte_node(kind);
+    }
+
+    fn visit_each_block(&mut self, it: &EachBlock<'a>) {
+        let kind = SvelteAstKind::EachBlock(self.alloc(it));
+        self.enter_svelte_node(kind);
+        self.mark_subtree_dynamic();
+        it.is_controlled.set(self.is_only_child_of_element());
+        // evaluate the expression in the parent scope
+        let old_expression_metadata = self
+            .state
+            .replace_expression_metadata(Some(Rc::clone(&it.expression_metadata)));
+        self.visit_expression(&it.expression);
+        self.state
+            .replace_expression_metadata(old_expression_metadata);
+        self.enter_scope(ScopeFlags::empty(), &it.scope_id);
+        self.visit_binding_pattern(&it.context);
+        if let Some(key) = &it.key {
+            self.visit_expression(key);
+        }
+        self.visit_fragment(&it.body);
+        self.leave_scope();
+        if let Some(fallback) = &it.fallback {
+            self.visit_fragment(fallback);
+        }
+        self.leave_svelte_node(kind);
+    }
+
+    fn visit_await_block(&mut self, it: &AwaitBlock<'a>) {
+        let kind = SvelteAstKind::AwaitBlock(self.alloc(it));
+        self.enter_svelte_node(kind);
+        self.mark_subtree_dynamic();
+        self.visit_expression(&it.expression);
+        if let Some(pending) = &it.pending {
+            self.visit_fragment(pending);
+        }
+        self.enter_scope(ScopeFlags::empty(), &it.then_scope_id);
+        if let Some(value) = &it.value {
+            self.visit_binding_pattern(value);
+        }
+        if let Some(then) = &it.then {
+            self.visit_fragment(then);
+        }
+        self.leave_scope();
+        self.enter_scope(ScopeFlags::empty(), &it.catch_scope_id);
+        if let Some(error) = &it.error {
+            self.visit_binding_pattern(error);
+        }
+        if let Some(catch) = &it.catch {
+            self.visit_fragment(catch);
+        }
+        self.leave_scope();
+        self.leave_svelte_node(kind);
+    }
+
+    fn visit_key_block(&mut self, it: &KeyBlock<'a>) {
+        let kind = SvelteAstKind::KeyBlock(self.alloc(it));
+        self.enter_svelte_node(kind);
+        self.mark_subtree_dynamic();
+        let old_expression_metadata = self
+            .state
+            .replace_expression_metadata(Some(Rc::clone(&it.expression_metadata)));
+        self.visit_expression(&it.expression);
+        self.state
+            .replace_expression_metadata(old_expression_metadata);
+        self.visit_fragment(&it.fragment);
+        self.leave_svelte_node(kind);
+    }
+
+    fn visit_spread_attribute(&mut self, it: &SpreadAttribute<'a>) {
+        walk_spread_attribute(self, it);
+        // the attributes of the element are set at runtime
+        self.mark_subtree_dynamic();
+    }
+
+    fn visit_let_directive(&mut self, it: &LetDirective<'a>) {
+        walk_let_directive(self, it);
+        // the slot props are never declared, so the names would not resolve
+        self.errors.push(AnalyzerError::new(
+            it.span,
+            AnalyzerErrorKind::LetDirectiveUnsupported,
+        ));
+    }
+
+    fn visit_on_directive(&mut self, it: &OnDirective<'a>) {
+        walk_on_directive(self, it);
+        // the listener is attached once the element is created
+        self.mark_subtree_dynamic();
+    }
+
+    fn visit_class_directive(&mut self, it: &ClassDirective<'a>) {
+        let old_expression_metadata = self
+            .state
+            .replace_expression_metadata(Some(Rc::clone(&it.expression_metadata)));
+        walk_class_directive(self, it);
+        self.state
+            .replace_expression_metadata(old_expression_metadata);
+        self.mark_subtree_dynamic();
+    }
+
+    fn visit_style_directive(&mut self, it: &StyleDirective<'a>) {
+        walk_style_directive(self, it);
+        self.mark_subtree_dynamic();
+        let mut metadata = it.expression_metadata.borrow_mut();
+        match &it.value {
+            // `style:color` reads the `color` variable
+            AttributeValue::True => {
+                if let Some((_, binding)) = self.find_binding(self.current_scope_id, it.name) {
+                    metadata.has_state = binding.kind() != BindingKind::Normal;
+                }
+            }
+            AttributeValue::ExpressionTag(tag) => {
+                metadata.has_state = tag.expression_metadata.borrow().has_state;
+                metadata.has_call = tag.expression_metadata.borrow().has_call;
+            }
+            AttributeValue::Quoted(values) => {
+                for value in values.iter() {
+                    if let QuotedAttributeValue::ExpressionTag(tag) = value {
+                        metadata.has_state |= tag.expression_metadata.borrow().has_state;
+                        metadata.has_call |= tag.expression_metadata.borrow().has_call;
+                    }
+                }
+            }
+        }
+    }
+
+    fn visit_bind_directive(&mut self, it: &BindDirective<'a>) {
+        let node_id = self.next_node_id;
+        walk_bind_directive(self, it);
+        self.validate_bind_directive(node_id, it);
+        // the binding is set up once the element is created
+        self.mark_subtree_dynamic();
+    }
+
+    fn visit_transition_directive(&mut self, it: &TransitionDirective<'a>) {
+        walk_transition_directive(self, it);
+        // the transition is created along with the element
+        self.mark_subtree_dynamic();
+    }
+
+    fn visit_animate_directive(&mut self, it: &AnimateDirective<'a>) {
+        let node_id = self.next_node_id;
+        walk_animate_directive(self, it);
+        self.validate_animate_directive(node_id, it);
+        self.mark_subtree_dynamic();
+    }
+
+    fn visit_use_directive(&mut self, it: &UseDirective<'a>) {
+        walk_use_directive(self, it);
+        // the action is called with the element once it is created
+        self.mark_subtree_dynamic();
+    }
+
     fn visit_normal_attribute(&mut self, attr: &NormalAttribute<'a>) {
         let node_id = self.next_node_id;
         walk_normal_attribute(self, attr);
@@ -59,12 +274,9 @@ impl<'a> Visit<'a> for Analyzer<'a> {
         }
 
         if attr.is_event_attribute() {
-            if self
-                .nodes
-                .parent_node(node_id)
-                .map(|node| matches!(node.kind, AstKind::Svelte(SvelteAstKind::RegularElement(_))))
-                .is_some()
-            {
+            if self.nodes.parent_node(node_id).is_some_and(|node| {
+                matches!(node.kind, AstKind::Svelte(SvelteAstKind::RegularElement(_)))
+            }) {
                 self.use_event_attribute = true;
             } else {
                 // TODO
@@ -72,3 +284,170 @@ impl<'a> Visit<'a> for Analyzer<'a> {
         }
     }
 }
+
+impl<'a> Analyzer<'a> {
+    fn validate_bind_directive(&mut self, node_id: NodeId, it: &BindDirective<'a>) {
+        let kind = match &it.expression {
+            Expression::Identifier(ident) => self
+                .find_binding(self.current_scope_id, &ident.name)
+                .and_then(|(_, binding)| {
+                    let is_state =
+                        matches!(binding.kind(), BindingKind::State | BindingKind::RawState);
+                    if matches!(
+                        binding.declaration_kind(),
+                        DeclarationKind::Const | DeclarationKind::Import
+                    ) && !is_state
+                    {
+                        Some(AnalyzerErrorKind::ConstantBinding)
+                    } else if it.name != "this"
+                        && (binding.kind() == BindingKind::Derived
+                            // in runes mode, a plain `let` isn't reactive
+                            || (self.runes && binding.kind() == BindingKind::Normal))
+                    {
+                        Some(AnalyzerErrorKind::BindInvalidValue)
+                    } else {
+                        None
+                    }
+                }),
+            expression if expression.is_member_expression() => None,
+            _ => Some(AnalyzerErrorKind::BindInvalidExpression),
+        }
+        .or_else(|| self.validate_bind_target(node_id, it));
+
+        if let Some(kind) = kind {
+            self.errors.push(AnalyzerError::new(it.span, kind));
+        }
+    }
+
+    /// Checks that the element supports the bound property
+    fn validate_bind_target(
+        &self,
+        node_id: NodeId,
+        it: &BindDirective<'a>,
+    ) -> Option<AnalyzerErrorKind> {
+        let AstKind::Svelte(SvelteAstKind::RegularElement(element)) =
+            self.nodes.parent_node(node_id)?.kind
+        else {
+            return None;
+        };
+        let Some(property) = get_binding_property(it.name) else {
+            return Some(AnalyzerErrorKind::BindInvalidName(it.name.to_string()));
+        };
+        if let Some(valid_elements) = property.valid_elements {
+            if !valid_elements.iter().any(|name| *name == element.name) {
+                return Some(AnalyzerErrorKind::BindInvalidTarget {
+                    name: it.name.to_string(),
+                    elements: valid_elements
+                        .iter()
+                        .map(|name| format!("`<{name}>`"))
+                        .collect::<Vec<_>>()
+                        .join(", "),
+                });
+            }
+        }
+
+        let input_type = element
+            .attributes
+            .iter()
+            .find_map(|attribute| match attribute {
+                Attribute::NormalAttribute(attribute) if attribute.name == "type" => {
+                    attribute.value.as_raw_text()
+                }
+                _ => None,
+            });
+        let expected_type = match it.name {
+            "checked" => "checkbox",
+            "files" => "file",
+            _ => return None,
+        };
+        (input_type != Some(expected_type)).then(|| AnalyzerErrorKind::BindInvalidTarget {
+            name: it.name.to_string(),
+            elements: format!("`<input type=\"{expected_type}\">`"),
+        })
+    }
+
+    /// Whether the current node is the only child of a regular element once the whitespace, the
+    /// comments and the hoisted nodes of the element are removed
+    fn is_only_child_of_element(&self) -> bool {
+        let mut ancestors = self
+            .nodes
+            .ancestors(self.current_node_id)
+            .skip(1)
+            .map(|node_id| self.nodes.node(node_id).kind);
+        let (
+            Some(AstKind::Svelte(SvelteAstKind::Fragment(fragment))),
+            Some(AstKind::Svelte(SvelteAstKind::RegularElement(_))),
+        ) = (ancestors.next(), ancestors.next())
+        else {
+            return false;
+        };
+        let children = fragment
+            .nodes
+            .iter()
+            .filter(|node| match node {
+                FragmentNode::Comment(_) => self.compile_options.preserve_comments,
+                FragmentNode::Text(text) => {
+                    self.compile_options.preserve_whitespace
+                        || REGEX_NOT_WHITESPACE.is_match(&text.data)
+                }
+                FragmentNode::Tag(Tag::ConstTag(_) | Tag::DebugTag(_))
+                | FragmentNode::Block(Block::SnippetBlock(_)) => false,
+                _ => true,
+            })
+            .count();
+        children == 1
+    }
+
+    /// Checks that the element is the only child of a keyed each block, so that it moves along
+    /// with its item
+    fn validate_animate_directive(&mut self, node_id: NodeId, it: &AnimateDirective<'a>) {
+        let mut ancestors = self
+            .nodes
+            .ancestors(node_id)
+            .skip(1)
+            .map(|node_id| self.nodes.node(node_id).kind);
+        let attributes = match ancestors.next() {
+            Some(AstKind::Svelte(SvelteAstKind::RegularElement(element))) => &element.attributes,
+            Some(AstKind::Svelte(SvelteAstKind::SvelteElement(element))) => &element.attributes,
+            _ => return,
+        };
+        let kind = match (ancestors.next(), ancestors.next()) {
+            (
+                Some(AstKind::Svelte(SvelteAstKind::Fragment(fragment))),
+                Some(AstKind::Svelte(SvelteAstKind::EachBlock(block))),
+            ) if std::ptr::eq(fragment, &block.body) => {
+                let children = fragment
+                    .nodes
+                    .iter()
+                    .filter(|node| match node {
+                        FragmentNode::Comment(_) | FragmentNode::Tag(Tag::ConstTag(_)) => false,
+                        FragmentNode::Text(text) => !text.data.trim().is_empty(),
+                        _ => true,
+                    })
+                    .count();
+                if !block.is_keyed() {
+                    Some(AnalyzerErrorKind::AnimationMissingKey)
+                } else if children > 1 {
+                    Some(AnalyzerErrorKind::AnimationInvalidPlacement)
+                } else {
+                    None
+                }
+            }
+            _ => Some(AnalyzerErrorKind::AnimationInvalidPlacement),
+        };
+        if let Some(kind) = kind {
+            self.errors.push(AnalyzerError::new(it.span, kind));
+        }
+
+        let is_first = attributes.iter().find_map(|attribute| match attribute {
+            Attribute::Directive(Directive::AnimateDirective(directive)) => Some(directive.span),
+            _ => None,
+        }) == Some(it.span);
+        if !is_first {
+            self.errors.push(AnalyzerError::new(
+                it.span,
+                AnalyzerErrorKind::AnimationDuplicate,
+            ));
+        }
+    }
+}
@@ -0,0 +1,148 @@
+use oxc_allocator::Allocator;
+use rusvelte_analyzer::{
+    binding::{Binding, BindingKind, DeclarationKind},
+    rune::{get_expression_rune, Rune},
+    Analysis, Analyzer, CompileOptions, SymbolId,
+};
+use rusvelte_ast::js_ast::Statement;
+use rusvelte_parser::Parser;
+
+fn analyze<'a>(source: &'a str, allocator: &'a Allocator) -> Analysis<'a> {
+    let root = allocator.alloc(Parser::new(source, allocator).parse().root);
+    Analyzer::new(CompileOptions::new("App".to_string()), root).analyze(root)
+}
+
+fn find_binding<'b>(analysis: &'b Analysis, name: &str) -> (SymbolId, &'b Binding) {
+    analysis
+        .symbols
+        .iter()
+        .find(|(_, binding)| binding.name() == name)
+        .unwrap_or_else(|| panic!("`{name}` is not declared"))
+}
+
+#[test]
+fn analyze_const_tag_scope() {
+    let source = r#"<script>
+  let items = $state([1, 2]);
+</script>
+{#each items as item}
+  {@const double = item * 2}
+  {double}
+{/each}"#;
+    let allocator = Allocator::default();
+    let analysis = analyze(source, &allocator);
+    let root_scope_id = analysis.scopes.root_scope_id();
+
+    let (item_id, item) = find_binding(&analysis, "item");
+    assert_eq!(item.kind(), BindingKind::Each);
+    let (_, double) = find_binding(&analysis, "double");
+    assert_eq!(double.kind(), BindingKind::Template);
+    // the tag is only visible inside the block, where the context of the block is visible too
+    assert!(analysis
+        .scopes
+        .find_symbol_id(root_scope_id, "double")
+        .is_none());
+    assert_eq!(
+        analysis.scopes.find_symbol_id(double.scope_id(), "item"),
+        Some(item_id)
+    );
+}
+
+#[test]
+fn analyze_snippet_parameters_scope() {
+    let source = r#"{#snippet row(name, index)}
+  <p>{index}: {name}</p>
+{/snippet}
+{@render row("a", 0)}"#;
+    let allocator = Allocator::default();
+    let analysis = analyze(source, &allocator);
+
+    let (row_id, row) = find_binding(&analysis, "row");
+    assert_eq!(row.kind(), BindingKind::Normal);
+    assert_eq!(row.declaration_kind(), DeclarationKind::Function);
+
+    let (_, name) = find_binding(&analysis, "name");
+    let (_, index) = find_binding(&analysis, "index");
+    assert_eq!(name.kind(), BindingKind::Snippet);
+    assert_eq!(index.kind(), BindingKind::Snippet);
+    assert_eq!(name.scope_id(), index.scope_id());
+    // the parameters are only visible inside the snippet, which can call itself
+    assert!(analysis
+        .scopes
+        .find_symbol_id(row.scope_id(), "name")
+        .is_none());
+    assert_eq!(
+        analysis.scopes.find_symbol_id(name.scope_id(), "row"),
+        Some(row_id)
+    );
+}
+
+#[test]
+fn analyze_rune_binding_kinds() {
+    let source = r#"<script>
+  let { a, b = 1, c = $bindable(), d: e = $bindable(0), ...rest } = $props();
+  let count = $state(0);
+  let list = $state.raw([]);
+  let doubled = $derived(count * 2);
+  let total = $derived.by(() => count + list.length);
+</script>"#;
+    let allocator = Allocator::default();
+    let analysis = analyze(source, &allocator);
+    for (name, kind) in [
+        ("a", BindingKind::Prop),
+        ("b", BindingKind::Prop),
+        ("c", BindingKind::BindableProp),
+        ("e", BindingKind::BindableProp),
+        ("rest", BindingKind::RestProp),
+        ("count", BindingKind::State),
+        ("list", BindingKind::RawState),
+        ("doubled", BindingKind::Derived),
+        ("total", BindingKind::Derived),
+    ] {
+        assert_eq!(find_binding(&analysis, name).1.kind(), kind, "`{name}`");
+    }
+
+    let source = r#"<script>
+  let props = $props();
+</script>"#;
+    let analysis = analyze(source, &allocator);
+    assert_eq!(
+        find_binding(&analysis, "props").1.kind(),
+        BindingKind::RestProp
+    );
+}
+
+#[test]
+fn analyze_inspect_with_rune() {
+    let source = r#"<script>
+  let count = $state(0);
+  $inspect(count).with(console.trace);
+  $inspect(count);
+</script>"#;
+    let allocator = Allocator::default();
+    let root = Parser::new(source, &allocator).parse().root;
+    let runes: Vec<_> = root
+        .instance
+        .as_ref()
+        .unwrap()
+        .content
+        .body
+        .iter()
+        .map(|stmt| match stmt {
+            Statement::VariableDeclaration(decl) => decl.declarations[0]
+                .init
+                .as_ref()
+                .and_then(get_expression_rune),
+            Statement::ExpressionStatement(it) => get_expression_rune(&it.expression),
+            _ => None,
+        })
+        .collect();
+    assert_eq!(
+        runes,
+        [
+            Some(Rune::State),
+            Some(Rune::InspectWith),
+            Some(Rune::Inspect)
+        ]
+    );
+}
@@ -22,8 +22,9 @@ pub struct NormalAttribute<'a> {
 }
 
 impl<'a> NormalAttribute<'a> {
+    /// Whether the attribute is an event handler, e.g. `onclick={handler}`
     pub fn is_event_attribute(&self) -> bool {
-        self.name.starts_with("on:") && self.value.is_expression_tag()
+        self.name.starts_with("on") && self.value.is_expression_tag()
     }
 
     pub fn get_expression_tag_values(&self) -> std::vec::Vec<&ExpressionTag<'a>> {
@@ -1,8 +1,11 @@
+use std::cell::Cell;
+
 use oxc_ast::ast::{BindingIdentifier, BindingPattern, Expression};
 use oxc_span::Span;
+use oxc_syntax::scope::ScopeId;
 use rusvelte_derive::{AstTree, OxcSpan};
 
-use super::Fragment;
+use super::{ExpressionMetadata, Fragment};
 
 #[derive(Debug, AstTree, OxcSpan)]
 pub enum Block<'a> {
@@ -23,27 +26,62 @@ pub struct IfBlock<'a> {
 }
 
 #[derive(Debug, AstTree, OxcSpan)]
+#[visit(order(expression, context, key, body, fallback))]
 pub struct EachBlock<'a> {
     pub span: Span,
     pub expression: Expression<'a>,
+    #[visit(scope = scope_id)]
     pub context: BindingPattern<'a>,
+    #[visit(scope = scope_id)]
     pub body: Fragment<'a>,
     pub fallback: Option<Fragment<'a>>,
     pub index: Option<&'a str>,
+    #[visit(scope = scope_id)]
     pub key: Option<Expression<'a>>,
+    #[ast_ignore]
+    pub expression_metadata: ExpressionMetadata,
+    /// The scope of the `context` and `index` bindings
+    #[ast_ignore]
+    pub scope_id: Cell<Option<ScopeId>>,
+    /// Whether the block is the only child of an element, which then serves as its anchor
+    #[ast_ignore]
+    pub is_controlled: Cell<bool>,
+}
+
+impl EachBlock<'_> {
+    /// `{#each items as item, i (i)}` is treated as a normal indexed block,
+    /// everything else with a key as keyed.
+    pub fn is_keyed(&self) -> bool {
+        match &self.key {
+            Some(Expression::Identifier(key)) => self.index != Some(key.name.as_str()),
+            Some(_) => true,
+            None => false,
+        }
+    }
 }
 
 #[derive(Debug, AstTree, OxcSpan)]
+#[visit(order(expression, pending, value, then, error, catch))]
 pub struct AwaitBlock<'a> {
     pub span: Span,
     pub expression: Expression<'a>,
     /// The resolved value inside the `then` block
+    #[visit(scope = then_scope_id)]
     pub value: Option<BindingPattern<'a>>,
     /// The rejection reason inside the `catch` block
+    #[visit(scope = catch_scope_id)]
     pub error: Option<BindingPattern<'a>>,
     pub pending: Option<Fragment<'a>>,
+    #[visit(scope = then_scope_id)]
     pub then: Option<Fragment<'a>>,
+    #[visit(scope = catch_scope_id)]
     pub catch: Option<Fragment<'a>>,
+    /// The scope of the `value` binding
+    #[ast_ignore]
+    pub then_scope_id: Cell<Option<ScopeId>>,
+    /// The scope of the `error` binding
+    #[ast_ignore]
+    pub catch_scope_id: Cell<Option<ScopeId>>,
 }
 
 #[derive(Debug, AstTree, OxcSpan)]
@@ -51,12 +89,19 @@ pub struct KeyBlock<'a> {
     pub span: Span,
     pub expression: Expression<'a>,
     pub fragment: Fragment<'a>,
+    #[ast_ignore]
+    pub expression_metadata: ExpressionMetadata,
 }
 
 #[derive(Debug, AstTree, OxcSpan)]
 pub struct SnippetBlock<'a> {
     pub span: Span,
     pub expression: BindingIdentifier<'a>,
+    #[visit(scope = scope_id)]
     pub parameters: Vec<BindingPattern<'a>>,
+    #[visit(scope = scope_id)]
     pub body: Fragment<'a>,
+    /// The scope of the `parameters` bindings
+    #[ast_ignore]
+    pub scope_id: Cell<Option<ScopeId>>,
 }
@@ -1,10 +1,13 @@
 use oxc_ast::ast::Expression;
 use oxc_span::Span;
 use rusvelte_derive::{AstTree, OxcSpan};
+use serde::Serialize;
 
-use super::attribute::AttributeValue;
+use super::{attribute::AttributeValue, ExpressionMetadata};
 
+/// Named `visit_svelte_directive` because `visit_directive` conflicts with the JS's directive
 #[derive(Debug, AstTree, OxcSpan)]
+#[visit(name = svelte_directive)]
 pub enum Directive<'a> {
     AnimateDirective(AnimateDirective<'a>),
     BindDirective(BindDirective<'a>),
@@ -20,13 +23,17 @@ impl<'a> Directive<'a> {
     /// # Panics
     ///
     /// Panics if `expression` is none when trying to construct a ClassDirective or UseDirective.
+    ///
+    /// # Errors
+    ///
+    /// Returns the first modifier of a TransitionDirective which isn't a [TransitionModifier].
     pub fn new(
         span: Span,
         kind: DirectiveKind<'a>,
         name: &'a str,
         expression: Option<Expression<'a>>,
         modifiers: Vec<&'a str>,
-    ) -> Self {
+    ) -> Result<Self, &'a str> {
         let directive = match kind {
             DirectiveKind::AnimateDirective => Directive::AnimateDirective(AnimateDirective {
                 span,
@@ -50,6 +57,7 @@ impl<'a> Directive<'a> {
                         span,
                         name,
                         expression,
+                        expression_metadata: ExpressionMetadata::default(),
                     })
                 } else {
                     panic!("Trying to construct a ClassDirective without expression")
@@ -70,19 +78,19 @@ impl<'a> Directive<'a> {
                 panic!("Should not use this method to create a StyleDirective")
             }
             DirectiveKind::TransitionDirective(direction) => {
-                let (intro, outro) = if direction == "transition" {
-                    (true, true)
-                } else {
-                    (direction == "in", direction == "out")
-                };
-                Directive::TransitionDirective(TransitionDirective {
+                let mut directive = Directive::TransitionDirective(TransitionDirective {
                     span,
                     name,
                     expression,
-                    modifiers,
-                    intro,
-                    outro,
-                })
+                    modifiers: modifiers
+                        .into_iter()
+                        .map(|modifier| TransitionModifier::from_name(modifier).ok_or(modifier))
+                        .collect::<Result<_, _>>()?,
+                    intro: false,
+                    outro: false,
+                });
+                directive.set_direction(direction);
+                directive
             }
             DirectiveKind::UseDirective => Directive::UseDirective(UseDirective {
                 span,
@@ -91,13 +99,14 @@ impl<'a> Directive<'a> {
             }),
         };
 
-        directive
+        Ok(directive)
     }
 
     /// Only useful when Directive is [TransitionDirective]
     pub fn set_direction(&mut self, direction: &'a str) {
         if let Directive::TransitionDirective(directive) = self {
-            directive.intro = direction == "in" || direction == "direction"
+            directive.intro = direction == "in" || direction == "transition";
+            directive.outro = direction == "out" || direction == "transition";
         }
     }
 
@@ -181,6 +190,8 @@ pub struct ClassDirective<'a> {
     pub name: &'a str,
     /// The `y`` in `class:x={y}`, or the `x` in `class:x`
     pub expression: Expression<'a>,
+    #[ast_ignore]
+    pub expression_metadata: ExpressionMetadata,
 }
 #[derive(Debug, AstTree, OxcSpan)]
 pub struct LetDirective<'a> {
@@ -205,10 +216,12 @@ pub struct StyleDirective<'a> {
     pub span: Span,
     /// The `x` in `style:x`
     pub name: &'a str,
-    /// The `y` in `style:x={y}`
+    /// The `y` in `style:x={y}`, [`AttributeValue::True`] for `style:x`
     pub value: AttributeValue<'a>,
-    // TODO: should be `important`
-    pub modifiers: Vec<&'a str>,
+    /// Whether the directive has the `|important` modifier
+    pub important: bool,
+    #[ast_ignore]
+    pub expression_metadata: ExpressionMetadata,
 }
 #[derive(Debug, AstTree, OxcSpan)]
 pub struct TransitionDirective<'a> {
@@ -217,12 +230,32 @@ pub struct TransitionDirective<'a> {
     pub name: &'a str,
     /// The `y` in `transition:x={y}`
     pub expression: Option<Expression<'a>>,
-    // TODO: should be `local` | `global`
-    pub modifiers: Vec<&'a str>,
+    pub modifiers: Vec<TransitionModifier>,
+    /// Whether the transition plays when the element is added, `in:` or `transition:`
     pub intro: bool,
+    /// Whether the transition plays when the element is removed, `out:` or `transition:`
     pub outro: bool,
 }
 
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum TransitionModifier {
+    /// Only play the transition when its own block is added or removed (the default)
+    Local,
+    /// Also play the transition when a parent block is added or removed
+    Global,
+}
+
+impl TransitionModifier {
+    pub fn from_name(name: &str) -> Option<TransitionModifier> {
+        match name {
+            "local" => Some(TransitionModifier::Local),
+            "global" => Some(TransitionModifier::Global),
+            _ => None,
+        }
+    }
+}
+
 #[derive(Debug, AstTree, OxcSpan)]
 pub struct UseDirective<'a> {
     pub span: Span,
@@ -24,11 +24,32 @@ pub enum Element<'a> {
     Component(Component<'a>),
 }
 
+impl<'a> Element<'a> {
+    pub fn attributes(&self) -> &[Attribute<'a>] {
+        match self {
+            Element::RegularElement(it) => &it.attributes,
+            Element::SvelteComponent(it) => &it.attributes,
+            Element::SvelteElement(it) => &it.attributes,
+            Element::SvelteBody(it) => &it.attributes,
+            Element::SvelteWindow(it) => &it.attributes,
+            Element::SvelteDocument(it) => &it.attributes,
+            Element::SvelteHead(it) => &it.attributes,
+            Element::SvelteFragment(it) => &it.attributes,
+            Element::SvelteSelf(it) => &it.attributes,
+            Element::TitleElement(it) => &it.attributes,
+            Element::SlotElement(it) => &it.attributes,
+            Element::Component(it) => &it.attributes,
+        }
+    }
+}
+
 #[derive(Debug, AstTree, OxcSpan)]
 pub struct RegularElement<'a> {
     pub span: Span,
     pub name: &'a str,
+    #[visit(scope = scope_id)]
     pub attributes: Vec<'a, Attribute<'a>>,
+    #[visit(scope = scope_id)]
     pub fragment: Fragment<'a>,
     #[ast_ignore]
     pub scope_id: Cell<Option<ScopeId>>,
@@ -128,6 +149,7 @@ pub struct Component<'a> {
     pub name: &'a str,
     pub attributes: Vec<'a, Attribute<'a>>,
     pub fragment: Fragment<'a>,
+    /// Whether the component can change, i.e. its name refers to a state or a prop
     #[ast_ignore]
-    pub dynamic: bool,
+    pub dynamic: Cell<bool>,
 }
@@ -9,6 +9,7 @@ use super::{Block, Comment, Element, ExpressionTag, RegularElement, Tag, Text};
 
 #[derive(Debug, AstTree)]
 pub struct Fragment<'a> {
+    #[visit(scope = scope_id)]
     pub nodes: Vec<'a, FragmentNode<'a>>,
     #[ast_ignore]
     pub metadata: RefCell<FragmentMetadata>,
@@ -82,10 +83,10 @@ impl<'a> FragmentNode<'a> {
 
     pub fn dynamic(&self) -> bool {
         match self {
-            Self::Tag(Tag::RenderTag(tag)) => tag.dynamic,
+            Self::Tag(Tag::RenderTag(tag)) => tag.dynamic.get(),
             Self::Element(element) => {
                 if let Element::Component(component) = element.as_ref() {
-                    component.dynamic
+                    component.dynamic.get()
                 } else {
                     false
                 }
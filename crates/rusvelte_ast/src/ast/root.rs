@@ -6,6 +6,7 @@ use rusvelte_derive::{AstTree, OxcSpan};
 use super::{style_sheet::StyleSheet, Attribute, Fragment, Script};
 
 #[derive(Debug, AstTree, OxcSpan)]
+#[visit(order(options, module, instance, fragment, css))]
 pub struct Root<'a> {
     pub css: Option<StyleSheet<'a>>,
     pub span: Span,
@@ -22,6 +23,7 @@ pub struct SvelteOptions<'a> {
     pub immutable: Option<bool>,
     pub accessors: Option<bool>,
     pub attributes: Vec<'a, Attribute<'a>>,
+    #[visit(ignore)]
     pub custom_element: Option<CustomElement<'a>>,
     pub namespace: Option<&'a str>,
     pub css: Option<&'a str>,
@@ -11,8 +11,10 @@ pub struct Script<'a> {
     pub span: Span,
     pub context: ScriptContext,
     pub content: Program<'a>,
+    #[visit(ignore)]
     pub attributes: Vec<'a, Attribute<'a>>,
     /// svelte store the comment into the Program, but I think it is not necessary to store there.
+    #[visit(ignore)]
     pub leading_comment: Option<Comment<'a>>,
 }
 
@@ -9,6 +9,7 @@ pub struct StyleSheet<'a> {
     pub span: Span,
     pub attributes: Vec<'a, Attribute<'a>>,
     pub children: Vec<'a, StyleSheetChild<'a>>,
+    #[visit(ignore)]
     pub content: StyleSheetContent<'a>,
 }
 
@@ -124,7 +125,9 @@ pub struct Rule<'a> {
     pub block: CSSBlock<'a>,
 }
 
+/// Named `visit_css_declaration` to not conflict with the JS's declaration
 #[derive(Debug, AstTree, OxcSpan)]
+#[visit(name = css_declaration)]
 pub struct Declaration<'a> {
     pub span: Span,
     pub property: &'a str,
@@ -1,5 +1,8 @@
+use std::cell::Cell;
+
 use oxc_ast::ast::{
-    CallExpression, ChainExpression, Expression, IdentifierReference, VariableDeclaration,
+    CallExpression, ChainElement, ChainExpression, Expression, IdentifierReference,
+    VariableDeclaration,
 };
 use oxc_span::Span;
 use rusvelte_derive::{AstTree, OxcSpan};
@@ -67,8 +70,9 @@ pub struct ConstTag<'a> {
 pub struct RenderTag<'a> {
     pub span: Span,
     pub expression: RenderTagExpression<'a>,
+    /// Whether the rendered snippet can change, i.e. it isn't declared by the component itself
     #[ast_ignore]
-    pub dynamic: bool,
+    pub dynamic: Cell<bool>,
 }
 
 #[derive(Debug, AstTree, OxcSpan)]
@@ -77,3 +81,26 @@ pub enum RenderTagExpression<'a> {
     // Only accept CallExpression in it.
     ChainExpression(ChainExpression<'a>),
 }
+
+impl<'a> RenderTagExpression<'a> {
+    /// The snippet call, with the optional chain unwrapped
+    pub fn call(&self) -> &CallExpression<'a> {
+        match self {
+            Self::CallExpression(call) => call,
+            Self::ChainExpression(chain) => match &chain.expression {
+                ChainElement::CallExpression(call) => call,
+                _ => unreachable!("the parser only accepts calls in a render tag"),
+            },
+        }
+    }
+
+    pub fn call_mut(&mut self) -> &mut CallExpression<'a> {
+        match self {
+            Self::CallExpression(call) => call,
+            Self::ChainExpression(chain) => match &mut chain.expression {
+                ChainElement::CallExpression(call) => call,
+                _ => unreachable!("the parser only accepts calls in a render tag"),
+            },
+        }
+    }
+}
@@ -3,6 +3,7 @@ use super::AstBuilder;
 use oxc_allocator::{Box, IntoIn, Vec};
 use oxc_ast::{ast::*, NONE};
 use oxc_span::{Atom, SPAN};
+use oxc_syntax::{identifier::is_identifier_name, number::NumberBase};
 
 impl<'a> AstBuilder<'a> {
     pub fn statement_import_declaration(self, decl: ImportDeclaration<'a>) -> Statement<'a> {
@@ -80,6 +81,16 @@ impl<'a> AstBuilder<'a> {
             .variable_declaration(SPAN, kind, self.vec([decl]), false)
     }
 
+    pub fn variable_declarator(
+        self,
+        kind: VariableDeclarationKind,
+        id: BindingPattern<'a>,
+        init: Expression<'a>,
+    ) -> VariableDeclarator<'a> {
+        self.builder
+            .variable_declarator(SPAN, kind, id, Some(init), false)
+    }
+
     pub fn statement_var(self, id: BindingPattern<'a>, init: Expression<'a>) -> Statement<'a> {
         Statement::VariableDeclaration(self.alloc(self.var(id, init)))
     }
@@ -94,6 +105,45 @@ impl<'a> AstBuilder<'a> {
         self.builder.binding_pattern(kind, NONE, false)
     }
 
+    /// `[a, b]`
+    pub fn binding_pattern_array(self, names: &[&str]) -> BindingPattern<'a> {
+        let elements = self.vec_from_iter(
+            names
+                .iter()
+                .map(|name| Some(self.binding_pattern_identifier(*name))),
+        );
+        let kind = self
+            .builder
+            .binding_pattern_kind_array_pattern(SPAN, elements, NONE);
+        self.builder.binding_pattern(kind, NONE, false)
+    }
+
+    /// `name = right`
+    pub fn binding_pattern_assignment<A>(self, name: A, right: Expression<'a>) -> BindingPattern<'a>
+    where
+        A: IntoIn<'a, Atom<'a>>,
+    {
+        let kind = self.builder.binding_pattern_kind_assignment_pattern(
+            SPAN,
+            self.binding_pattern_identifier(name),
+            right,
+        );
+        self.builder.binding_pattern(kind, NONE, false)
+    }
+
+    /// `{ name }` inside an object pattern
+    pub fn binding_property_shorthand(self, name: &str) -> BindingProperty<'a> {
+        let key =
+            PropertyKey::StaticIdentifier(self.alloc(self.builder.identifier_name(SPAN, name)));
+        self.builder.binding_property(
+            SPAN,
+            key,
+            self.binding_pattern_identifier(name),
+            true,
+            false,
+        )
+    }
+
     pub fn function_declaration<A>(
         self,
         name: A,
@@ -152,6 +202,52 @@ impl<'a> AstBuilder<'a> {
         Expression::ArrowFunctionExpression(self.alloc(self.arrow(params, statements)))
     }
 
+    /// Same as [`Self::expression_arrow`] but always prints the body as a block
+    pub fn expression_arrow_block(
+        self,
+        params: Vec<'a, FormalParameter<'a>>,
+        statements: Vec<'a, Statement<'a>>,
+    ) -> Expression<'a> {
+        let mut arrow = self.arrow(params, statements);
+        arrow.expression = false;
+        Expression::ArrowFunctionExpression(self.alloc(arrow))
+    }
+
+    /// `function(...rest) { statements }`
+    pub fn expression_function_with_rest(
+        self,
+        rest: &str,
+        statements: Vec<'a, Statement<'a>>,
+    ) -> Expression<'a> {
+        let rest = self
+            .builder
+            .alloc_binding_rest_element(SPAN, self.binding_pattern_identifier(rest));
+        let params = self.builder.formal_parameters(
+            SPAN,
+            FormalParameterKind::FormalParameter,
+            self.vec([]),
+            Some(rest),
+        );
+        let body = self.builder.function_body(SPAN, self.vec([]), statements);
+        Expression::FunctionExpression(self.alloc(self.builder.function(
+            SPAN,
+            FunctionType::FunctionExpression,
+            None,
+            false,
+            false,
+            false,
+            NONE,
+            NONE,
+            params,
+            NONE,
+            Some(body),
+        )))
+    }
+
+    pub fn expression_sequence(self, expressions: Vec<'a, Expression<'a>>) -> Expression<'a> {
+        self.builder.expression_sequence(SPAN, expressions)
+    }
+
     pub fn formal_parameter(self, pattern: BindingPattern<'a>) -> FormalParameter<'a> {
         self.builder
             .formal_parameter(SPAN, self.vec([]), pattern, None, false, false)
@@ -193,6 +289,469 @@ impl<'a> AstBuilder<'a> {
         Statement::ImportDeclaration(decl)
     }
 
+    pub fn expression_string_literal<A>(self, value: A) -> Expression<'a>
+    where
+        A: IntoIn<'a, Atom<'a>>,
+    {
+        self.builder.expression_string_literal(SPAN, value, None)
+    }
+
+    pub fn expression_boolean_literal(self, value: bool) -> Expression<'a> {
+        self.builder.expression_boolean_literal(SPAN, value)
+    }
+
+    pub fn expression_static_member(
+        self,
+        object: Expression<'a>,
+        property: &str,
+    ) -> Expression<'a> {
+        let property = self.builder.identifier_name(SPAN, property);
+        Expression::StaticMemberExpression(
+            self.alloc(
+                self.builder
+                    .static_member_expression(SPAN, object, property, false),
+            ),
+        )
+    }
+
+    /// Same as [`Self::expression_static_member`] but falls back to a computed member if
+    /// `property` is not a valid identifier name, e.g. `$$props["aria-label"]`.
+    pub fn expression_member(self, object: Expression<'a>, property: &str) -> Expression<'a> {
+        if is_identifier_name(property) {
+            self.expression_static_member(object, property)
+        } else {
+            self.expression_computed_member(object, self.expression_string_literal(property))
+        }
+    }
+
+    pub fn expression_computed_member(
+        self,
+        object: Expression<'a>,
+        property: Expression<'a>,
+    ) -> Expression<'a> {
+        Expression::ComputedMemberExpression(
+            self.alloc(
+                self.builder
+                    .computed_member_expression(SPAN, object, property, false),
+            ),
+        )
+    }
+
+    /// Converts an identifier or member expression into an assignment target.
+    pub fn assignment_target(self, expr: Expression<'a>) -> AssignmentTarget<'a> {
+        match expr {
+            Expression::Identifier(ident) => AssignmentTarget::AssignmentTargetIdentifier(ident),
+            Expression::StaticMemberExpression(member) => {
+                AssignmentTarget::StaticMemberExpression(member)
+            }
+            Expression::ComputedMemberExpression(member) => {
+                AssignmentTarget::ComputedMemberExpression(member)
+            }
+            _ => unreachable!("expression can not be used as an assignment target"),
+        }
+    }
+
+    pub fn expression_assignment(
+        self,
+        left: Expression<'a>,
+        right: Expression<'a>,
+    ) -> Expression<'a> {
+        self.builder.expression_assignment(
+            SPAN,
+            AssignmentOperator::Assign,
+            self.assignment_target(left),
+            right,
+        )
+    }
+
+    pub fn expression_call(
+        self,
+        callee: Expression<'a>,
+        args: Vec<'a, Argument<'a>>,
+    ) -> Expression<'a> {
+        self.builder
+            .expression_call(SPAN, callee, NONE, args, false)
+    }
+
+    /// Uses a string literal as the key if `key` is not a valid identifier name, e.g. `"aria-label"`.
+    pub fn object_property(self, key: &str, value: Expression<'a>) -> ObjectPropertyKind<'a> {
+        ObjectPropertyKind::ObjectProperty(self.alloc(self.builder.object_property(
+            SPAN,
+            PropertyKind::Init,
+            self.property_key(key),
+            value,
+            false,
+            false,
+            false,
+        )))
+    }
+
+    /// `get key() { return value; }`
+    pub fn object_property_getter(
+        self,
+        key: &str,
+        value: Expression<'a>,
+    ) -> ObjectPropertyKind<'a> {
+        let params = self.builder.formal_parameters(
+            SPAN,
+            FormalParameterKind::UniqueFormalParameters,
+            self.vec([]),
+            NONE,
+        );
+        let body = self.builder.function_body(
+            SPAN,
+            self.vec([]),
+            self.vec([self.statement_return(value)]),
+        );
+        let getter = self.builder.function(
+            SPAN,
+            FunctionType::FunctionExpression,
+            None,
+            false,
+            false,
+            false,
+            NONE,
+            NONE,
+            params,
+            NONE,
+            Some(body),
+        );
+        ObjectPropertyKind::ObjectProperty(self.alloc(self.builder.object_property(
+            SPAN,
+            PropertyKind::Get,
+            self.property_key(key),
+            Expression::FunctionExpression(self.alloc(getter)),
+            false,
+            false,
+            false,
+        )))
+    }
+
+    /// `set key(param) { statements }`
+    pub fn object_property_setter(
+        self,
+        key: &str,
+        param: &str,
+        statements: Vec<'a, Statement<'a>>,
+    ) -> ObjectPropertyKind<'a> {
+        let params = self.builder.formal_parameters(
+            SPAN,
+            FormalParameterKind::UniqueFormalParameters,
+            self.vec([self.formal_parameter(self.binding_pattern_identifier(param))]),
+            NONE,
+        );
+        let body = self.builder.function_body(SPAN, self.vec([]), statements);
+        let setter = self.builder.function(
+            SPAN,
+            FunctionType::FunctionExpression,
+            None,
+            false,
+            false,
+            false,
+            NONE,
+            NONE,
+            params,
+            NONE,
+            Some(body),
+        );
+        ObjectPropertyKind::ObjectProperty(self.alloc(self.builder.object_property(
+            SPAN,
+            PropertyKind::Set,
+            self.property_key(key),
+            Expression::FunctionExpression(self.alloc(setter)),
+            false,
+            false,
+            false,
+        )))
+    }
+
+    fn property_key(self, key: &str) -> PropertyKey<'a> {
+        if is_identifier_name(key) {
+            PropertyKey::StaticIdentifier(self.alloc(self.builder.identifier_name(SPAN, key)))
+        } else {
+            PropertyKey::StringLiteral(self.alloc(self.string_literal(key)))
+        }
+    }
+
+    /// `{ name }`
+    pub fn object_property_shorthand(self, name: &str) -> ObjectPropertyKind<'a> {
+        let key =
+            PropertyKey::StaticIdentifier(self.alloc(self.builder.identifier_name(SPAN, name)));
+        ObjectPropertyKind::ObjectProperty(self.alloc(self.builder.object_property(
+            SPAN,
+            PropertyKind::Init,
+            key,
+            self.expression_identifier_reference(name),
+            false,
+            true,
+            false,
+        )))
+    }
+
+    pub fn spread_property(self, argument: Expression<'a>) -> ObjectPropertyKind<'a> {
+        ObjectPropertyKind::SpreadProperty(self.alloc(self.builder.spread_element(SPAN, argument)))
+    }
+
+    pub fn expression_object(self, properties: Vec<'a, ObjectPropertyKind<'a>>) -> Expression<'a> {
+        self.builder.expression_object(SPAN, properties, None)
+    }
+
+    pub fn expression_array(self, elements: Vec<'a, ArrayExpressionElement<'a>>) -> Expression<'a> {
+        self.builder.expression_array(SPAN, elements, None)
+    }
+
+    pub fn statement_const(self, id: BindingPattern<'a>, init: Expression<'a>) -> Statement<'a> {
+        let kind = VariableDeclarationKind::Const;
+        let decl = self
+            .builder
+            .variable_declarator(SPAN, kind, id, Some(init), false);
+        Statement::VariableDeclaration(self.alloc(self.builder.variable_declaration(
+            SPAN,
+            kind,
+            self.vec([decl]),
+            false,
+        )))
+    }
+
+    pub fn let_declaration(
+        self,
+        declarators: impl IntoIterator<Item = (BindingPattern<'a>, Expression<'a>)>,
+    ) -> VariableDeclaration<'a> {
+        let kind = VariableDeclarationKind::Let;
+        let declarators = self.vec_from_iter(declarators.into_iter().map(|(id, init)| {
+            self.builder
+                .variable_declarator(SPAN, kind, id, Some(init), false)
+        }));
+        self.builder
+            .variable_declaration(SPAN, kind, declarators, false)
+    }
+
+    pub fn statement_let(self, id: BindingPattern<'a>, init: Expression<'a>) -> Statement<'a> {
+        Statement::VariableDeclaration(self.alloc(self.let_declaration([(id, init)])))
+    }
+
+    /// `let id;`
+    pub fn statement_let_uninit(self, id: BindingPattern<'a>) -> Statement<'a> {
+        let kind = VariableDeclarationKind::Let;
+        let decl = self
+            .builder
+            .variable_declarator(SPAN, kind, id, None, false);
+        Statement::VariableDeclaration(self.alloc(self.builder.variable_declaration(
+            SPAN,
+            kind,
+            self.vec([decl]),
+            false,
+        )))
+    }
+
+    pub fn statement_for(
+        self,
+        init: VariableDeclaration<'a>,
+        test: Expression<'a>,
+        update: Expression<'a>,
+        body: Vec<'a, Statement<'a>>,
+    ) -> Statement<'a> {
+        let init = ForStatementInit::VariableDeclaration(self.alloc(init));
+        self.builder.statement_for(
+            SPAN,
+            Some(init),
+            Some(test),
+            Some(update),
+            self.statement_block(body),
+        )
+    }
+
+    pub fn statement_do_while(
+        self,
+        body: Vec<'a, Statement<'a>>,
+        test: Expression<'a>,
+    ) -> Statement<'a> {
+        self.builder
+            .statement_do_while(SPAN, self.statement_block(body), test)
+    }
+
+    pub fn statement_return(self, argument: Expression<'a>) -> Statement<'a> {
+        self.builder.statement_return(SPAN, Some(argument))
+    }
+
+    pub fn statement_empty(self) -> Statement<'a> {
+        self.builder.statement_empty(SPAN)
+    }
+
+    pub fn statement_debugger(self) -> Statement<'a> {
+        self.builder.statement_debugger(SPAN)
+    }
+
+    pub fn expression_numeric_literal(self, value: f64) -> Expression<'a> {
+        self.builder
+            .expression_numeric_literal(SPAN, value, None, NumberBase::Decimal)
+    }
+
+    /// `void 0`
+    pub fn expression_void_zero(self) -> Expression<'a> {
+        self.builder.expression_unary(
+            SPAN,
+            UnaryOperator::Void,
+            self.expression_numeric_literal(0.0),
+        )
+    }
+
+    /// `!argument`
+    pub fn expression_not(self, argument: Expression<'a>) -> Expression<'a> {
+        self.builder
+            .expression_unary(SPAN, UnaryOperator::LogicalNot, argument)
+    }
+
+    pub fn expression_this(self) -> Expression<'a> {
+        self.builder.expression_this(SPAN)
+    }
+
+    pub fn expression_null(self) -> Expression<'a> {
+        self.builder.expression_null_literal(SPAN)
+    }
+
+    pub fn expression_binary(
+        self,
+        left: Expression<'a>,
+        operator: BinaryOperator,
+        right: Expression<'a>,
+    ) -> Expression<'a> {
+        self.builder.expression_binary(SPAN, left, operator, right)
+    }
+
+    pub fn expression_conditional(
+        self,
+        test: Expression<'a>,
+        consequent: Expression<'a>,
+        alternate: Expression<'a>,
+    ) -> Expression<'a> {
+        self.builder
+            .expression_conditional(SPAN, test, consequent, alternate)
+    }
+
+    pub fn expression_logical(
+        self,
+        left: Expression<'a>,
+        operator: LogicalOperator,
+        right: Expression<'a>,
+    ) -> Expression<'a> {
+        self.builder.expression_logical(SPAN, left, operator, right)
+    }
+
+    /// `name++`
+    pub fn expression_increment(self, name: &str) -> Expression<'a> {
+        let argument = SimpleAssignmentTarget::AssignmentTargetIdentifier(
+            self.alloc(self.builder.identifier_reference(SPAN, name)),
+        );
+        self.builder
+            .expression_update(SPAN, UpdateOperator::Increment, false, argument)
+    }
+
+    pub fn expression_compound_assignment(
+        self,
+        operator: AssignmentOperator,
+        left: Expression<'a>,
+        right: Expression<'a>,
+    ) -> Expression<'a> {
+        self.builder
+            .expression_assignment(SPAN, operator, self.assignment_target(left), right)
+    }
+
+    /// `callee?.(...args)`
+    pub fn expression_optional_call(
+        self,
+        callee: Expression<'a>,
+        args: Vec<'a, Argument<'a>>,
+    ) -> Expression<'a> {
+        let call = self.builder.call_expression(SPAN, callee, NONE, args, true);
+        self.builder
+            .expression_chain(SPAN, ChainElement::CallExpression(self.alloc(call)))
+    }
+
+    /// `object?.method(args)`
+    pub fn expression_optional_method_call(
+        self,
+        object: Expression<'a>,
+        method: &str,
+        args: Vec<'a, Argument<'a>>,
+    ) -> Expression<'a> {
+        let property = self.builder.identifier_name(SPAN, method);
+        let callee = Expression::StaticMemberExpression(
+            self.alloc(
+                self.builder
+                    .static_member_expression(SPAN, object, property, true),
+            ),
+        );
+        let call = self
+            .builder
+            .call_expression(SPAN, callee, NONE, args, false);
+        self.builder
+            .expression_chain(SPAN, ChainElement::CallExpression(self.alloc(call)))
+    }
+
+    /// Builds a template literal from the raw quasis, which must already be escaped.
+    /// There is always one more quasi than expressions.
+    pub fn expression_template_literal(
+        self,
+        quasis: &[String],
+        expressions: Vec<'a, Expression<'a>>,
+    ) -> Expression<'a> {
+        let quasis = self.vec_from_iter(quasis.iter().enumerate().map(|(i, raw)| {
+            let value = TemplateElementValue {
+                raw: raw.as_str().into_in(self.allocator),
+                cooked: None,
+            };
+            self.builder
+                .template_element(SPAN, i + 1 == quasis.len(), value)
+        }));
+        self.builder
+            .expression_template_literal(SPAN, quasis, expressions)
+    }
+
+    pub fn statement_if(
+        self,
+        test: Expression<'a>,
+        consequent: Vec<'a, Statement<'a>>,
+        alternate: Option<Statement<'a>>,
+    ) -> Statement<'a> {
+        let consequent = self.builder.statement_block(SPAN, consequent);
+        self.builder.statement_if(SPAN, test, consequent, alternate)
+    }
+
+    /// Unlike [`AstBuilder::statement_if`], the branches are not wrapped in block statements.
+    pub fn statement_if_unbraced(
+        self,
+        test: Expression<'a>,
+        consequent: Statement<'a>,
+        alternate: Option<Statement<'a>>,
+    ) -> Statement<'a> {
+        self.builder.statement_if(SPAN, test, consequent, alternate)
+    }
+
+    pub fn statement_block(self, body: Vec<'a, Statement<'a>>) -> Statement<'a> {
+        self.builder.statement_block(SPAN, body)
+    }
+
+    pub fn statement_function_declaration(self, function: Function<'a>) -> Statement<'a> {
+        Statement::FunctionDeclaration(self.alloc(function))
+    }
+
+    pub fn statement_export_default_identifier<A>(self, name: A) -> Statement<'a>
+    where
+        A: IntoIn<'a, Atom<'a>>,
+    {
+        let declaration = ExportDefaultDeclarationKind::Identifier(
+            self.alloc(self.builder.identifier_reference(SPAN, name)),
+        );
+        let export = self.builder.export_default_declaration(
+            SPAN,
+            declaration,
+            self.builder
+                .module_export_name_identifier_name(SPAN, "default"),
+        );
+        Statement::ExportDefaultDeclaration(self.alloc(export))
+    }
+
     pub fn build_assignment_value(
         self,
         operator: AssignmentOperator,
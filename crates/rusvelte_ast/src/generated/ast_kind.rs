@@ -0,0 +1,129 @@
+// Auto-generated code, DO NOT EDIT DIRECTLY!
+// To edit this generated file you have to edit `crates/rusvelte_ast/src/ast`
+// and run `just codegen`.
+
+use crate::ast::*;
+
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum SvelteAstType {
+    NormalAttribute,
+    SpreadAttribute,
+    IfBlock,
+    EachBlock,
+    AwaitBlock,
+    KeyBlock,
+    SnippetBlock,
+    AnimateDirective,
+    BindDirective,
+    ClassDirective,
+    LetDirective,
+    OnDirective,
+    StyleDirective,
+    TransitionDirective,
+    UseDirective,
+    RegularElement,
+    Comment,
+    SvelteHead,
+    SvelteWindow,
+    SvelteDocument,
+    SvelteBody,
+    SvelteElement,
+    SvelteComponent,
+    SvelteSelf,
+    SvelteFragment,
+    TitleElement,
+    SlotElement,
+    Component,
+    Fragment,
+    Root,
+    SvelteOptions,
+    CustomElement,
+    Script,
+    StyleSheet,
+    StyleSheetContent,
+    AtRule,
+    RelativeSelector,
+    TypeSelector,
+    IdSelector,
+    ClassSelector,
+    AttributeSelector,
+    PseudoElementSelector,
+    PseudoClassSelector,
+    Percentage,
+    NestingSelector,
+    Nth,
+    ComplexSelector,
+    Rule,
+    Declaration,
+    CSSBlock,
+    SelectorList,
+    Combinator,
+    ExpressionTag,
+    HtmlTag,
+    DebugTag,
+    ConstTag,
+    RenderTag,
+    Text,
+}
+
+#[derive(Debug, Clone, Copy)]
+pub enum SvelteAstKind<'a> {
+    NormalAttribute(&'a NormalAttribute<'a>),
+    SpreadAttribute(&'a SpreadAttribute<'a>),
+    IfBlock(&'a IfBlock<'a>),
+    EachBlock(&'a EachBlock<'a>),
+    AwaitBlock(&'a AwaitBlock<'a>),
+    KeyBlock(&'a KeyBlock<'a>),
+    SnippetBlock(&'a SnippetBlock<'a>),
+    AnimateDirective(&'a AnimateDirective<'a>),
+    BindDirective(&'a BindDirective<'a>),
+    ClassDirective(&'a ClassDirective<'a>),
+    LetDirective(&'a LetDirective<'a>),
+    OnDirective(&'a OnDirective<'a>),
+    StyleDirective(&'a StyleDirective<'a>),
+    TransitionDirective(&'a TransitionDirective<'a>),
+    UseDirective(&'a UseDirective<'a>),
+    RegularElement(&'a RegularElement<'a>),
+    Comment(&'a Comment<'a>),
+    SvelteHead(&'a SvelteHead<'a>),
+    SvelteWindow(&'a SvelteWindow<'a>),
+    SvelteDocument(&'a SvelteDocument<'a>),
+    SvelteBody(&'a SvelteBody<'a>),
+    SvelteElement(&'a SvelteElement<'a>),
+    SvelteComponent(&'a SvelteComponent<'a>),
+    SvelteSelf(&'a SvelteSelf<'a>),
+    SvelteFragment(&'a SvelteFragment<'a>),
+    TitleElement(&'a TitleElement<'a>),
+    SlotElement(&'a SlotElement<'a>),
+    Component(&'a Component<'a>),
+    Fragment(&'a Fragment<'a>),
+    Root(&'a Root<'a>),
+    SvelteOptions(&'a SvelteOptions<'a>),
+    CustomElement(&'a CustomElement<'a>),
+    Script(&'a Script<'a>),
+    StyleSheet(&'a StyleSheet<'a>),
+    StyleSheetContent(&'a StyleSheetContent<'a>),
+    AtRule(&'a AtRule<'a>),
+    RelativeSelector(&'a RelativeSelector<'a>),
+    TypeSelector(&'a TypeSelector<'a>),
+    IdSelector(&'a IdSelector<'a>),
+    ClassSelector(&'a ClassSelector<'a>),
+    AttributeSelector(&'a AttributeSelector<'a>),
+    PseudoElementSelector(&'a PseudoElementSelector<'a>),
+    PseudoClassSelector(&'a PseudoClassSelector<'a>),
+    Percentage(&'a Percentage<'a>),
+    NestingSelector(&'a NestingSelector<'a>),
+    Nth(&'a Nth<'a>),
+    ComplexSelector(&'a ComplexSelector<'a>),
+    Rule(&'a Rule<'a>),
+    Declaration(&'a Declaration<'a>),
+    CSSBlock(&'a CSSBlock<'a>),
+    SelectorList(&'a SelectorList<'a>),
+    Combinator(&'a Combinator<'a>),
+    ExpressionTag(&'a ExpressionTag<'a>),
+    HtmlTag(&'a HtmlTag<'a>),
+    DebugTag(&'a DebugTag<'a>),
+    ConstTag(&'a ConstTag<'a>),
+    RenderTag(&'a RenderTag<'a>),
+    Text(&'a Text<'a>),
+}
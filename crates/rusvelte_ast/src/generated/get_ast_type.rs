@@ -0,0 +1,477 @@
+// Auto-generated code, DO NOT EDIT DIRECTLY!
+// To edit this generated file you have to edit `crates/rusvelte_ast/src/ast`
+// and run `just codegen`.
+
+use crate::{ast::*, ast_kind::SvelteAstType, traits::get_ast_type::GetAstType};
+
+impl GetAstType for NormalAttribute<'_> {
+    fn ast_type(&self) -> SvelteAstType {
+        SvelteAstType::NormalAttribute
+    }
+}
+
+impl GetAstType for SpreadAttribute<'_> {
+    fn ast_type(&self) -> SvelteAstType {
+        SvelteAstType::SpreadAttribute
+    }
+}
+
+impl GetAstType for IfBlock<'_> {
+    fn ast_type(&self) -> SvelteAstType {
+        SvelteAstType::IfBlock
+    }
+}
+
+impl GetAstType for EachBlock<'_> {
+    fn ast_type(&self) -> SvelteAstType {
+        SvelteAstType::EachBlock
+    }
+}
+
+impl GetAstType for AwaitBlock<'_> {
+    fn ast_type(&self) -> SvelteAstType {
+        SvelteAstType::AwaitBlock
+    }
+}
+
+impl GetAstType for KeyBlock<'_> {
+    fn ast_type(&self) -> SvelteAstType {
+        SvelteAstType::KeyBlock
+    }
+}
+
+impl GetAstType for SnippetBlock<'_> {
+    fn ast_type(&self) -> SvelteAstType {
+        SvelteAstType::SnippetBlock
+    }
+}
+
+impl GetAstType for AnimateDirective<'_> {
+    fn ast_type(&self) -> SvelteAstType {
+        SvelteAstType::AnimateDirective
+    }
+}
+
+impl GetAstType for BindDirective<'_> {
+    fn ast_type(&self) -> SvelteAstType {
+        SvelteAstType::BindDirective
+    }
+}
+
+impl GetAstType for ClassDirective<'_> {
+    fn ast_type(&self) -> SvelteAstType {
+        SvelteAstType::ClassDirective
+    }
+}
+
+impl GetAstType for LetDirective<'_> {
+    fn ast_type(&self) -> SvelteAstType {
+        SvelteAstType::LetDirective
+    }
+}
+
+impl GetAstType for OnDirective<'_> {
+    fn ast_type(&self) -> SvelteAstType {
+        SvelteAstType::OnDirective
+    }
+}
+
+impl GetAstType for StyleDirective<'_> {
+    fn ast_type(&self) -> SvelteAstType {
+        SvelteAstType::StyleDirective
+    }
+}
+
+impl GetAstType for TransitionDirective<'_> {
+    fn ast_type(&self) -> SvelteAstType {
+        SvelteAstType::TransitionDirective
+    }
+}
+
+impl GetAstType for UseDirective<'_> {
+    fn ast_type(&self) -> SvelteAstType {
+        SvelteAstType::UseDirective
+    }
+}
+
+impl GetAstType for RegularElement<'_> {
+    fn ast_type(&self) -> SvelteAstType {
+        SvelteAstType::RegularElement
+    }
+}
+
+impl GetAstType for Comment<'_> {
+    fn ast_type(&self) -> SvelteAstType {
+        SvelteAstType::Comment
+    }
+}
+
+impl GetAstType for SvelteHead<'_> {
+    fn ast_type(&self) -> SvelteAstType {
+        SvelteAstType::SvelteHead
+    }
+}
+
+impl GetAstType for SvelteWindow<'_> {
+    fn ast_type(&self) -> SvelteAstType {
+        SvelteAstType::SvelteWindow
+    }
+}
+
+impl GetAstType for SvelteDocument<'_> {
+    fn ast_type(&self) -> SvelteAstType {
+        SvelteAstType::SvelteDocument
+    }
+}
+
+impl GetAstType for SvelteBody<'_> {
+    fn ast_type(&self) -> SvelteAstType {
+        SvelteAstType::SvelteBody
+    }
+}
+
+impl GetAstType for SvelteElement<'_> {
+    fn ast_type(&self) -> SvelteAstType {
+        SvelteAstType::SvelteElement
+    }
+}
+
+impl GetAstType for SvelteComponent<'_> {
+    fn ast_type(&self) -> SvelteAstType {
+        SvelteAstType::SvelteComponent
+    }
+}
+
+impl GetAstType for SvelteSelf<'_> {
+    fn ast_type(&self) -> SvelteAstType {
+        SvelteAstType::SvelteSelf
+    }
+}
+
+impl GetAstType for SvelteFragment<'_> {
+    fn ast_type(&self) -> SvelteAstType {
+        SvelteAstType::SvelteFragment
+    }
+}
+
+impl GetAstType for TitleElement<'_> {
+    fn ast_type(&self) -> SvelteAstType {
+        SvelteAstType::TitleElement
+    }
+}
+
+impl GetAstType for SlotElement<'_> {
+    fn ast_type(&self) -> SvelteAstType {
+        SvelteAstType::SlotElement
+    }
+}
+
+impl GetAstType for Component<'_> {
+    fn ast_type(&self) -> SvelteAstType {
+        SvelteAstType::Component
+    }
+}
+
+impl GetAstType for Fragment<'_> {
+    fn ast_type(&self) -> SvelteAstType {
+        SvelteAstType::Fragment
+    }
+}
+
+impl GetAstType for Root<'_> {
+    fn ast_type(&self) -> SvelteAstType {
+        SvelteAstType::Root
+    }
+}
+
+impl GetAstType for SvelteOptions<'_> {
+    fn ast_type(&self) -> SvelteAstType {
+        SvelteAstType::SvelteOptions
+    }
+}
+
+impl GetAstType for CustomElement<'_> {
+    fn ast_type(&self) -> SvelteAstType {
+        SvelteAstType::CustomElement
+    }
+}
+
+impl GetAstType for Script<'_> {
+    fn ast_type(&self) -> SvelteAstType {
+        SvelteAstType::Script
+    }
+}
+
+impl GetAstType for StyleSheet<'_> {
+    fn ast_type(&self) -> SvelteAstType {
+        SvelteAstType::StyleSheet
+    }
+}
+
+impl GetAstType for StyleSheetContent<'_> {
+    fn ast_type(&self) -> SvelteAstType {
+        SvelteAstType::StyleSheetContent
+    }
+}
+
+impl GetAstType for AtRule<'_> {
+    fn ast_type(&self) -> SvelteAstType {
+        SvelteAstType::AtRule
+    }
+}
+
+impl GetAstType for RelativeSelector<'_> {
+    fn ast_type(&self) -> SvelteAstType {
+        SvelteAstType::RelativeSelector
+    }
+}
+
+impl GetAstType for TypeSelector<'_> {
+    fn ast_type(&self) -> SvelteAstType {
+        SvelteAstType::TypeSelector
+    }
+}
+
+impl GetAstType for IdSelector<'_> {
+    fn ast_type(&self) -> SvelteAstType {
+        SvelteAstType::IdSelector
+    }
+}
+
+impl GetAstType for ClassSelector<'_> {
+    fn ast_type(&self) -> SvelteAstType {
+        SvelteAstType::ClassSelector
+    }
+}
+
+impl GetAstType for AttributeSelector<'_> {
+    fn ast_type(&self) -> SvelteAstType {
+        SvelteAstType::AttributeSelector
+    }
+}
+
+impl GetAstType for PseudoElementSelector<'_> {
+    fn ast_type(&self) -> SvelteAstType {
+        SvelteAstType::PseudoElementSelector
+    }
+}
+
+impl GetAstType for PseudoClassSelector<'_> {
+    fn ast_type(&self) -> SvelteAstType {
+        SvelteAstType::PseudoClassSelector
+    }
+}
+
+impl GetAstType for Percentage<'_> {
+    fn ast_type(&self) -> SvelteAstType {
+        SvelteAstType::Percentage
+    }
+}
+
+impl GetAstType for NestingSelector<'_> {
+    fn ast_type(&self) -> SvelteAstType {
+        SvelteAstType::NestingSelector
+    }
+}
+
+impl GetAstType for Nth<'_> {
+    fn ast_type(&self) -> SvelteAstType {
+        SvelteAstType::Nth
+    }
+}
+
+impl GetAstType for ComplexSelector<'_> {
+    fn ast_type(&self) -> SvelteAstType {
+        SvelteAstType::ComplexSelector
+    }
+}
+
+impl GetAstType for Rule<'_> {
+    fn ast_type(&self) -> SvelteAstType {
+        SvelteAstType::Rule
+    }
+}
+
+impl GetAstType for Declaration<'_> {
+    fn ast_type(&self) -> SvelteAstType {
+        SvelteAstType::Declaration
+    }
+}
+
+impl GetAstType for CSSBlock<'_> {
+    fn ast_type(&self) -> SvelteAstType {
+        SvelteAstType::CSSBlock
+    }
+}
+
+impl GetAstType for SelectorList<'_> {
+    fn ast_type(&self) -> SvelteAstType {
+        SvelteAstType::SelectorList
+    }
+}
+
+impl GetAstType for Combinator<'_> {
+    fn ast_type(&self) -> SvelteAstType {
+        SvelteAstType::Combinator
+    }
+}
+
+impl GetAstType for ExpressionTag<'_> {
+    fn ast_type(&self) -> SvelteAstType {
+        SvelteAstType::ExpressionTag
+    }
+}
+
+impl GetAstType for HtmlTag<'_> {
+    fn ast_type(&self) -> SvelteAstType {
+        SvelteAstType::HtmlTag
+    }
+}
+
+impl GetAstType for DebugTag<'_> {
+    fn ast_type(&self) -> SvelteAstType {
+        SvelteAstType::DebugTag
+    }
+}
+
+impl GetAstType for ConstTag<'_> {
+    fn ast_type(&self) -> SvelteAstType {
+        SvelteAstType::ConstTag
+    }
+}
+
+impl GetAstType for RenderTag<'_> {
+    fn ast_type(&self) -> SvelteAstType {
+        SvelteAstType::RenderTag
+    }
+}
+
+impl GetAstType for Text<'_> {
+    fn ast_type(&self) -> SvelteAstType {
+        SvelteAstType::Text
+    }
+}
+
+impl GetAstType for Attribute<'_> {
+    fn ast_type(&self) -> SvelteAstType {
+        match self {
+            Attribute::NormalAttribute(it) => it.ast_type(),
+            Attribute::SpreadAttribute(it) => it.ast_type(),
+            Attribute::Directive(it) => it.ast_type(),
+        }
+    }
+}
+
+impl GetAstType for QuotedAttributeValue<'_> {
+    fn ast_type(&self) -> SvelteAstType {
+        match self {
+            QuotedAttributeValue::ExpressionTag(it) => it.ast_type(),
+            QuotedAttributeValue::Text(it) => it.ast_type(),
+        }
+    }
+}
+
+impl GetAstType for Block<'_> {
+    fn ast_type(&self) -> SvelteAstType {
+        match self {
+            Block::IfBlock(it) => it.ast_type(),
+            Block::EachBlock(it) => it.ast_type(),
+            Block::AwaitBlock(it) => it.ast_type(),
+            Block::KeyBlock(it) => it.ast_type(),
+            Block::SnippetBlock(it) => it.ast_type(),
+        }
+    }
+}
+
+impl GetAstType for Directive<'_> {
+    fn ast_type(&self) -> SvelteAstType {
+        match self {
+            Directive::AnimateDirective(it) => it.ast_type(),
+            Directive::BindDirective(it) => it.ast_type(),
+            Directive::ClassDirective(it) => it.ast_type(),
+            Directive::LetDirective(it) => it.ast_type(),
+            Directive::OnDirective(it) => it.ast_type(),
+            Directive::StyleDirective(it) => it.ast_type(),
+            Directive::TransitionDirective(it) => it.ast_type(),
+            Directive::UseDirective(it) => it.ast_type(),
+        }
+    }
+}
+
+impl GetAstType for Element<'_> {
+    fn ast_type(&self) -> SvelteAstType {
+        match self {
+            Element::RegularElement(it) => it.ast_type(),
+            Element::SvelteComponent(it) => it.ast_type(),
+            Element::SvelteElement(it) => it.ast_type(),
+            Element::SvelteBody(it) => it.ast_type(),
+            Element::SvelteWindow(it) => it.ast_type(),
+            Element::SvelteDocument(it) => it.ast_type(),
+            Element::SvelteHead(it) => it.ast_type(),
+            Element::SvelteFragment(it) => it.ast_type(),
+            Element::SvelteSelf(it) => it.ast_type(),
+            Element::TitleElement(it) => it.ast_type(),
+            Element::SlotElement(it) => it.ast_type(),
+            Element::Component(it) => it.ast_type(),
+        }
+    }
+}
+
+impl GetAstType for FragmentNode<'_> {
+    fn ast_type(&self) -> SvelteAstType {
+        match self {
+            FragmentNode::Text(it) => it.ast_type(),
+            FragmentNode::Element(it) => it.ast_type(),
+            FragmentNode::Tag(it) => it.ast_type(),
+            FragmentNode::Comment(it) => it.ast_type(),
+            FragmentNode::Block(it) => it.ast_type(),
+        }
+    }
+}
+
+impl GetAstType for StyleSheetChild<'_> {
+    fn ast_type(&self) -> SvelteAstType {
+        match self {
+            StyleSheetChild::AtRule(it) => it.ast_type(),
+            StyleSheetChild::Rule(it) => it.ast_type(),
+        }
+    }
+}
+
+impl GetAstType for SimpleSelector<'_> {
+    fn ast_type(&self) -> SvelteAstType {
+        match self {
+            SimpleSelector::TypeSelector(it) => it.ast_type(),
+            SimpleSelector::IdSelector(it) => it.ast_type(),
+            SimpleSelector::ClassSelector(it) => it.ast_type(),
+            SimpleSelector::AttributeSelector(it) => it.ast_type(),
+            SimpleSelector::PseudoElementSelector(it) => it.ast_type(),
+            SimpleSelector::PseudoClassSelector(it) => it.ast_type(),
+            SimpleSelector::Percentage(it) => it.ast_type(),
+            SimpleSelector::Nth(it) => it.ast_type(),
+            SimpleSelector::NestingSelector(it) => it.ast_type(),
+        }
+    }
+}
+
+impl GetAstType for BlockChild<'_> {
+    fn ast_type(&self) -> SvelteAstType {
+        match self {
+            BlockChild::Rule(it) => it.ast_type(),
+            BlockChild::AtRule(it) => it.ast_type(),
+            BlockChild::Declaration(it) => it.ast_type(),
+        }
+    }
+}
+
+impl GetAstType for Tag<'_> {
+    fn ast_type(&self) -> SvelteAstType {
+        match self {
+            Tag::ExpressionTag(it) => it.ast_type(),
+            Tag::HtmlTag(it) => it.ast_type(),
+            Tag::DebugTag(it) => it.ast_type(),
+            Tag::ConstTag(it) => it.ast_type(),
+            Tag::RenderTag(it) => it.ast_type(),
+        }
+    }
+}
@@ -0,0 +1,4 @@
+pub mod ast_kind;
+mod get_ast_type;
+pub mod visit;
+pub mod visit_mut;
@@ -0,0 +1,903 @@
+// Auto-generated code, DO NOT EDIT DIRECTLY!
+// To edit this generated file you have to edit `crates/rusvelte_ast/src/ast`
+// and run `just codegen`.
+
+#![allow(unused_variables)]
+pub use oxc_ast::Visit as JsVisit;
+
+use crate::{ast::*, ast_kind::SvelteAstKind};
+use walk::*;
+
+pub use oxc_ast::VisitMut as JsVisitMut;
+
+pub trait Visit<'a>: JsVisit<'a> {
+    fn enter_svelte_node(&mut self, kind: SvelteAstKind<'a>) {}
+    fn leave_svelte_node(&mut self, kind: SvelteAstKind<'a>) {}
+
+    fn visit_normal_attribute(&mut self, it: &NormalAttribute<'a>) {
+        walk_normal_attribute(self, it);
+    }
+    fn visit_spread_attribute(&mut self, it: &SpreadAttribute<'a>) {
+        walk_spread_attribute(self, it);
+    }
+    fn visit_if_block(&mut self, it: &IfBlock<'a>) {
+        walk_if_block(self, it);
+    }
+    fn visit_each_block(&mut self, it: &EachBlock<'a>) {
+        walk_each_block(self, it);
+    }
+    fn visit_await_block(&mut self, it: &AwaitBlock<'a>) {
+        walk_await_block(self, it);
+    }
+    fn visit_key_block(&mut self, it: &KeyBlock<'a>) {
+        walk_key_block(self, it);
+    }
+    fn visit_snippet_block(&mut self, it: &SnippetBlock<'a>) {
+        walk_snippet_block(self, it);
+    }
+    fn visit_animate_directive(&mut self, it: &AnimateDirective<'a>) {
+        walk_animate_directive(self, it);
+    }
+    fn visit_bind_directive(&mut self, it: &BindDirective<'a>) {
+        walk_bind_directive(self, it);
+    }
+    fn visit_class_directive(&mut self, it: &ClassDirective<'a>) {
+        walk_class_directive(self, it);
+    }
+    fn visit_let_directive(&mut self, it: &LetDirective<'a>) {
+        walk_let_directive(self, it);
+    }
+    fn visit_on_directive(&mut self, it: &OnDirective<'a>) {
+        walk_on_directive(self, it);
+    }
+    fn visit_style_directive(&mut self, it: &StyleDirective<'a>) {
+        walk_style_directive(self, it);
+    }
+    fn visit_transition_directive(&mut self, it: &TransitionDirective<'a>) {
+        walk_transition_directive(self, it);
+    }
+    fn visit_use_directive(&mut self, it: &UseDirective<'a>) {
+        walk_use_directive(self, it);
+    }
+    fn visit_regular_element(&mut self, it: &RegularElement<'a>) {
+        walk_regular_element(self, it);
+    }
+    fn visit_comment(&mut self, it: &Comment<'a>) {
+        walk_comment(self, it);
+    }
+    fn visit_svelte_head(&mut self, it: &SvelteHead<'a>) {
+        walk_svelte_head(self, it);
+    }
+    fn visit_svelte_window(&mut self, it: &SvelteWindow<'a>) {
+        walk_svelte_window(self, it);
+    }
+    fn visit_svelte_document(&mut self, it: &SvelteDocument<'a>) {
+        walk_svelte_document(self, it);
+    }
+    fn visit_svelte_body(&mut self, it: &SvelteBody<'a>) {
+        walk_svelte_body(self, it);
+    }
+    fn visit_svelte_element(&mut self, it: &SvelteElement<'a>) {
+        walk_svelte_element(self, it);
+    }
+    fn visit_svelte_component(&mut self, it: &SvelteComponent<'a>) {
+        walk_svelte_component(self, it);
+    }
+    fn visit_svelte_self(&mut self, it: &SvelteSelf<'a>) {
+        walk_svelte_self(self, it);
+    }
+    fn visit_svelte_fragment(&mut self, it: &SvelteFragment<'a>) {
+        walk_svelte_fragment(self, it);
+    }
+    fn visit_title_element(&mut self, it: &TitleElement<'a>) {
+        walk_title_element(self, it);
+    }
+    fn visit_slot_element(&mut self, it: &SlotElement<'a>) {
+        walk_slot_element(self, it);
+    }
+    fn visit_component(&mut self, it: &Component<'a>) {
+        walk_component(self, it);
+    }
+    fn visit_fragment(&mut self, it: &Fragment<'a>) {
+        walk_fragment(self, it);
+    }
+    fn visit_root(&mut self, it: &Root<'a>) {
+        walk_root(self, it);
+    }
+    fn visit_svelte_options(&mut self, it: &SvelteOptions<'a>) {
+        walk_svelte_options(self, it);
+    }
+    fn visit_custom_element(&mut self, it: &CustomElement<'a>) {
+        walk_custom_element(self, it);
+    }
+    fn visit_script(&mut self, it: &Script<'a>) {
+        walk_script(self, it);
+    }
+    fn visit_style_sheet(&mut self, it: &StyleSheet<'a>) {
+        walk_style_sheet(self, it);
+    }
+    fn visit_style_sheet_content(&mut self, it: &StyleSheetContent<'a>) {
+        walk_style_sheet_content(self, it);
+    }
+    fn visit_at_rule(&mut self, it: &AtRule<'a>) {
+        walk_at_rule(self, it);
+    }
+    fn visit_relative_selector(&mut self, it: &RelativeSelector<'a>) {
+        walk_relative_selector(self, it);
+    }
+    fn visit_type_selector(&mut self, it: &TypeSelector<'a>) {
+        walk_type_selector(self, it);
+    }
+    fn visit_id_selector(&mut self, it: &IdSelector<'a>) {
+        walk_id_selector(self, it);
+    }
+    fn visit_class_selector(&mut self, it: &ClassSelector<'a>) {
+        walk_class_selector(self, it);
+    }
+    fn visit_attribute_selector(&mut self, it: &AttributeSelector<'a>) {
+        walk_attribute_selector(self, it);
+    }
+    fn visit_pseudo_element_selector(&mut self, it: &PseudoElementSelector<'a>) {
+        walk_pseudo_element_selector(self, it);
+    }
+    fn visit_pseudo_class_selector(&mut self, it: &PseudoClassSelector<'a>) {
+        walk_pseudo_class_selector(self, it);
+    }
+    fn visit_percentage(&mut self, it: &Percentage<'a>) {
+        walk_percentage(self, it);
+    }
+    fn visit_nesting_selector(&mut self, it: &NestingSelector<'a>) {
+        walk_nesting_selector(self, it);
+    }
+    fn visit_nth(&mut self, it: &Nth<'a>) {
+        walk_nth(self, it);
+    }
+    fn visit_complex_selector(&mut self, it: &ComplexSelector<'a>) {
+        walk_complex_selector(self, it);
+    }
+    fn visit_rule(&mut self, it: &Rule<'a>) {
+        walk_rule(self, it);
+    }
+    fn visit_css_declaration(&mut self, it: &Declaration<'a>) {
+        walk_css_declaration(self, it);
+    }
+    fn visit_css_block(&mut self, it: &CSSBlock<'a>) {
+        walk_css_block(self, it);
+    }
+    fn visit_selector_list(&mut self, it: &SelectorList<'a>) {
+        walk_selector_list(self, it);
+    }
+    fn visit_combinator(&mut self, it: &Combinator<'a>) {
+        walk_combinator(self, it);
+    }
+    fn visit_expression_tag(&mut self, it: &ExpressionTag<'a>) {
+        walk_expression_tag(self, it);
+    }
+    fn visit_html_tag(&mut self, it: &HtmlTag<'a>) {
+        walk_html_tag(self, it);
+    }
+    fn visit_debug_tag(&mut self, it: &DebugTag<'a>) {
+        walk_debug_tag(self, it);
+    }
+    fn visit_const_tag(&mut self, it: &ConstTag<'a>) {
+        walk_const_tag(self, it);
+    }
+    fn visit_render_tag(&mut self, it: &RenderTag<'a>) {
+        walk_render_tag(self, it);
+    }
+    fn visit_text(&mut self, it: &Text<'a>) {
+        walk_text(self, it);
+    }
+    fn visit_attribute(&mut self, it: &Attribute<'a>) {
+        walk_attribute(self, it);
+    }
+    fn visit_attribute_value(&mut self, it: &AttributeValue<'a>) {
+        walk_attribute_value(self, it);
+    }
+    fn visit_quoted_attribute_value(&mut self, it: &QuotedAttributeValue<'a>) {
+        walk_quoted_attribute_value(self, it);
+    }
+    fn visit_block(&mut self, it: &Block<'a>) {
+        walk_block(self, it);
+    }
+    fn visit_svelte_directive(&mut self, it: &Directive<'a>) {
+        walk_svelte_directive(self, it);
+    }
+    fn visit_element(&mut self, it: &Element<'a>) {
+        walk_element(self, it);
+    }
+    fn visit_fragment_node(&mut self, it: &FragmentNode<'a>) {
+        walk_fragment_node(self, it);
+    }
+    fn visit_style_sheet_child(&mut self, it: &StyleSheetChild<'a>) {
+        walk_style_sheet_child(self, it);
+    }
+    fn visit_simple_selector(&mut self, it: &SimpleSelector<'a>) {
+        walk_simple_selector(self, it);
+    }
+    fn visit_block_child(&mut self, it: &BlockChild<'a>) {
+        walk_block_child(self, it);
+    }
+    fn visit_tag(&mut self, it: &Tag<'a>) {
+        walk_tag(self, it);
+    }
+    fn visit_render_tag_expression(&mut self, it: &RenderTagExpression<'a>) {
+        walk_render_tag_expression(self, it);
+    }
+}
+
+pub mod walk {
+    use oxc_syntax::scope::ScopeFlags;
+
+    use super::*;
+
+    pub fn walk_normal_attribute<'a, V: Visit<'a>>(visitor: &mut V, it: &NormalAttribute<'a>) {
+        let kind = SvelteAstKind::NormalAttribute(visitor.alloc(it));
+        visitor.enter_svelte_node(kind);
+        visitor.visit_attribute_value(&it.value);
+        visitor.leave_svelte_node(kind);
+    }
+
+    pub fn walk_spread_attribute<'a, V: Visit<'a>>(visitor: &mut V, it: &SpreadAttribute<'a>) {
+        let kind = SvelteAstKind::SpreadAttribute(visitor.alloc(it));
+        visitor.enter_svelte_node(kind);
+        visitor.visit_expression(&it.expression);
+        visitor.leave_svelte_node(kind);
+    }
+
+    pub fn walk_if_block<'a, V: Visit<'a>>(visitor: &mut V, it: &IfBlock<'a>) {
+        let kind = SvelteAstKind::IfBlock(visitor.alloc(it));
+        visitor.enter_svelte_node(kind);
+        visitor.visit_expression(&it.test);
+        visitor.visit_fragment(&it.consequent);
+        if let Some(alternate) = &it.alternate {
+            visitor.visit_fragment(alternate);
+        }
+        visitor.leave_svelte_node(kind);
+    }
+
+    pub fn walk_each_block<'a, V: Visit<'a>>(visitor: &mut V, it: &EachBlock<'a>) {
+        let kind = SvelteAstKind::EachBlock(visitor.alloc(it));
+        visitor.enter_svelte_node(kind);
+        visitor.visit_expression(&it.expression);
+        visitor.enter_scope(ScopeFlags::empty(), &it.scope_id);
+        visitor.visit_binding_pattern(&it.context);
+        if let Some(key) = &it.key {
+            visitor.visit_expression(key);
+        }
+        visitor.visit_fragment(&it.body);
+        visitor.leave_scope();
+        if let Some(fallback) = &it.fallback {
+            visitor.visit_fragment(fallback);
+        }
+        visitor.leave_svelte_node(kind);
+    }
+
+    pub fn walk_await_block<'a, V: Visit<'a>>(visitor: &mut V, it: &AwaitBlock<'a>) {
+        let kind = SvelteAstKind::AwaitBlock(visitor.alloc(it));
+        visitor.enter_svelte_node(kind);
+        visitor.visit_expression(&it.expression);
+        if let Some(pending) = &it.pending {
+            visitor.visit_fragment(pending);
+        }
+        visitor.enter_scope(ScopeFlags::empty(), &it.then_scope_id);
+        if let Some(value) = &it.value {
+            visitor.visit_binding_pattern(value);
+        }
+        if let Some(then) = &it.then {
+            visitor.visit_fragment(then);
+        }
+        visitor.leave_scope();
+        visitor.enter_scope(ScopeFlags::empty(), &it.catch_scope_id);
+        if let Some(error) = &it.error {
+            visitor.visit_binding_pattern(error);
+        }
+        if let Some(catch) = &it.catch {
+            visitor.visit_fragment(catch);
+        }
+        visitor.leave_scope();
+        visitor.leave_svelte_node(kind);
+    }
+
+    pub fn walk_key_block<'a, V: Visit<'a>>(visitor: &mut V, it: &KeyBlock<'a>) {
+        let kind = SvelteAstKind::KeyBlock(visitor.alloc(it));
+        visitor.enter_svelte_node(kind);
+        visitor.visit_expression(&it.expression);
+        visitor.visit_fragment(&it.fragment);
+        visitor.leave_svelte_node(kind);
+    }
+
+    pub fn walk_snippet_block<'a, V: Visit<'a>>(visitor: &mut V, it: &SnippetBlock<'a>) {
+        let kind = SvelteAstKind::SnippetBlock(visitor.alloc(it));
+        visitor.enter_svelte_node(kind);
+        visitor.visit_binding_identifier(&it.expression);
+        visitor.enter_scope(ScopeFlags::empty(), &it.scope_id);
+        for item in it.parameters.iter() {
+            visitor.visit_binding_pattern(item);
+        }
+        visitor.visit_fragment(&it.body);
+        visitor.leave_scope();
+        visitor.leave_svelte_node(kind);
+    }
+
+    pub fn walk_animate_directive<'a, V: Visit<'a>>(visitor: &mut V, it: &AnimateDirective<'a>) {
+        let kind = SvelteAstKind::AnimateDirective(visitor.alloc(it));
+        visitor.enter_svelte_node(kind);
+        if let Some(expression) = &it.expression {
+            visitor.visit_expression(expression);
+        }
+        visitor.leave_svelte_node(kind);
+    }
+
+    pub fn walk_bind_directive<'a, V: Visit<'a>>(visitor: &mut V, it: &BindDirective<'a>) {
+        let kind = SvelteAstKind::BindDirective(visitor.alloc(it));
+        visitor.enter_svelte_node(kind);
+        visitor.visit_expression(&it.expression);
+        visitor.leave_svelte_node(kind);
+    }
+
+    pub fn walk_class_directive<'a, V: Visit<'a>>(visitor: &mut V, it: &ClassDirective<'a>) {
+        let kind = SvelteAstKind::ClassDirective(visitor.alloc(it));
+        visitor.enter_svelte_node(kind);
+        visitor.visit_expression(&it.expression);
+        visitor.leave_svelte_node(kind);
+    }
+
+    pub fn walk_let_directive<'a, V: Visit<'a>>(visitor: &mut V, it: &LetDirective<'a>) {
+        let kind = SvelteAstKind::LetDirective(visitor.alloc(it));
+        visitor.enter_svelte_node(kind);
+        if let Some(expression) = &it.expression {
+            visitor.visit_expression(expression);
+        }
+        visitor.leave_svelte_node(kind);
+    }
+
+    pub fn walk_on_directive<'a, V: Visit<'a>>(visitor: &mut V, it: &OnDirective<'a>) {
+        let kind = SvelteAstKind::OnDirective(visitor.alloc(it));
+        visitor.enter_svelte_node(kind);
+        if let Some(expression) = &it.expression {
+            visitor.visit_expression(expression);
+        }
+        visitor.leave_svelte_node(kind);
+    }
+
+    pub fn walk_style_directive<'a, V: Visit<'a>>(visitor: &mut V, it: &StyleDirective<'a>) {
+        let kind = SvelteAstKind::StyleDirective(visitor.alloc(it));
+        visitor.enter_svelte_node(kind);
+        visitor.visit_attribute_value(&it.value);
+        visitor.leave_svelte_node(kind);
+    }
+
+    pub fn walk_transition_directive<'a, V: Visit<'a>>(
+        visitor: &mut V,
+        it: &TransitionDirective<'a>,
+    ) {
+        let kind = SvelteAstKind::TransitionDirective(visitor.alloc(it));
+        visitor.enter_svelte_node(kind);
+        if let Some(expression) = &it.expression {
+            visitor.visit_expression(expression);
+        }
+        visitor.leave_svelte_node(kind);
+    }
+
+    pub fn walk_use_directive<'a, V: Visit<'a>>(visitor: &mut V, it: &UseDirective<'a>) {
+        let kind = SvelteAstKind::UseDirective(visitor.alloc(it));
+        visitor.enter_svelte_node(kind);
+        if let Some(expression) = &it.expression {
+            visitor.visit_expression(expression);
+        }
+        visitor.leave_svelte_node(kind);
+    }
+
+    pub fn walk_regular_element<'a, V: Visit<'a>>(visitor: &mut V, it: &RegularElement<'a>) {
+        let kind = SvelteAstKind::RegularElement(visitor.alloc(it));
+        visitor.enter_svelte_node(kind);
+        visitor.enter_scope(ScopeFlags::empty(), &it.scope_id);
+        for item in it.attributes.iter() {
+            visitor.visit_attribute(item);
+        }
+        visitor.visit_fragment(&it.fragment);
+        visitor.leave_scope();
+        visitor.leave_svelte_node(kind);
+    }
+
+    pub fn walk_comment<'a, V: Visit<'a>>(visitor: &mut V, it: &Comment<'a>) {
+        let kind = SvelteAstKind::Comment(visitor.alloc(it));
+        visitor.enter_svelte_node(kind);
+        visitor.leave_svelte_node(kind);
+    }
+
+    pub fn walk_svelte_head<'a, V: Visit<'a>>(visitor: &mut V, it: &SvelteHead<'a>) {
+        let kind = SvelteAstKind::SvelteHead(visitor.alloc(it));
+        visitor.enter_svelte_node(kind);
+        for item in it.attributes.iter() {
+            visitor.visit_attribute(item);
+        }
+        visitor.visit_fragment(&it.fragment);
+        visitor.leave_svelte_node(kind);
+    }
+
+    pub fn walk_svelte_window<'a, V: Visit<'a>>(visitor: &mut V, it: &SvelteWindow<'a>) {
+        let kind = SvelteAstKind::SvelteWindow(visitor.alloc(it));
+        visitor.enter_svelte_node(kind);
+        for item in it.attributes.iter() {
+            visitor.visit_attribute(item);
+        }
+        visitor.visit_fragment(&it.fragment);
+        visitor.leave_svelte_node(kind);
+    }
+
+    pub fn walk_svelte_document<'a, V: Visit<'a>>(visitor: &mut V, it: &SvelteDocument<'a>) {
+        let kind = SvelteAstKind::SvelteDocument(visitor.alloc(it));
+        visitor.enter_svelte_node(kind);
+        for item in it.attributes.iter() {
+            visitor.visit_attribute(item);
+        }
+        visitor.visit_fragment(&it.fragment);
+        visitor.leave_svelte_node(kind);
+    }
+
+    pub fn walk_svelte_body<'a, V: Visit<'a>>(visitor: &mut V, it: &SvelteBody<'a>) {
+        let kind = SvelteAstKind::SvelteBody(visitor.alloc(it));
+        visitor.enter_svelte_node(kind);
+        for item in it.attributes.iter() {
+            visitor.visit_attribute(item);
+        }
+        visitor.visit_fragment(&it.fragment);
+        visitor.leave_svelte_node(kind);
+    }
+
+    pub fn walk_svelte_element<'a, V: Visit<'a>>(visitor: &mut V, it: &SvelteElement<'a>) {
+        let kind = SvelteAstKind::SvelteElement(visitor.alloc(it));
+        visitor.enter_svelte_node(kind);
+        for item in it.attributes.iter() {
+            visitor.visit_attribute(item);
+        }
+        visitor.visit_fragment(&it.fragment);
+        visitor.visit_expression(&it.tag);
+        visitor.leave_svelte_node(kind);
+    }
+
+    pub fn walk_svelte_component<'a, V: Visit<'a>>(visitor: &mut V, it: &SvelteComponent<'a>) {
+        let kind = SvelteAstKind::SvelteComponent(visitor.alloc(it));
+        visitor.enter_svelte_node(kind);
+        for item in it.attributes.iter() {
+            visitor.visit_attribute(item);
+        }
+        visitor.visit_fragment(&it.fragment);
+        visitor.visit_expression(&it.expression);
+        visitor.leave_svelte_node(kind);
+    }
+
+    pub fn walk_svelte_self<'a, V: Visit<'a>>(visitor: &mut V, it: &SvelteSelf<'a>) {
+        let kind = SvelteAstKind::SvelteSelf(visitor.alloc(it));
+        visitor.enter_svelte_node(kind);
+        for item in it.attributes.iter() {
+            visitor.visit_attribute(item);
+        }
+        visitor.visit_fragment(&it.fragment);
+        visitor.leave_svelte_node(kind);
+    }
+
+    pub fn walk_svelte_fragment<'a, V: Visit<'a>>(visitor: &mut V, it: &SvelteFragment<'a>) {
+        let kind = SvelteAstKind::SvelteFragment(visitor.alloc(it));
+        visitor.enter_svelte_node(kind);
+        for item in it.attributes.iter() {
+            visitor.visit_attribute(item);
+        }
+        visitor.visit_fragment(&it.fragment);
+        visitor.leave_svelte_node(kind);
+    }
+
+    pub fn walk_title_element<'a, V: Visit<'a>>(visitor: &mut V, it: &TitleElement<'a>) {
+        let kind = SvelteAstKind::TitleElement(visitor.alloc(it));
+        visitor.enter_svelte_node(kind);
+        for item in it.attributes.iter() {
+            visitor.visit_attribute(item);
+        }
+        visitor.visit_fragment(&it.fragment);
+        visitor.leave_svelte_node(kind);
+    }
+
+    pub fn walk_slot_element<'a, V: Visit<'a>>(visitor: &mut V, it: &SlotElement<'a>) {
+        let kind = SvelteAstKind::SlotElement(visitor.alloc(it));
+        visitor.enter_svelte_node(kind);
+        for item in it.attributes.iter() {
+            visitor.visit_attribute(item);
+        }
+        visitor.visit_fragment(&it.fragment);
+        visitor.leave_svelte_node(kind);
+    }
+
+    pub fn walk_component<'a, V: Visit<'a>>(visitor: &mut V, it: &Component<'a>) {
+        let kind = SvelteAstKind::Component(visitor.alloc(it));
+        visitor.enter_svelte_node(kind);
+        for item in it.attributes.iter() {
+            visitor.visit_attribute(item);
+        }
+        visitor.visit_fragment(&it.fragment);
+        visitor.leave_svelte_node(kind);
+    }
+
+    pub fn walk_fragment<'a, V: Visit<'a>>(visitor: &mut V, it: &Fragment<'a>) {
+        let kind = SvelteAstKind::Fragment(visitor.alloc(it));
+        visitor.enter_svelte_node(kind);
+        visitor.enter_scope(ScopeFlags::empty(), &it.scope_id);
+        for item in it.nodes.iter() {
+            visitor.visit_fragment_node(item);
+        }
+        visitor.leave_scope();
+        visitor.leave_svelte_node(kind);
+    }
+
+    pub fn walk_root<'a, V: Visit<'a>>(visitor: &mut V, it: &Root<'a>) {
+        let kind = SvelteAstKind::Root(visitor.alloc(it));
+        visitor.enter_svelte_node(kind);
+        if let Some(options) = &it.options {
+            visitor.visit_svelte_options(options);
+        }
+        if let Some(module) = &it.module {
+            visitor.visit_script(module);
+        }
+        if let Some(instance) = &it.instance {
+            visitor.visit_script(instance);
+        }
+        visitor.visit_fragment(&it.fragment);
+        if let Some(css) = &it.css {
+            visitor.visit_style_sheet(css);
+        }
+        visitor.leave_svelte_node(kind);
+    }
+
+    pub fn walk_svelte_options<'a, V: Visit<'a>>(visitor: &mut V, it: &SvelteOptions<'a>) {
+        let kind = SvelteAstKind::SvelteOptions(visitor.alloc(it));
+        visitor.enter_svelte_node(kind);
+        for item in it.attributes.iter() {
+            visitor.visit_attribute(item);
+        }
+        visitor.leave_svelte_node(kind);
+    }
+
+    pub fn walk_custom_element<'a, V: Visit<'a>>(visitor: &mut V, it: &CustomElement<'a>) {
+        let kind = SvelteAstKind::CustomElement(visitor.alloc(it));
+        visitor.enter_svelte_node(kind);
+        if let Some(props) = &it.props {
+            visitor.visit_object_expression(props);
+        }
+        if let Some(extend) = &it.extend {
+            visitor.visit_expression(extend);
+        }
+        visitor.leave_svelte_node(kind);
+    }
+
+    pub fn walk_script<'a, V: Visit<'a>>(visitor: &mut V, it: &Script<'a>) {
+        let kind = SvelteAstKind::Script(visitor.alloc(it));
+        visitor.enter_svelte_node(kind);
+        visitor.visit_program(&it.content);
+        visitor.leave_svelte_node(kind);
+    }
+
+    pub fn walk_style_sheet<'a, V: Visit<'a>>(visitor: &mut V, it: &StyleSheet<'a>) {
+        let kind = SvelteAstKind::StyleSheet(visitor.alloc(it));
+        visitor.enter_svelte_node(kind);
+        for item in it.attributes.iter() {
+            visitor.visit_attribute(item);
+        }
+        for item in it.children.iter() {
+            visitor.visit_style_sheet_child(item);
+        }
+        visitor.leave_svelte_node(kind);
+    }
+
+    pub fn walk_style_sheet_content<'a, V: Visit<'a>>(visitor: &mut V, it: &StyleSheetContent<'a>) {
+        let kind = SvelteAstKind::StyleSheetContent(visitor.alloc(it));
+        visitor.enter_svelte_node(kind);
+        if let Some(comment) = &it.comment {
+            visitor.visit_comment(comment);
+        }
+        visitor.leave_svelte_node(kind);
+    }
+
+    pub fn walk_at_rule<'a, V: Visit<'a>>(visitor: &mut V, it: &AtRule<'a>) {
+        let kind = SvelteAstKind::AtRule(visitor.alloc(it));
+        visitor.enter_svelte_node(kind);
+        if let Some(block) = &it.block {
+            visitor.visit_css_block(block);
+        }
+        visitor.leave_svelte_node(kind);
+    }
+
+    pub fn walk_relative_selector<'a, V: Visit<'a>>(visitor: &mut V, it: &RelativeSelector<'a>) {
+        let kind = SvelteAstKind::RelativeSelector(visitor.alloc(it));
+        visitor.enter_svelte_node(kind);
+        if let Some(combinator) = &it.combinator {
+            visitor.visit_combinator(combinator);
+        }
+        for item in it.selectors.iter() {
+            visitor.visit_simple_selector(item);
+        }
+        visitor.leave_svelte_node(kind);
+    }
+
+    pub fn walk_type_selector<'a, V: Visit<'a>>(visitor: &mut V, it: &TypeSelector<'a>) {
+        let kind = SvelteAstKind::TypeSelector(visitor.alloc(it));
+        visitor.enter_svelte_node(kind);
+        visitor.leave_svelte_node(kind);
+    }
+
+    pub fn walk_id_selector<'a, V: Visit<'a>>(visitor: &mut V, it: &IdSelector<'a>) {
+        let kind = SvelteAstKind::IdSelector(visitor.alloc(it));
+        visitor.enter_svelte_node(kind);
+        visitor.leave_svelte_node(kind);
+    }
+
+    pub fn walk_class_selector<'a, V: Visit<'a>>(visitor: &mut V, it: &ClassSelector<'a>) {
+        let kind = SvelteAstKind::ClassSelector(visitor.alloc(it));
+        visitor.enter_svelte_node(kind);
+        visitor.leave_svelte_node(kind);
+    }
+
+    pub fn walk_attribute_selector<'a, V: Visit<'a>>(visitor: &mut V, it: &AttributeSelector<'a>) {
+        let kind = SvelteAstKind::AttributeSelector(visitor.alloc(it));
+        visitor.enter_svelte_node(kind);
+        visitor.leave_svelte_node(kind);
+    }
+
+    pub fn walk_pseudo_element_selector<'a, V: Visit<'a>>(
+        visitor: &mut V,
+        it: &PseudoElementSelector<'a>,
+    ) {
+        let kind = SvelteAstKind::PseudoElementSelector(visitor.alloc(it));
+        visitor.enter_svelte_node(kind);
+        visitor.leave_svelte_node(kind);
+    }
+
+    pub fn walk_pseudo_class_selector<'a, V: Visit<'a>>(
+        visitor: &mut V,
+        it: &PseudoClassSelector<'a>,
+    ) {
+        let kind = SvelteAstKind::PseudoClassSelector(visitor.alloc(it));
+        visitor.enter_svelte_node(kind);
+        if let Some(args) = &it.args {
+            visitor.visit_selector_list(args);
+        }
+        visitor.leave_svelte_node(kind);
+    }
+
+    pub fn walk_percentage<'a, V: Visit<'a>>(visitor: &mut V, it: &Percentage<'a>) {
+        let kind = SvelteAstKind::Percentage(visitor.alloc(it));
+        visitor.enter_svelte_node(kind);
+        visitor.leave_svelte_node(kind);
+    }
+
+    pub fn walk_nesting_selector<'a, V: Visit<'a>>(visitor: &mut V, it: &NestingSelector<'a>) {
+        let kind = SvelteAstKind::NestingSelector(visitor.alloc(it));
+        visitor.enter_svelte_node(kind);
+        visitor.leave_svelte_node(kind);
+    }
+
+    pub fn walk_nth<'a, V: Visit<'a>>(visitor: &mut V, it: &Nth<'a>) {
+        let kind = SvelteAstKind::Nth(visitor.alloc(it));
+        visitor.enter_svelte_node(kind);
+        visitor.leave_svelte_node(kind);
+    }
+
+    pub fn walk_complex_selector<'a, V: Visit<'a>>(visitor: &mut V, it: &ComplexSelector<'a>) {
+        let kind = SvelteAstKind::ComplexSelector(visitor.alloc(it));
+        visitor.enter_svelte_node(kind);
+        for item in it.children.iter() {
+            visitor.visit_relative_selector(item);
+        }
+        visitor.leave_svelte_node(kind);
+    }
+
+    pub fn walk_rule<'a, V: Visit<'a>>(visitor: &mut V, it: &Rule<'a>) {
+        let kind = SvelteAstKind::Rule(visitor.alloc(it));
+        visitor.enter_svelte_node(kind);
+        visitor.visit_selector_list(&it.prelude);
+        visitor.visit_css_block(&it.block);
+        visitor.leave_svelte_node(kind);
+    }
+
+    pub fn walk_css_declaration<'a, V: Visit<'a>>(visitor: &mut V, it: &Declaration<'a>) {
+        let kind = SvelteAstKind::Declaration(visitor.alloc(it));
+        visitor.enter_svelte_node(kind);
+        visitor.leave_svelte_node(kind);
+    }
+
+    pub fn walk_css_block<'a, V: Visit<'a>>(visitor: &mut V, it: &CSSBlock<'a>) {
+        let kind = SvelteAstKind::CSSBlock(visitor.alloc(it));
+        visitor.enter_svelte_node(kind);
+        for item in it.children.iter() {
+            visitor.visit_block_child(item);
+        }
+        visitor.leave_svelte_node(kind);
+    }
+
+    pub fn walk_selector_list<'a, V: Visit<'a>>(visitor: &mut V, it: &SelectorList<'a>) {
+        let kind = SvelteAstKind::SelectorList(visitor.alloc(it));
+        visitor.enter_svelte_node(kind);
+        for item in it.children.iter() {
+            visitor.visit_complex_selector(item);
+        }
+        visitor.leave_svelte_node(kind);
+    }
+
+    pub fn walk_combinator<'a, V: Visit<'a>>(visitor: &mut V, it: &Combinator<'a>) {
+        let kind = SvelteAstKind::Combinator(visitor.alloc(it));
+        visitor.enter_svelte_node(kind);
+        visitor.leave_svelte_node(kind);
+    }
+
+    pub fn walk_expression_tag<'a, V: Visit<'a>>(visitor: &mut V, it: &ExpressionTag<'a>) {
+        let kind = SvelteAstKind::ExpressionTag(visitor.alloc(it));
+        visitor.enter_svelte_node(kind);
+        visitor.visit_expression(&it.expression);
+        visitor.leave_svelte_node(kind);
+    }
+
+    pub fn walk_html_tag<'a, V: Visit<'a>>(visitor: &mut V, it: &HtmlTag<'a>) {
+        let kind = SvelteAstKind::HtmlTag(visitor.alloc(it));
+        visitor.enter_svelte_node(kind);
+        visitor.visit_expression(&it.expression);
+        visitor.leave_svelte_node(kind);
+    }
+
+    pub fn walk_debug_tag<'a, V: Visit<'a>>(visitor: &mut V, it: &DebugTag<'a>) {
+        let kind = SvelteAstKind::DebugTag(visitor.alloc(it));
+        visitor.enter_svelte_node(kind);
+        for item in it.identifiers.iter() {
+            visitor.visit_identifier_reference(item);
+        }
+        visitor.leave_svelte_node(kind);
+    }
+
+    pub fn walk_const_tag<'a, V: Visit<'a>>(visitor: &mut V, it: &ConstTag<'a>) {
+        let kind = SvelteAstKind::ConstTag(visitor.alloc(it));
+        visitor.enter_svelte_node(kind);
+        visitor.visit_variable_declaration(&it.declaration);
+        visitor.leave_svelte_node(kind);
+    }
+
+    pub fn walk_render_tag<'a, V: Visit<'a>>(visitor: &mut V, it: &RenderTag<'a>) {
+        let kind = SvelteAstKind::RenderTag(visitor.alloc(it));
+        visitor.enter_svelte_node(kind);
+        visitor.visit_render_tag_expression(&it.expression);
+        visitor.leave_svelte_node(kind);
+    }
+
+    pub fn walk_text<'a, V: Visit<'a>>(visitor: &mut V, it: &Text<'a>) {
+        let kind = SvelteAstKind::Text(visitor.alloc(it));
+        visitor.enter_svelte_node(kind);
+        visitor.leave_svelte_node(kind);
+    }
+
+    pub fn walk_attribute<'a, V: Visit<'a>>(visitor: &mut V, it: &Attribute<'a>) {
+        match it {
+            Attribute::NormalAttribute(it) => visitor.visit_normal_attribute(it),
+            Attribute::SpreadAttribute(it) => visitor.visit_spread_attribute(it),
+            Attribute::Directive(it) => visitor.visit_svelte_directive(it),
+        }
+    }
+
+    pub fn walk_attribute_value<'a, V: Visit<'a>>(visitor: &mut V, it: &AttributeValue<'a>) {
+        match it {
+            AttributeValue::ExpressionTag(it) => visitor.visit_expression_tag(it),
+            AttributeValue::Quoted(it) => {
+                for item in it.iter() {
+                    visitor.visit_quoted_attribute_value(item);
+                }
+            }
+            AttributeValue::True => {}
+        }
+    }
+
+    pub fn walk_quoted_attribute_value<'a, V: Visit<'a>>(
+        visitor: &mut V,
+        it: &QuotedAttributeValue<'a>,
+    ) {
+        match it {
+            QuotedAttributeValue::ExpressionTag(it) => visitor.visit_expression_tag(it),
+            QuotedAttributeValue::Text(it) => visitor.visit_text(it),
+        }
+    }
+
+    pub fn walk_block<'a, V: Visit<'a>>(visitor: &mut V, it: &Block<'a>) {
+        match it {
+            Block::IfBlock(it) => visitor.visit_if_block(it),
+            Block::EachBlock(it) => visitor.visit_each_block(it),
+            Block::AwaitBlock(it) => visitor.visit_await_block(it),
+            Block::KeyBlock(it) => visitor.visit_key_block(it),
+            Block::SnippetBlock(it) => visitor.visit_snippet_block(it),
+        }
+    }
+
+    pub fn walk_svelte_directive<'a, V: Visit<'a>>(visitor: &mut V, it: &Directive<'a>) {
+        match it {
+            Directive::AnimateDirective(it) => visitor.visit_animate_directive(it),
+            Directive::BindDirective(it) => visitor.visit_bind_directive(it),
+            Directive::ClassDirective(it) => visitor.visit_class_directive(it),
+            Directive::LetDirective(it) => visitor.visit_let_directive(it),
+            Directive::OnDirective(it) => visitor.visit_on_directive(it),
+            Directive::StyleDirective(it) => visitor.visit_style_directive(it),
+            Directive::TransitionDirective(it) => visitor.visit_transition_directive(it),
+            Directive::UseDirective(it) => visitor.visit_use_directive(it),
+        }
+    }
+
+    pub fn walk_element<'a, V: Visit<'a>>(visitor: &mut V, it: &Element<'a>) {
+        match it {
+            Element::RegularElement(it) => visitor.visit_regular_element(it),
+            Element::SvelteComponent(it) => visitor.visit_svelte_component(it),
+            Element::SvelteElement(it) => visitor.visit_svelte_element(it),
+            Element::SvelteBody(it) => visitor.visit_svelte_body(it),
+            Element::SvelteWindow(it) => visitor.visit_svelte_window(it),
+            Element::SvelteDocument(it) => visitor.visit_svelte_document(it),
+            Element::SvelteHead(it) => visitor.visit_svelte_head(it),
+            Element::SvelteFragment(it) => visitor.visit_svelte_fragment(it),
+            Element::SvelteSelf(it) => visitor.visit_svelte_self(it),
+            Element::TitleElement(it) => visitor.visit_title_element(it),
+            Element::SlotElement(it) => visitor.visit_slot_element(it),
+            Element::Component(it) => visitor.visit_component(it),
+        }
+    }
+
+    pub fn walk_fragment_node<'a, V: Visit<'a>>(visitor: &mut V, it: &FragmentNode<'a>) {
+        match it {
+            FragmentNode::Text(it) => visitor.visit_text(it),
+            FragmentNode::Element(it) => visitor.visit_element(it),
+            FragmentNode::Tag(it) => visitor.visit_tag(it),
+            FragmentNode::Comment(it) => visitor.visit_comment(it),
+            FragmentNode::Block(it) => visitor.visit_block(it),
+        }
+    }
+
+    pub fn walk_style_sheet_child<'a, V: Visit<'a>>(visitor: &mut V, it: &StyleSheetChild<'a>) {
+        match it {
+            StyleSheetChild::AtRule(it) => visitor.visit_at_rule(it),
+            StyleSheetChild::Rule(it) => visitor.visit_rule(it),
+        }
+    }
+
+    pub fn walk_simple_selector<'a, V: Visit<'a>>(visitor: &mut V, it: &SimpleSelector<'a>) {
+        match it {
+            SimpleSelector::TypeSelector(it) => visitor.visit_type_selector(it),
+            SimpleSelector::IdSelector(it) => visitor.visit_id_selector(it),
+            SimpleSelector::ClassSelector(it) => visitor.visit_class_selector(it),
+            SimpleSelector::AttributeSelector(it) => visitor.visit_attribute_selector(it),
+            SimpleSelector::PseudoElementSelector(it) => visitor.visit_pseudo_element_selector(it),
+            SimpleSelector::PseudoClassSelector(it) => visitor.visit_pseudo_class_selector(it),
+            SimpleSelector::Percentage(it) => visitor.visit_percentage(it),
+            SimpleSelector::Nth(it) => visitor.visit_nth(it),
+            SimpleSelector::NestingSelector(it) => visitor.visit_nesting_selector(it),
+        }
+    }
+
+    pub fn walk_block_child<'a, V: Visit<'a>>(visitor: &mut V, it: &BlockChild<'a>) {
+        match it {
+            BlockChild::Rule(it) => visitor.visit_rule(it),
+            BlockChild::AtRule(it) => visitor.visit_at_rule(it),
+            BlockChild::Declaration(it) => visitor.visit_css_declaration(it),
+        }
+    }
+
+    pub fn walk_tag<'a, V: Visit<'a>>(visitor: &mut V, it: &Tag<'a>) {
+        match it {
+            Tag::ExpressionTag(it) => visitor.visit_expression_tag(it),
+            Tag::HtmlTag(it) => visitor.visit_html_tag(it),
+            Tag::DebugTag(it) => visitor.visit_debug_tag(it),
+            Tag::ConstTag(it) => visitor.visit_const_tag(it),
+            Tag::RenderTag(it) => visitor.visit_render_tag(it),
+        }
+    }
+
+    pub fn walk_render_tag_expression<'a, V: Visit<'a>>(
+        visitor: &mut V,
+        it: &RenderTagExpression<'a>,
+    ) {
+        match it {
+            RenderTagExpression::CallExpression(it) => visitor.visit_call_expression(it),
+            RenderTagExpression::ChainExpression(it) => visitor.visit_chain_expression(it),
+        }
+    }
+}
@@ -0,0 +1,1243 @@
+// Auto-generated code, DO NOT EDIT DIRECTLY!
+// To edit this generated file you have to edit `crates/rusvelte_ast/src/ast`
+// and run `just codegen`.
+
+#![allow(unused_variables)]
+pub use oxc_ast::VisitMut as JsVisitMut;
+
+use oxc_allocator::Vec as OxcVec;
+use oxc_ast::ast::Statement;
+
+use crate::{ast::*, ast_kind::SvelteAstType};
+use walk_mut::*;
+
+pub trait VisitMut<'a>: JsVisitMut<'a> {
+    fn enter_svelte_node(&mut self, kind: SvelteAstType) {}
+    fn leave_svelte_node(&mut self, kind: SvelteAstType) {}
+    fn vec<T, const N: usize>(&self, array: [T; N]) -> OxcVec<'a, T>;
+
+    fn visit_normal_attribute(
+        &mut self,
+        it: &mut NormalAttribute<'a>,
+    ) -> OxcVec<'a, Statement<'a>> {
+        walk_normal_attribute(self, it)
+    }
+    fn visit_spread_attribute(
+        &mut self,
+        it: &mut SpreadAttribute<'a>,
+    ) -> OxcVec<'a, Statement<'a>> {
+        walk_spread_attribute(self, it)
+    }
+    fn visit_if_block(&mut self, it: &mut IfBlock<'a>) -> OxcVec<'a, Statement<'a>> {
+        walk_if_block(self, it)
+    }
+    fn visit_each_block(&mut self, it: &mut EachBlock<'a>) -> OxcVec<'a, Statement<'a>> {
+        walk_each_block(self, it)
+    }
+    fn visit_await_block(&mut self, it: &mut AwaitBlock<'a>) -> OxcVec<'a, Statement<'a>> {
+        walk_await_block(self, it)
+    }
+    fn visit_key_block(&mut self, it: &mut KeyBlock<'a>) -> OxcVec<'a, Statement<'a>> {
+        walk_key_block(self, it)
+    }
+    fn visit_snippet_block(&mut self, it: &mut SnippetBlock<'a>) -> OxcVec<'a, Statement<'a>> {
+        walk_snippet_block(self, it)
+    }
+    fn visit_animate_directive(
+        &mut self,
+        it: &mut AnimateDirective<'a>,
+    ) -> OxcVec<'a, Statement<'a>> {
+        walk_animate_directive(self, it)
+    }
+    fn visit_bind_directive(&mut self, it: &mut BindDirective<'a>) -> OxcVec<'a, Statement<'a>> {
+        walk_bind_directive(self, it)
+    }
+    fn visit_class_directive(&mut self, it: &mut ClassDirective<'a>) -> OxcVec<'a, Statement<'a>> {
+        walk_class_directive(self, it)
+    }
+    fn visit_let_directive(&mut self, it: &mut LetDirective<'a>) -> OxcVec<'a, Statement<'a>> {
+        walk_let_directive(self, it)
+    }
+    fn visit_on_directive(&mut self, it: &mut OnDirective<'a>) -> OxcVec<'a, Statement<'a>> {
+        walk_on_directive(self, it)
+    }
+    fn visit_style_directive(&mut self, it: &mut StyleDirective<'a>) -> OxcVec<'a, Statement<'a>> {
+        walk_style_directive(self, it)
+    }
+    fn visit_transition_directive(
+        &mut self,
+        it: &mut TransitionDirective<'a>,
+    ) -> OxcVec<'a, Statement<'a>> {
+        walk_transition_directive(self, it)
+    }
+    fn visit_use_directive(&mut self, it: &mut UseDirective<'a>) -> OxcVec<'a, Statement<'a>> {
+        walk_use_directive(self, it)
+    }
+    fn visit_regular_element(&mut self, it: &mut RegularElement<'a>) -> OxcVec<'a, Statement<'a>> {
+        walk_regular_element(self, it)
+    }
+    fn visit_comment(&mut self, it: &mut Comment<'a>) -> OxcVec<'a, Statement<'a>> {
+        walk_comment(self, it)
+    }
+    fn visit_svelte_head(&mut self, it: &mut SvelteHead<'a>) -> OxcVec<'a, Statement<'a>> {
+        walk_svelte_head(self, it)
+    }
+    fn visit_svelte_window(&mut self, it: &mut SvelteWindow<'a>) -> OxcVec<'a, Statement<'a>> {
+        walk_svelte_window(self, it)
+    }
+    fn visit_svelte_document(&mut self, it: &mut SvelteDocument<'a>) -> OxcVec<'a, Statement<'a>> {
+        walk_svelte_document(self, it)
+    }
+    fn visit_svelte_body(&mut self, it: &mut SvelteBody<'a>) -> OxcVec<'a, Statement<'a>> {
+        walk_svelte_body(self, it)
+    }
+    fn visit_svelte_element(&mut self, it: &mut SvelteElement<'a>) -> OxcVec<'a, Statement<'a>> {
+        walk_svelte_element(self, it)
+    }
+    fn visit_svelte_component(
+        &mut self,
+        it: &mut SvelteComponent<'a>,
+    ) -> OxcVec<'a, Statement<'a>> {
+        walk_svelte_component(self, it)
+    }
+    fn visit_svelte_self(&mut self, it: &mut SvelteSelf<'a>) -> OxcVec<'a, Statement<'a>> {
+        walk_svelte_self(self, it)
+    }
+    fn visit_svelte_fragment(&mut self, it: &mut SvelteFragment<'a>) -> OxcVec<'a, Statement<'a>> {
+        walk_svelte_fragment(self, it)
+    }
+    fn visit_title_element(&mut self, it: &mut TitleElement<'a>) -> OxcVec<'a, Statement<'a>> {
+        walk_title_element(self, it)
+    }
+    fn visit_slot_element(&mut self, it: &mut SlotElement<'a>) -> OxcVec<'a, Statement<'a>> {
+        walk_slot_element(self, it)
+    }
+    fn visit_component(&mut self, it: &mut Component<'a>) -> OxcVec<'a, Statement<'a>> {
+        walk_component(self, it)
+    }
+    fn visit_fragment(&mut self, it: &mut Fragment<'a>) -> OxcVec<'a, Statement<'a>> {
+        walk_fragment(self, it)
+    }
+    fn visit_root(&mut self, it: &mut Root<'a>) -> OxcVec<'a, Statement<'a>> {
+        walk_root(self, it)
+    }
+    fn visit_svelte_options(&mut self, it: &mut SvelteOptions<'a>) -> OxcVec<'a, Statement<'a>> {
+        walk_svelte_options(self, it)
+    }
+    fn visit_custom_element(&mut self, it: &mut CustomElement<'a>) -> OxcVec<'a, Statement<'a>> {
+        walk_custom_element(self, it)
+    }
+    fn visit_script(&mut self, it: &mut Script<'a>) -> OxcVec<'a, Statement<'a>> {
+        walk_script(self, it)
+    }
+    fn visit_style_sheet(&mut self, it: &mut StyleSheet<'a>) -> OxcVec<'a, Statement<'a>> {
+        walk_style_sheet(self, it)
+    }
+    fn visit_style_sheet_content(
+        &mut self,
+        it: &mut StyleSheetContent<'a>,
+    ) -> OxcVec<'a, Statement<'a>> {
+        walk_style_sheet_content(self, it)
+    }
+    fn visit_at_rule(&mut self, it: &mut AtRule<'a>) -> OxcVec<'a, Statement<'a>> {
+        walk_at_rule(self, it)
+    }
+    fn visit_relative_selector(
+        &mut self,
+        it: &mut RelativeSelector<'a>,
+    ) -> OxcVec<'a, Statement<'a>> {
+        walk_relative_selector(self, it)
+    }
+    fn visit_type_selector(&mut self, it: &mut TypeSelector<'a>) -> OxcVec<'a, Statement<'a>> {
+        walk_type_selector(self, it)
+    }
+    fn visit_id_selector(&mut self, it: &mut IdSelector<'a>) -> OxcVec<'a, Statement<'a>> {
+        walk_id_selector(self, it)
+    }
+    fn visit_class_selector(&mut self, it: &mut ClassSelector<'a>) -> OxcVec<'a, Statement<'a>> {
+        walk_class_selector(self, it)
+    }
+    fn visit_attribute_selector(
+        &mut self,
+        it: &mut AttributeSelector<'a>,
+    ) -> OxcVec<'a, Statement<'a>> {
+        walk_attribute_selector(self, it)
+    }
+    fn visit_pseudo_element_selector(
+        &mut self,
+        it: &mut PseudoElementSelector<'a>,
+    ) -> OxcVec<'a, Statement<'a>> {
+        walk_pseudo_element_selector(self, it)
+    }
+    fn visit_pseudo_class_selector(
+        &mut self,
+        it: &mut PseudoClassSelector<'a>,
+    ) -> OxcVec<'a, Statement<'a>> {
+        walk_pseudo_class_selector(self, it)
+    }
+    fn visit_percentage(&mut self, it: &mut Percentage<'a>) -> OxcVec<'a, Statement<'a>> {
+        walk_percentage(self, it)
+    }
+    fn visit_nesting_selector(
+        &mut self,
+        it: &mut NestingSelector<'a>,
+    ) -> OxcVec<'a, Statement<'a>> {
+        walk_nesting_selector(self, it)
+    }
+    fn visit_nth(&mut self, it: &mut Nth<'a>) -> OxcVec<'a, Statement<'a>> {
+        walk_nth(self, it)
+    }
+    fn visit_complex_selector(
+        &mut self,
+        it: &mut ComplexSelector<'a>,
+    ) -> OxcVec<'a, Statement<'a>> {
+        walk_complex_selector(self, it)
+    }
+    fn visit_rule(&mut self, it: &mut Rule<'a>) -> OxcVec<'a, Statement<'a>> {
+        walk_rule(self, it)
+    }
+    fn visit_css_declaration(&mut self, it: &mut Declaration<'a>) -> OxcVec<'a, Statement<'a>> {
+        walk_css_declaration(self, it)
+    }
+    fn visit_css_block(&mut self, it: &mut CSSBlock<'a>) -> OxcVec<'a, Statement<'a>> {
+        walk_css_block(self, it)
+    }
+    fn visit_selector_list(&mut self, it: &mut SelectorList<'a>) -> OxcVec<'a, Statement<'a>> {
+        walk_selector_list(self, it)
+    }
+    fn visit_combinator(&mut self, it: &mut Combinator<'a>) -> OxcVec<'a, Statement<'a>> {
+        walk_combinator(self, it)
+    }
+    fn visit_expression_tag(&mut self, it: &mut ExpressionTag<'a>) -> OxcVec<'a, Statement<'a>> {
+        walk_expression_tag(self, it)
+    }
+    fn visit_html_tag(&mut self, it: &mut HtmlTag<'a>) -> OxcVec<'a, Statement<'a>> {
+        walk_html_tag(self, it)
+    }
+    fn visit_debug_tag(&mut self, it: &mut DebugTag<'a>) -> OxcVec<'a, Statement<'a>> {
+        walk_debug_tag(self, it)
+    }
+    fn visit_const_tag(&mut self, it: &mut ConstTag<'a>) -> OxcVec<'a, Statement<'a>> {
+        walk_const_tag(self, it)
+    }
+    fn visit_render_tag(&mut self, it: &mut RenderTag<'a>) -> OxcVec<'a, Statement<'a>> {
+        walk_render_tag(self, it)
+    }
+    fn visit_text(&mut self, it: &mut Text<'a>) -> OxcVec<'a, Statement<'a>> {
+        walk_text(self, it)
+    }
+    fn visit_attribute(&mut self, it: &mut Attribute<'a>) -> OxcVec<'a, Statement<'a>> {
+        walk_attribute(self, it)
+    }
+    fn visit_attribute_value(&mut self, it: &mut AttributeValue<'a>) -> OxcVec<'a, Statement<'a>> {
+        walk_attribute_value(self, it)
+    }
+    fn visit_quoted_attribute_value(
+        &mut self,
+        it: &mut QuotedAttributeValue<'a>,
+    ) -> OxcVec<'a, Statement<'a>> {
+        walk_quoted_attribute_value(self, it)
+    }
+    fn visit_block(&mut self, it: &mut Block<'a>) -> OxcVec<'a, Statement<'a>> {
+        walk_block(self, it)
+    }
+    fn visit_svelte_directive(&mut self, it: &mut Directive<'a>) -> OxcVec<'a, Statement<'a>> {
+        walk_svelte_directive(self, it)
+    }
+    fn visit_element(&mut self, it: &mut Element<'a>) -> OxcVec<'a, Statement<'a>> {
+        walk_element(self, it)
+    }
+    fn visit_fragment_node(&mut self, it: &mut FragmentNode<'a>) -> OxcVec<'a, Statement<'a>> {
+        walk_fragment_node(self, it)
+    }
+    fn visit_style_sheet_child(
+        &mut self,
+        it: &mut StyleSheetChild<'a>,
+    ) -> OxcVec<'a, Statement<'a>> {
+        walk_style_sheet_child(self, it)
+    }
+    fn visit_simple_selector(&mut self, it: &mut SimpleSelector<'a>) -> OxcVec<'a, Statement<'a>> {
+        walk_simple_selector(self, it)
+    }
+    fn visit_block_child(&mut self, it: &mut BlockChild<'a>) -> OxcVec<'a, Statement<'a>> {
+        walk_block_child(self, it)
+    }
+    fn visit_tag(&mut self, it: &mut Tag<'a>) -> OxcVec<'a, Statement<'a>> {
+        walk_tag(self, it)
+    }
+    fn visit_render_tag_expression(
+        &mut self,
+        it: &mut RenderTagExpression<'a>,
+    ) -> OxcVec<'a, Statement<'a>> {
+        walk_render_tag_expression(self, it)
+    }
+}
+
+pub mod walk_mut {
+    use oxc_syntax::scope::ScopeFlags;
+
+    use super::*;
+
+    pub fn walk_normal_attribute<'a, V: VisitMut<'a>>(
+        visitor: &mut V,
+        it: &mut NormalAttribute<'a>,
+    ) -> OxcVec<'a, Statement<'a>> {
+        let kind = SvelteAstType::NormalAttribute;
+        let mut result = visitor.vec([]);
+        visitor.enter_svelte_node(kind);
+        result.append(&mut visitor.visit_attribute_value(&mut it.value));
+        visitor.leave_svelte_node(kind);
+        result
+    }
+
+    pub fn walk_spread_attribute<'a, V: VisitMut<'a>>(
+        visitor: &mut V,
+        it: &mut SpreadAttribute<'a>,
+    ) -> OxcVec<'a, Statement<'a>> {
+        let kind = SvelteAstType::SpreadAttribute;
+        visitor.enter_svelte_node(kind);
+        visitor.visit_expression(&mut it.expression);
+        visitor.leave_svelte_node(kind);
+        visitor.vec([])
+    }
+
+    pub fn walk_if_block<'a, V: VisitMut<'a>>(
+        visitor: &mut V,
+        it: &mut IfBlock<'a>,
+    ) -> OxcVec<'a, Statement<'a>> {
+        let kind = SvelteAstType::IfBlock;
+        let mut result = visitor.vec([]);
+        visitor.enter_svelte_node(kind);
+        visitor.visit_expression(&mut it.test);
+        result.append(&mut visitor.visit_fragment(&mut it.consequent));
+        if let Some(alternate) = &mut it.alternate {
+            result.append(&mut visitor.visit_fragment(alternate));
+        }
+        visitor.leave_svelte_node(kind);
+        result
+    }
+
+    pub fn walk_each_block<'a, V: VisitMut<'a>>(
+        visitor: &mut V,
+        it: &mut EachBlock<'a>,
+    ) -> OxcVec<'a, Statement<'a>> {
+        let kind = SvelteAstType::EachBlock;
+        let mut result = visitor.vec([]);
+        visitor.enter_svelte_node(kind);
+        visitor.visit_expression(&mut it.expression);
+        visitor.enter_scope(ScopeFlags::empty(), &it.scope_id);
+        visitor.visit_binding_pattern(&mut it.context);
+        if let Some(key) = &mut it.key {
+            visitor.visit_expression(key);
+        }
+        result.append(&mut visitor.visit_fragment(&mut it.body));
+        visitor.leave_scope();
+        if let Some(fallback) = &mut it.fallback {
+            result.append(&mut visitor.visit_fragment(fallback));
+        }
+        visitor.leave_svelte_node(kind);
+        result
+    }
+
+    pub fn walk_await_block<'a, V: VisitMut<'a>>(
+        visitor: &mut V,
+        it: &mut AwaitBlock<'a>,
+    ) -> OxcVec<'a, Statement<'a>> {
+        let kind = SvelteAstType::AwaitBlock;
+        let mut result = visitor.vec([]);
+        visitor.enter_svelte_node(kind);
+        visitor.visit_expression(&mut it.expression);
+        if let Some(pending) = &mut it.pending {
+            result.append(&mut visitor.visit_fragment(pending));
+        }
+        visitor.enter_scope(ScopeFlags::empty(), &it.then_scope_id);
+        if let Some(value) = &mut it.value {
+            visitor.visit_binding_pattern(value);
+        }
+        if let Some(then) = &mut it.then {
+            result.append(&mut visitor.visit_fragment(then));
+        }
+        visitor.leave_scope();
+        visitor.enter_scope(ScopeFlags::empty(), &it.catch_scope_id);
+        if let Some(error) = &mut it.error {
+            visitor.visit_binding_pattern(error);
+        }
+        if let Some(catch) = &mut it.catch {
+            result.append(&mut visitor.visit_fragment(catch));
+        }
+        visitor.leave_scope();
+        visitor.leave_svelte_node(kind);
+        result
+    }
+
+    pub fn walk_key_block<'a, V: VisitMut<'a>>(
+        visitor: &mut V,
+        it: &mut KeyBlock<'a>,
+    ) -> OxcVec<'a, Statement<'a>> {
+        let kind = SvelteAstType::KeyBlock;
+        let mut result = visitor.vec([]);
+        visitor.enter_svelte_node(kind);
+        visitor.visit_expression(&mut it.expression);
+        result.append(&mut visitor.visit_fragment(&mut it.fragment));
+        visitor.leave_svelte_node(kind);
+        result
+    }
+
+    pub fn walk_snippet_block<'a, V: VisitMut<'a>>(
+        visitor: &mut V,
+        it: &mut SnippetBlock<'a>,
+    ) -> OxcVec<'a, Statement<'a>> {
+        let kind = SvelteAstType::SnippetBlock;
+        let mut result = visitor.vec([]);
+        visitor.enter_svelte_node(kind);
+        visitor.visit_binding_identifier(&mut it.expression);
+        visitor.enter_scope(ScopeFlags::empty(), &it.scope_id);
+        for item in it.parameters.iter_mut() {
+            visitor.visit_binding_pattern(item);
+        }
+        result.append(&mut visitor.visit_fragment(&mut it.body));
+        visitor.leave_scope();
+        visitor.leave_svelte_node(kind);
+        result
+    }
+
+    pub fn walk_animate_directive<'a, V: VisitMut<'a>>(
+        visitor: &mut V,
+        it: &mut AnimateDirective<'a>,
+    ) -> OxcVec<'a, Statement<'a>> {
+        let kind = SvelteAstType::AnimateDirective;
+        visitor.enter_svelte_node(kind);
+        if let Some(expression) = &mut it.expression {
+            visitor.visit_expression(expression);
+        }
+        visitor.leave_svelte_node(kind);
+        visitor.vec([])
+    }
+
+    pub fn walk_bind_directive<'a, V: VisitMut<'a>>(
+        visitor: &mut V,
+        it: &mut BindDirective<'a>,
+    ) -> OxcVec<'a, Statement<'a>> {
+        let kind = SvelteAstType::BindDirective;
+        visitor.enter_svelte_node(kind);
+        visitor.visit_expression(&mut it.expression);
+        visitor.leave_svelte_node(kind);
+        visitor.vec([])
+    }
+
+    pub fn walk_class_directive<'a, V: VisitMut<'a>>(
+        visitor: &mut V,
+        it: &mut ClassDirective<'a>,
+    ) -> OxcVec<'a, Statement<'a>> {
+        let kind = SvelteAstType::ClassDirective;
+        visitor.enter_svelte_node(kind);
+        visitor.visit_expression(&mut it.expression);
+        visitor.leave_svelte_node(kind);
+        visitor.vec([])
+    }
+
+    pub fn walk_let_directive<'a, V: VisitMut<'a>>(
+        visitor: &mut V,
+        it: &mut LetDirective<'a>,
+    ) -> OxcVec<'a, Statement<'a>> {
+        let kind = SvelteAstType::LetDirective;
+        visitor.enter_svelte_node(kind);
+        if let Some(expression) = &mut it.expression {
+            visitor.visit_expression(expression);
+        }
+        visitor.leave_svelte_node(kind);
+        visitor.vec([])
+    }
+
+    pub fn walk_on_directive<'a, V: VisitMut<'a>>(
+        visitor: &mut V,
+        it: &mut OnDirective<'a>,
+    ) -> OxcVec<'a, Statement<'a>> {
+        let kind = SvelteAstType::OnDirective;
+        visitor.enter_svelte_node(kind);
+        if let Some(expression) = &mut it.expression {
+            visitor.visit_expression(expression);
+        }
+        visitor.leave_svelte_node(kind);
+        visitor.vec([])
+    }
+
+    pub fn walk_style_directive<'a, V: VisitMut<'a>>(
+        visitor: &mut V,
+        it: &mut StyleDirective<'a>,
+    ) -> OxcVec<'a, Statement<'a>> {
+        let kind = SvelteAstType::StyleDirective;
+        let mut result = visitor.vec([]);
+        visitor.enter_svelte_node(kind);
+        result.append(&mut visitor.visit_attribute_value(&mut it.value));
+        visitor.leave_svelte_node(kind);
+        result
+    }
+
+    pub fn walk_transition_directive<'a, V: VisitMut<'a>>(
+        visitor: &mut V,
+        it: &mut TransitionDirective<'a>,
+    ) -> OxcVec<'a, Statement<'a>> {
+        let kind = SvelteAstType::TransitionDirective;
+        visitor.enter_svelte_node(kind);
+        if let Some(expression) = &mut it.expression {
+            visitor.visit_expression(expression);
+        }
+        visitor.leave_svelte_node(kind);
+        visitor.vec([])
+    }
+
+    pub fn walk_use_directive<'a, V: VisitMut<'a>>(
+        visitor: &mut V,
+        it: &mut UseDirective<'a>,
+    ) -> OxcVec<'a, Statement<'a>> {
+        let kind = SvelteAstType::UseDirective;
+        visitor.enter_svelte_node(kind);
+        if let Some(expression) = &mut it.expression {
+            visitor.visit_expression(expression);
+        }
+        visitor.leave_svelte_node(kind);
+        visitor.vec([])
+    }
+
+    pub fn walk_regular_element<'a, V: VisitMut<'a>>(
+        visitor: &mut V,
+        it: &mut RegularElement<'a>,
+    ) -> OxcVec<'a, Statement<'a>> {
+        let kind = SvelteAstType::RegularElement;
+        let mut result = visitor.vec([]);
+        visitor.enter_svelte_node(kind);
+        visitor.enter_scope(ScopeFlags::empty(), &it.scope_id);
+        for item in it.attributes.iter_mut() {
+            result.append(&mut visitor.visit_attribute(item));
+        }
+        result.append(&mut visitor.visit_fragment(&mut it.fragment));
+        visitor.leave_scope();
+        visitor.leave_svelte_node(kind);
+        result
+    }
+
+    pub fn walk_comment<'a, V: VisitMut<'a>>(
+        visitor: &mut V,
+        it: &mut Comment<'a>,
+    ) -> OxcVec<'a, Statement<'a>> {
+        let kind = SvelteAstType::Comment;
+        visitor.enter_svelte_node(kind);
+        visitor.leave_svelte_node(kind);
+        visitor.vec([])
+    }
+
+    pub fn walk_svelte_head<'a, V: VisitMut<'a>>(
+        visitor: &mut V,
+        it: &mut SvelteHead<'a>,
+    ) -> OxcVec<'a, Statement<'a>> {
+        let kind = SvelteAstType::SvelteHead;
+        let mut result = visitor.vec([]);
+        visitor.enter_svelte_node(kind);
+        for item in it.attributes.iter_mut() {
+            result.append(&mut visitor.visit_attribute(item));
+        }
+        result.append(&mut visitor.visit_fragment(&mut it.fragment));
+        visitor.leave_svelte_node(kind);
+        result
+    }
+
+    pub fn walk_svelte_window<'a, V: VisitMut<'a>>(
+        visitor: &mut V,
+        it: &mut SvelteWindow<'a>,
+    ) -> OxcVec<'a, Statement<'a>> {
+        let kind = SvelteAstType::SvelteWindow;
+        let mut result = visitor.vec([]);
+        visitor.enter_svelte_node(kind);
+        for item in it.attributes.iter_mut() {
+            result.append(&mut visitor.visit_attribute(item));
+        }
+        result.append(&mut visitor.visit_fragment(&mut it.fragment));
+        visitor.leave_svelte_node(kind);
+        result
+    }
+
+    pub fn walk_svelte_document<'a, V: VisitMut<'a>>(
+        visitor: &mut V,
+        it: &mut SvelteDocument<'a>,
+    ) -> OxcVec<'a, Statement<'a>> {
+        let kind = SvelteAstType::SvelteDocument;
+        let mut result = visitor.vec([]);
+        visitor.enter_svelte_node(kind);
+        for item in it.attributes.iter_mut() {
+            result.append(&mut visitor.visit_attribute(item));
+        }
+        result.append(&mut visitor.visit_fragment(&mut it.fragment));
+        visitor.leave_svelte_node(kind);
+        result
+    }
+
+    pub fn walk_svelte_body<'a, V: VisitMut<'a>>(
+        visitor: &mut V,
+        it: &mut SvelteBody<'a>,
+    ) -> OxcVec<'a, Statement<'a>> {
+        let kind = SvelteAstType::SvelteBody;
+        let mut result = visitor.vec([]);
+        visitor.enter_svelte_node(kind);
+        for item in it.attributes.iter_mut() {
+            result.append(&mut visitor.visit_attribute(item));
+        }
+        result.append(&mut visitor.visit_fragment(&mut it.fragment));
+        visitor.leave_svelte_node(kind);
+        result
+    }
+
+    pub fn walk_svelte_element<'a, V: VisitMut<'a>>(
+        visitor: &mut V,
+        it: &mut SvelteElement<'a>,
+    ) -> OxcVec<'a, Statement<'a>> {
+        let kind = SvelteAstType::SvelteElement;
+        let mut result = visitor.vec([]);
+        visitor.enter_svelte_node(kind);
+        for item in it.attributes.iter_mut() {
+            result.append(&mut visitor.visit_attribute(item));
+        }
+        result.append(&mut visitor.visit_fragment(&mut it.fragment));
+        visitor.visit_expression(&mut it.tag);
+        visitor.leave_svelte_node(kind);
+        result
+    }
+
+    pub fn walk_svelte_component<'a, V: VisitMut<'a>>(
+        visitor: &mut V,
+        it: &mut SvelteComponent<'a>,
+    ) -> OxcVec<'a, Statement<'a>> {
+        let kind = SvelteAstType::SvelteComponent;
+        let mut result = visitor.vec([]);
+        visitor.enter_svelte_node(kind);
+        for item in it.attributes.iter_mut() {
+            result.append(&mut visitor.visit_attribute(item));
+        }
+        result.append(&mut visitor.visit_fragment(&mut it.fragment));
+        visitor.visit_expression(&mut it.expression);
+        visitor.leave_svelte_node(kind);
+        result
+    }
+
+    pub fn walk_svelte_self<'a, V: VisitMut<'a>>(
+        visitor: &mut V,
+        it: &mut SvelteSelf<'a>,
+    ) -> OxcVec<'a, Statement<'a>> {
+        let kind = SvelteAstType::SvelteSelf;
+        let mut result = visitor.vec([]);
+        visitor.enter_svelte_node(kind);
+        for item in it.attributes.iter_mut() {
+            result.append(&mut visitor.visit_attribute(item));
+        }
+        result.append(&mut visitor.visit_fragment(&mut it.fragment));
+        visitor.leave_svelte_node(kind);
+        result
+    }
+
+    pub fn walk_svelte_fragment<'a, V: VisitMut<'a>>(
+        visitor: &mut V,
+        it: &mut SvelteFragment<'a>,
+    ) -> OxcVec<'a, Statement<'a>> {
+        let kind = SvelteAstType::SvelteFragment;
+        let mut result = visitor.vec([]);
+        visitor.enter_svelte_node(kind);
+        for item in it.attributes.iter_mut() {
+            result.append(&mut visitor.visit_attribute(item));
+        }
+        result.append(&mut visitor.visit_fragment(&mut it.fragment));
+        visitor.leave_svelte_node(kind);
+        result
+    }
+
+    pub fn walk_title_element<'a, V: VisitMut<'a>>(
+        visitor: &mut V,
+        it: &mut TitleElement<'a>,
+    ) -> OxcVec<'a, Statement<'a>> {
+        let kind = SvelteAstType::TitleElement;
+        let mut result = visitor.vec([]);
+        visitor.enter_svelte_node(kind);
+        for item in it.attributes.iter_mut() {
+            result.append(&mut visitor.visit_attribute(item));
+        }
+        result.append(&mut visitor.visit_fragment(&mut it.fragment));
+        visitor.leave_svelte_node(kind);
+        result
+    }
+
+    pub fn walk_slot_element<'a, V: VisitMut<'a>>(
+        visitor: &mut V,
+        it: &mut SlotElement<'a>,
+    ) -> OxcVec<'a, Statement<'a>> {
+        let kind = SvelteAstType::SlotElement;
+        let mut result = visitor.vec([]);
+        visitor.enter_svelte_node(kind);
+        for item in it.attributes.iter_mut() {
+            result.append(&mut visitor.visit_attribute(item));
+        }
+        result.append(&mut visitor.visit_fragment(&mut it.fragment));
+        visitor.leave_svelte_node(kind);
+        result
+    }
+
+    pub fn walk_component<'a, V: VisitMut<'a>>(
+        visitor: &mut V,
+        it: &mut Component<'a>,
+    ) -> OxcVec<'a, Statement<'a>> {
+        let kind = SvelteAstType::Component;
+        let mut result = visitor.vec([]);
+        visitor.enter_svelte_node(kind);
+        for item in it.attributes.iter_mut() {
+            result.append(&mut visitor.visit_attribute(item));
+        }
+        result.append(&mut visitor.visit_fragment(&mut it.fragment));
+        visitor.leave_svelte_node(kind);
+        result
+    }
+
+    pub fn walk_fragment<'a, V: VisitMut<'a>>(
+        visitor: &mut V,
+        it: &mut Fragment<'a>,
+    ) -> OxcVec<'a, Statement<'a>> {
+        let kind = SvelteAstType::Fragment;
+        let mut result = visitor.vec([]);
+        visitor.enter_svelte_node(kind);
+        visitor.enter_scope(ScopeFlags::empty(), &it.scope_id);
+        for item in it.nodes.iter_mut() {
+            result.append(&mut visitor.visit_fragment_node(item));
+        }
+        visitor.leave_scope();
+        visitor.leave_svelte_node(kind);
+        result
+    }
+
+    pub fn walk_root<'a, V: VisitMut<'a>>(
+        visitor: &mut V,
+        it: &mut Root<'a>,
+    ) -> OxcVec<'a, Statement<'a>> {
+        let kind = SvelteAstType::Root;
+        let mut result = visitor.vec([]);
+        visitor.enter_svelte_node(kind);
+        if let Some(options) = &mut it.options {
+            result.append(&mut visitor.visit_svelte_options(options));
+        }
+        if let Some(module) = &mut it.module {
+            result.append(&mut visitor.visit_script(module));
+        }
+        if let Some(instance) = &mut it.instance {
+            result.append(&mut visitor.visit_script(instance));
+        }
+        result.append(&mut visitor.visit_fragment(&mut it.fragment));
+        if let Some(css) = &mut it.css {
+            result.append(&mut visitor.visit_style_sheet(css));
+        }
+        visitor.leave_svelte_node(kind);
+        result
+    }
+
+    pub fn walk_svelte_options<'a, V: VisitMut<'a>>(
+        visitor: &mut V,
+        it: &mut SvelteOptions<'a>,
+    ) -> OxcVec<'a, Statement<'a>> {
+        let kind = SvelteAstType::SvelteOptions;
+        let mut result = visitor.vec([]);
+        visitor.enter_svelte_node(kind);
+        for item in it.attributes.iter_mut() {
+            result.append(&mut visitor.visit_attribute(item));
+        }
+        visitor.leave_svelte_node(kind);
+        result
+    }
+
+    pub fn walk_custom_element<'a, V: VisitMut<'a>>(
+        visitor: &mut V,
+        it: &mut CustomElement<'a>,
+    ) -> OxcVec<'a, Statement<'a>> {
+        let kind = SvelteAstType::CustomElement;
+        visitor.enter_svelte_node(kind);
+        if let Some(props) = &mut it.props {
+            visitor.visit_object_expression(props);
+        }
+        if let Some(extend) = &mut it.extend {
+            visitor.visit_expression(extend);
+        }
+        visitor.leave_svelte_node(kind);
+        visitor.vec([])
+    }
+
+    pub fn walk_script<'a, V: VisitMut<'a>>(
+        visitor: &mut V,
+        it: &mut Script<'a>,
+    ) -> OxcVec<'a, Statement<'a>> {
+        let kind = SvelteAstType::Script;
+        visitor.enter_svelte_node(kind);
+        visitor.visit_program(&mut it.content);
+        visitor.leave_svelte_node(kind);
+        visitor.vec([])
+    }
+
+    pub fn walk_style_sheet<'a, V: VisitMut<'a>>(
+        visitor: &mut V,
+        it: &mut StyleSheet<'a>,
+    ) -> OxcVec<'a, Statement<'a>> {
+        let kind = SvelteAstType::StyleSheet;
+        let mut result = visitor.vec([]);
+        visitor.enter_svelte_node(kind);
+        for item in it.attributes.iter_mut() {
+            result.append(&mut visitor.visit_attribute(item));
+        }
+        for item in it.children.iter_mut() {
+            result.append(&mut visitor.visit_style_sheet_child(item));
+        }
+        visitor.leave_svelte_node(kind);
+        result
+    }
+
+    pub fn walk_style_sheet_content<'a, V: VisitMut<'a>>(
+        visitor: &mut V,
+        it: &mut StyleSheetContent<'a>,
+    ) -> OxcVec<'a, Statement<'a>> {
+        let kind = SvelteAstType::StyleSheetContent;
+        let mut result = visitor.vec([]);
+        visitor.enter_svelte_node(kind);
+        if let Some(comment) = &mut it.comment {
+            result.append(&mut visitor.visit_comment(comment));
+        }
+        visitor.leave_svelte_node(kind);
+        result
+    }
+
+    pub fn walk_at_rule<'a, V: VisitMut<'a>>(
+        visitor: &mut V,
+        it: &mut AtRule<'a>,
+    ) -> OxcVec<'a, Statement<'a>> {
+        let kind = SvelteAstType::AtRule;
+        let mut result = visitor.vec([]);
+        visitor.enter_svelte_node(kind);
+        if let Some(block) = &mut it.block {
+            result.append(&mut visitor.visit_css_block(block));
+        }
+        visitor.leave_svelte_node(kind);
+        result
+    }
+
+    pub fn walk_relative_selector<'a, V: VisitMut<'a>>(
+        visitor: &mut V,
+        it: &mut RelativeSelector<'a>,
+    ) -> OxcVec<'a, Statement<'a>> {
+        let kind = SvelteAstType::RelativeSelector;
+        let mut result = visitor.vec([]);
+        visitor.enter_svelte_node(kind);
+        if let Some(combinator) = &mut it.combinator {
+            result.append(&mut visitor.visit_combinator(combinator));
+        }
+        for item in it.selectors.iter_mut() {
+            result.append(&mut visitor.visit_simple_selector(item));
+        }
+        visitor.leave_svelte_node(kind);
+        result
+    }
+
+    pub fn walk_type_selector<'a, V: VisitMut<'a>>(
+        visitor: &mut V,
+        it: &mut TypeSelector<'a>,
+    ) -> OxcVec<'a, Statement<'a>> {
+        let kind = SvelteAstType::TypeSelector;
+        visitor.enter_svelte_node(kind);
+        visitor.leave_svelte_node(kind);
+        visitor.vec([])
+    }
+
+    pub fn walk_id_selector<'a, V: VisitMut<'a>>(
+        visitor: &mut V,
+        it: &mut IdSelector<'a>,
+    ) -> OxcVec<'a, Statement<'a>> {
+        let kind = SvelteAstType::IdSelector;
+        visitor.enter_svelte_node(kind);
+        visitor.leave_svelte_node(kind);
+        visitor.vec([])
+    }
+
+    pub fn walk_class_selector<'a, V: VisitMut<'a>>(
+        visitor: &mut V,
+        it: &mut ClassSelector<'a>,
+    ) -> OxcVec<'a, Statement<'a>> {
+        let kind = SvelteAstType::ClassSelector;
+        visitor.enter_svelte_node(kind);
+        visitor.leave_svelte_node(kind);
+        visitor.vec([])
+    }
+
+    pub fn walk_attribute_selector<'a, V: VisitMut<'a>>(
+        visitor: &mut V,
+        it: &mut AttributeSelector<'a>,
+    ) -> OxcVec<'a, Statement<'a>> {
+        let kind = SvelteAstType::AttributeSelector;
+        visitor.enter_svelte_node(kind);
+        visitor.leave_svelte_node(kind);
+        visitor.vec([])
+    }
+
+    pub fn walk_pseudo_element_selector<'a, V: VisitMut<'a>>(
+        visitor: &mut V,
+        it: &mut PseudoElementSelector<'a>,
+    ) -> OxcVec<'a, Statement<'a>> {
+        let kind = SvelteAstType::PseudoElementSelector;
+        visitor.enter_svelte_node(kind);
+        visitor.leave_svelte_node(kind);
+        visitor.vec([])
+    }
+
+    pub fn walk_pseudo_class_selector<'a, V: VisitMut<'a>>(
+        visitor: &mut V,
+        it: &mut PseudoClassSelector<'a>,
+    ) -> OxcVec<'a, Statement<'a>> {
+        let kind = SvelteAstType::PseudoClassSelector;
+        let mut result = visitor.vec([]);
+        visitor.enter_svelte_node(kind);
+        if let Some(args) = &mut it.args {
+            result.append(&mut visitor.visit_selector_list(args));
+        }
+        visitor.leave_svelte_node(kind);
+        result
+    }
+
+    pub fn walk_percentage<'a, V: VisitMut<'a>>(
+        visitor: &mut V,
+        it: &mut Percentage<'a>,
+    ) -> OxcVec<'a, Statement<'a>> {
+        let kind = SvelteAstType::Percentage;
+        visitor.enter_svelte_node(kind);
+        visitor.leave_svelte_node(kind);
+        visitor.vec([])
+    }
+
+    pub fn walk_nesting_selector<'a, V: VisitMut<'a>>(
+        visitor: &mut V,
+        it: &mut NestingSelector<'a>,
+    ) -> OxcVec<'a, Statement<'a>> {
+        let kind = SvelteAstType::NestingSelector;
+        visitor.enter_svelte_node(kind);
+        visitor.leave_svelte_node(kind);
+        visitor.vec([])
+    }
+
+    pub fn walk_nth<'a, V: VisitMut<'a>>(
+        visitor: &mut V,
+        it: &mut Nth<'a>,
+    ) -> OxcVec<'a, Statement<'a>> {
+        let kind = SvelteAstType::Nth;
+        visitor.enter_svelte_node(kind);
+        visitor.leave_svelte_node(kind);
+        visitor.vec([])
+    }
+
+    pub fn walk_complex_selector<'a, V: VisitMut<'a>>(
+        visitor: &mut V,
+        it: &mut ComplexSelector<'a>,
+    ) -> OxcVec<'a, Statement<'a>> {
+        let kind = SvelteAstType::ComplexSelector;
+        let mut result = visitor.vec([]);
+        visitor.enter_svelte_node(kind);
+        for item in it.children.iter_mut() {
+            result.append(&mut visitor.visit_relative_selector(item));
+        }
+        visitor.leave_svelte_node(kind);
+        result
+    }
+
+    pub fn walk_rule<'a, V: VisitMut<'a>>(
+        visitor: &mut V,
+        it: &mut Rule<'a>,
+    ) -> OxcVec<'a, Statement<'a>> {
+        let kind = SvelteAstType::Rule;
+        let mut result = visitor.vec([]);
+        visitor.enter_svelte_node(kind);
+        result.append(&mut visitor.visit_selector_list(&mut it.prelude));
+        result.append(&mut visitor.visit_css_block(&mut it.block));
+        visitor.leave_svelte_node(kind);
+        result
+    }
+
+    pub fn walk_css_declaration<'a, V: VisitMut<'a>>(
+        visitor: &mut V,
+        it: &mut Declaration<'a>,
+    ) -> OxcVec<'a, Statement<'a>> {
+        let kind = SvelteAstType::Declaration;
+        visitor.enter_svelte_node(kind);
+        visitor.leave_svelte_node(kind);
+        visitor.vec([])
+    }
+
+    pub fn walk_css_block<'a, V: VisitMut<'a>>(
+        visitor: &mut V,
+        it: &mut CSSBlock<'a>,
+    ) -> OxcVec<'a, Statement<'a>> {
+        let kind = SvelteAstType::CSSBlock;
+        let mut result = visitor.vec([]);
+        visitor.enter_svelte_node(kind);
+        for item in it.children.iter_mut() {
+            result.append(&mut visitor.visit_block_child(item));
+        }
+        visitor.leave_svelte_node(kind);
+        result
+    }
+
+    pub fn walk_selector_list<'a, V: VisitMut<'a>>(
+        visitor: &mut V,
+        it: &mut SelectorList<'a>,
+    ) -> OxcVec<'a, Statement<'a>> {
+        let kind = SvelteAstType::SelectorList;
+        let mut result = visitor.vec([]);
+        visitor.enter_svelte_node(kind);
+        for item in it.children.iter_mut() {
+            result.append(&mut visitor.visit_complex_selector(item));
+        }
+        visitor.leave_svelte_node(kind);
+        result
+    }
+
+    pub fn walk_combinator<'a, V: VisitMut<'a>>(
+        visitor: &mut V,
+        it: &mut Combinator<'a>,
+    ) -> OxcVec<'a, Statement<'a>> {
+        let kind = SvelteAstType::Combinator;
+        visitor.enter_svelte_node(kind);
+        visitor.leave_svelte_node(kind);
+        visitor.vec([])
+    }
+
+    pub fn walk_expression_tag<'a, V: VisitMut<'a>>(
+        visitor: &mut V,
+        it: &mut ExpressionTag<'a>,
+    ) -> OxcVec<'a, Statement<'a>> {
+        let kind = SvelteAstType::ExpressionTag;
+        visitor.enter_svelte_node(kind);
+        visitor.visit_expression(&mut it.expression);
+        visitor.leave_svelte_node(kind);
+        visitor.vec([])
+    }
+
+    pub fn walk_html_tag<'a, V: VisitMut<'a>>(
+        visitor: &mut V,
+        it: &mut HtmlTag<'a>,
+    ) -> OxcVec<'a, Statement<'a>> {
+        let kind = SvelteAstType::HtmlTag;
+        visitor.enter_svelte_node(kind);
+        visitor.visit_expression(&mut it.expression);
+        visitor.leave_svelte_node(kind);
+        visitor.vec([])
+    }
+
+    pub fn walk_debug_tag<'a, V: VisitMut<'a>>(
+        visitor: &mut V,
+        it: &mut DebugTag<'a>,
+    ) -> OxcVec<'a, Statement<'a>> {
+        let kind = SvelteAstType::DebugTag;
+        visitor.enter_svelte_node(kind);
+        for item in it.identifiers.iter_mut() {
+            visitor.visit_identifier_reference(item);
+        }
+        visitor.leave_svelte_node(kind);
+        visitor.vec([])
+    }
+
+    pub fn walk_const_tag<'a, V: VisitMut<'a>>(
+        visitor: &mut V,
+        it: &mut ConstTag<'a>,
+    ) -> OxcVec<'a, Statement<'a>> {
+        let kind = SvelteAstType::ConstTag;
+        visitor.enter_svelte_node(kind);
+        visitor.visit_variable_declaration(&mut it.declaration);
+        visitor.leave_svelte_node(kind);
+        visitor.vec([])
+    }
+
+    pub fn walk_render_tag<'a, V: VisitMut<'a>>(
+        visitor: &mut V,
+        it: &mut RenderTag<'a>,
+    ) -> OxcVec<'a, Statement<'a>> {
+        let kind = SvelteAstType::RenderTag;
+        let mut result = visitor.vec([]);
+        visitor.enter_svelte_node(kind);
+        result.append(&mut visitor.visit_render_tag_expression(&mut it.expression));
+        visitor.leave_svelte_node(kind);
+        result
+    }
+
+    pub fn walk_text<'a, V: VisitMut<'a>>(
+        visitor: &mut V,
+        it: &mut Text<'a>,
+    ) -> OxcVec<'a, Statement<'a>> {
+        let kind = SvelteAstType::Text;
+        visitor.enter_svelte_node(kind);
+        visitor.leave_svelte_node(kind);
+        visitor.vec([])
+    }
+
+    pub fn walk_attribute<'a, V: VisitMut<'a>>(
+        visitor: &mut V,
+        it: &mut Attribute<'a>,
+    ) -> OxcVec<'a, Statement<'a>> {
+        match it {
+            Attribute::NormalAttribute(it) => visitor.visit_normal_attribute(it),
+            Attribute::SpreadAttribute(it) => visitor.visit_spread_attribute(it),
+            Attribute::Directive(it) => visitor.visit_svelte_directive(it),
+        }
+    }
+
+    pub fn walk_attribute_value<'a, V: VisitMut<'a>>(
+        visitor: &mut V,
+        it: &mut AttributeValue<'a>,
+    ) -> OxcVec<'a, Statement<'a>> {
+        match it {
+            AttributeValue::ExpressionTag(it) => visitor.visit_expression_tag(it),
+            AttributeValue::Quoted(it) => {
+                let mut result = visitor.vec([]);
+                for item in it.iter_mut() {
+                    result.append(&mut visitor.visit_quoted_attribute_value(item));
+                }
+                result
+            }
+            AttributeValue::True => visitor.vec([]),
+        }
+    }
+
+    pub fn walk_quoted_attribute_value<'a, V: VisitMut<'a>>(
+        visitor: &mut V,
+        it: &mut QuotedAttributeValue<'a>,
+    ) -> OxcVec<'a, Statement<'a>> {
+        match it {
+            QuotedAttributeValue::ExpressionTag(it) => visitor.visit_expression_tag(it),
+            QuotedAttributeValue::Text(it) => visitor.visit_text(it),
+        }
+    }
+
+    pub fn walk_block<'a, V: VisitMut<'a>>(
+        visitor: &mut V,
+        it: &mut Block<'a>,
+    ) -> OxcVec<'a, Statement<'a>> {
+        match it {
+            Block::IfBlock(it) => visitor.visit_if_block(it),
+            Block::EachBlock(it) => visitor.visit_each_block(it),
+            Block::AwaitBlock(it) => visitor.visit_await_block(it),
+            Block::KeyBlock(it) => visitor.visit_key_block(it),
+            Block::SnippetBlock(it) => visitor.visit_snippet_block(it),
+        }
+    }
+
+    pub fn walk_svelte_directive<'a, V: VisitMut<'a>>(
+        visitor: &mut V,
+        it: &mut Directive<'a>,
+    ) -> OxcVec<'a, Statement<'a>> {
+        match it {
+            Directive::AnimateDirective(it) => visitor.visit_animate_directive(it),
+            Directive::BindDirective(it) => visitor.visit_bind_directive(it),
+            Directive::ClassDirective(it) => visitor.visit_class_directive(it),
+            Directive::LetDirective(it) => visitor.visit_let_directive(it),
+            Directive::OnDirective(it) => visitor.visit_on_directive(it),
+            Directive::StyleDirective(it) => visitor.visit_style_directive(it),
+            Directive::TransitionDirective(it) => visitor.visit_transition_directive(it),
+            Directive::UseDirective(it) => visitor.visit_use_directive(it),
+        }
+    }
+
+    pub fn walk_element<'a, V: VisitMut<'a>>(
+        visitor: &mut V,
+        it: &mut Element<'a>,
+    ) -> OxcVec<'a, Statement<'a>> {
+        match it {
+            Element::RegularElement(it) => visitor.visit_regular_element(it),
+            Element::SvelteComponent(it) => visitor.visit_svelte_component(it),
+            Element::SvelteElement(it) => visitor.visit_svelte_element(it),
+            Element::SvelteBody(it) => visitor.visit_svelte_body(it),
+            Element::SvelteWindow(it) => visitor.visit_svelte_window(it),
+            Element::SvelteDocument(it) => visitor.visit_svelte_document(it),
+            Element::SvelteHead(it) => visitor.visit_svelte_head(it),
+            Element::SvelteFragment(it) => visitor.visit_svelte_fragment(it),
+            Element::SvelteSelf(it) => visitor.visit_svelte_self(it),
+            Element::TitleElement(it) => visitor.visit_title_element(it),
+            Element::SlotElement(it) => visitor.visit_slot_element(it),
+            Element::Component(it) => visitor.visit_component(it),
+        }
+    }
+
+    pub fn walk_fragment_node<'a, V: VisitMut<'a>>(
+        visitor: &mut V,
+        it: &mut FragmentNode<'a>,
+    ) -> OxcVec<'a, Statement<'a>> {
+        match it {
+            FragmentNode::Text(it) => visitor.visit_text(it),
+            FragmentNode::Element(it) => visitor.visit_element(it),
+            FragmentNode::Tag(it) => visitor.visit_tag(it),
+            FragmentNode::Comment(it) => visitor.visit_comment(it),
+            FragmentNode::Block(it) => visitor.visit_block(it),
+        }
+    }
+
+    pub fn walk_style_sheet_child<'a, V: VisitMut<'a>>(
+        visitor: &mut V,
+        it: &mut StyleSheetChild<'a>,
+    ) -> OxcVec<'a, Statement<'a>> {
+        match it {
+            StyleSheetChild::AtRule(it) => visitor.visit_at_rule(it),
+            StyleSheetChild::Rule(it) => visitor.visit_rule(it),
+        }
+    }
+
+    pub fn walk_simple_selector<'a, V: VisitMut<'a>>(
+        visitor: &mut V,
+        it: &mut SimpleSelector<'a>,
+    ) -> OxcVec<'a, Statement<'a>> {
+        match it {
+            SimpleSelector::TypeSelector(it) => visitor.visit_type_selector(it),
+            SimpleSelector::IdSelector(it) => visitor.visit_id_selector(it),
+            SimpleSelector::ClassSelector(it) => visitor.visit_class_selector(it),
+            SimpleSelector::AttributeSelector(it) => visitor.visit_attribute_selector(it),
+            SimpleSelector::PseudoElementSelector(it) => visitor.visit_pseudo_element_selector(it),
+            SimpleSelector::PseudoClassSelector(it) => visitor.visit_pseudo_class_selector(it),
+            SimpleSelector::Percentage(it) => visitor.visit_percentage(it),
+            SimpleSelector::Nth(it) => visitor.visit_nth(it),
+            SimpleSelector::NestingSelector(it) => visitor.visit_nesting_selector(it),
+        }
+    }
+
+    pub fn walk_block_child<'a, V: VisitMut<'a>>(
+        visitor: &mut V,
+        it: &mut BlockChild<'a>,
+    ) -> OxcVec<'a, Statement<'a>> {
+        match it {
+            BlockChild::Rule(it) => visitor.visit_rule(it),
+            BlockChild::AtRule(it) => visitor.visit_at_rule(it),
+            BlockChild::Declaration(it) => visitor.visit_css_declaration(it),
+        }
+    }
+
+    pub fn walk_tag<'a, V: VisitMut<'a>>(
+        visitor: &mut V,
+        it: &mut Tag<'a>,
+    ) -> OxcVec<'a, Statement<'a>> {
+        match it {
+            Tag::ExpressionTag(it) => visitor.visit_expression_tag(it),
+            Tag::HtmlTag(it) => visitor.visit_html_tag(it),
+            Tag::DebugTag(it) => visitor.visit_debug_tag(it),
+            Tag::ConstTag(it) => visitor.visit_const_tag(it),
+            Tag::RenderTag(it) => visitor.visit_render_tag(it),
+        }
+    }
+
+    pub fn walk_render_tag_expression<'a, V: VisitMut<'a>>(
+        visitor: &mut V,
+        it: &mut RenderTagExpression<'a>,
+    ) -> OxcVec<'a, Statement<'a>> {
+        match it {
+            RenderTagExpression::CallExpression(it) => {
+                visitor.visit_call_expression(it);
+                visitor.vec([])
+            }
+            RenderTagExpression::ChainExpression(it) => {
+                visitor.visit_chain_expression(it);
+                visitor.vec([])
+            }
+        }
+    }
+}
@@ -3,8 +3,9 @@ pub mod ast_builder;
 pub mod ast_kind;
 pub mod span_offset;
 pub mod traits;
-pub mod visit;
-pub mod visit_mut;
+
+mod generated;
+pub use generated::{visit, visit_mut};
 
 pub mod js_ast {
     pub use oxc_ast::ast::*;
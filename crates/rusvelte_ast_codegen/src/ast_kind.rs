@@ -0,0 +1,27 @@
+use crate::{schema::Schema, type_with_lifetime};
+
+pub fn generate(schema: &Schema) -> String {
+    let mut types = String::new();
+    let mut kinds = String::new();
+    for def in schema.structs.iter() {
+        types.push_str(&format!("{},\n", def.name));
+        kinds.push_str(&format!(
+            "{}(&'a {}),\n",
+            def.name,
+            type_with_lifetime(&def.name, def.has_lifetime)
+        ));
+    }
+
+    format!(
+        "use crate::ast::*;
+
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum SvelteAstType {{
+{types}}}
+
+#[derive(Debug, Clone, Copy)]
+pub enum SvelteAstKind<'a> {{
+{kinds}}}
+"
+    )
+}
@@ -0,0 +1,54 @@
+use crate::schema::{Schema, Target, Wrapper};
+
+pub fn generate(schema: &Schema) -> String {
+    let mut impls = String::new();
+    for def in schema.structs.iter() {
+        let lifetime = if def.has_lifetime { "<'_>" } else { "" };
+        impls.push_str(&format!(
+            "impl GetAstType for {name}{lifetime} {{
+    fn ast_type(&self) -> SvelteAstType {{
+        SvelteAstType::{name}
+    }}
+}}
+
+",
+            name = def.name
+        ));
+    }
+
+    for def in schema.enums.iter() {
+        // only the enums whose variants all hold a single Svelte node have a type
+        let holds_svelte_nodes = def.variants.iter().all(|variant| {
+            variant.ty.as_ref().is_some_and(|ty| {
+                ty.wrapper == Wrapper::None && matches!(ty.target, Target::Svelte(_))
+            })
+        });
+        if !holds_svelte_nodes {
+            continue;
+        }
+        let lifetime = if def.has_lifetime { "<'_>" } else { "" };
+        let arms = def
+            .variants
+            .iter()
+            .map(|variant| format!("{}::{}(it) => it.ast_type(),\n", def.name, variant.name))
+            .collect::<String>();
+        impls.push_str(&format!(
+            "impl GetAstType for {name}{lifetime} {{
+    fn ast_type(&self) -> SvelteAstType {{
+        match self {{
+            {arms}
+        }}
+    }}
+}}
+
+",
+            name = def.name
+        ));
+    }
+
+    format!(
+        "use crate::{{ast::*, ast_kind::SvelteAstType, traits::get_ast_type::GetAstType}};
+
+{impls}"
+    )
+}
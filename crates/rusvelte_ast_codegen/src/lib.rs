@@ -0,0 +1,75 @@
+//! Generates the code of `rusvelte_ast` that mirrors the shape of the AST: the node kinds,
+//! the `Visit` / `VisitMut` traits and the `GetAstType` implementations.
+
+mod ast_kind;
+mod get_ast_type;
+mod schema;
+mod visit;
+
+use std::{
+    io::Write,
+    path::{Path, PathBuf},
+    process::{Command, Stdio},
+};
+
+use schema::Schema;
+
+const HEADER: &str = "// Auto-generated code, DO NOT EDIT DIRECTLY!
+// To edit this generated file you have to edit `crates/rusvelte_ast/src/ast`
+// and run `just codegen`.
+";
+
+pub struct Output {
+    /// Relative to the workspace root
+    pub path: PathBuf,
+    pub content: String,
+}
+
+pub fn workspace_root() -> PathBuf {
+    Path::new(env!("CARGO_MANIFEST_DIR")).join("../..")
+}
+
+pub fn generate(root: &Path) -> Vec<Output> {
+    let schema = Schema::load(&root.join("crates/rusvelte_ast/src/ast"));
+    let output_dir = Path::new("crates/rusvelte_ast/src/generated");
+
+    [
+        ("ast_kind.rs", ast_kind::generate(&schema)),
+        ("visit.rs", visit::generate(&schema, false)),
+        ("visit_mut.rs", visit::generate(&schema, true)),
+        ("get_ast_type.rs", get_ast_type::generate(&schema)),
+    ]
+    .into_iter()
+    .map(|(file_name, code)| Output {
+        path: output_dir.join(file_name),
+        content: format!("{HEADER}\n{}", rustfmt(&code)),
+    })
+    .collect()
+}
+
+fn rustfmt(code: &str) -> String {
+    let mut child = Command::new("rustfmt")
+        .args(["--edition", "2021"])
+        .stdin(Stdio::piped())
+        .stdout(Stdio::piped())
+        .spawn()
+        .expect("Cannot run rustfmt");
+    child
+        .stdin
+        .take()
+        .unwrap()
+        .write_all(code.as_bytes())
+        .unwrap();
+    let output = child.wait_with_output().unwrap();
+    assert!(output.status.success(), "Cannot format:\n{code}");
+    String::from_utf8(output.stdout).unwrap()
+}
+
+/// `Fragment<'a>` or `ScriptContext`
+fn type_with_lifetime(name: &str, has_lifetime: bool) -> String {
+    if has_lifetime {
+        format!("{name}<'a>")
+    } else {
+        name.to_string()
+    }
+}
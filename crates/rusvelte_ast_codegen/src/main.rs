@@ -0,0 +1,12 @@
+//! Regenerates the files in `crates/rusvelte_ast/src/generated`.
+//!
+//! Run it with `just codegen` after changing a node in `crates/rusvelte_ast/src/ast`.
+
+fn main() {
+    let root = rusvelte_ast_codegen::workspace_root();
+    for output in rusvelte_ast_codegen::generate(&root) {
+        std::fs::write(root.join(&output.path), output.content)
+            .unwrap_or_else(|err| panic!("Cannot write {}: {err}", output.path.display()));
+        println!("Generated {}", output.path.display());
+    }
+}
@@ -0,0 +1,337 @@
+//! Collects the nodes declared in `crates/rusvelte_ast/src/ast`.
+//!
+//! Every struct and enum deriving `AstTree` is a node. How a node is walked is driven by the
+//! `#[visit(...)]` attributes:
+//!
+//! - `#[visit(name = x)]` on a type renames its `visit_*` / `walk_*` methods
+//! - `#[visit(order(a, b, c))]` on a struct walks its fields in the given order
+//!   instead of the declaration order
+//! - `#[visit(scope = scope_id)]` on a field walks it inside the scope stored in `scope_id`
+//! - `#[visit(ignore)]` on a field skips it
+
+use std::{collections::HashMap, path::Path};
+
+use syn::{
+    punctuated::Punctuated, Attribute, Fields, GenericArgument, Ident, Item, PathArguments, Token,
+    Type,
+};
+
+/// The JS nodes that can be found in the Svelte AST, they are walked by `oxc_ast::Visit`.
+const JS_TYPES: &[&str] = &[
+    "BindingIdentifier",
+    "BindingPattern",
+    "CallExpression",
+    "ChainExpression",
+    "Expression",
+    "IdentifierReference",
+    "ObjectExpression",
+    "Program",
+    "VariableDeclaration",
+];
+
+pub struct Schema {
+    pub structs: Vec<StructDef>,
+    pub enums: Vec<EnumDef>,
+    visit_names: HashMap<String, String>,
+}
+
+pub struct StructDef {
+    pub name: String,
+    pub visit_name: String,
+    pub has_lifetime: bool,
+    /// The fields to walk, in the order they are walked
+    pub fields: Vec<FieldDef>,
+}
+
+pub struct FieldDef {
+    pub name: String,
+    pub ty: FieldType,
+    pub scope: Option<String>,
+}
+
+pub struct EnumDef {
+    pub name: String,
+    pub visit_name: String,
+    pub has_lifetime: bool,
+    pub variants: Vec<VariantDef>,
+}
+
+pub struct VariantDef {
+    pub name: String,
+    /// `None` for unit variants
+    pub ty: Option<FieldType>,
+}
+
+pub struct FieldType {
+    pub wrapper: Wrapper,
+    pub target: Target,
+}
+
+#[derive(PartialEq, Eq)]
+pub enum Wrapper {
+    None,
+    Option,
+    Vec,
+}
+
+pub enum Target {
+    Svelte(String),
+    Js(String),
+}
+
+impl Schema {
+    pub fn load(ast_dir: &Path) -> Self {
+        let mut paths = std::fs::read_dir(ast_dir)
+            .unwrap_or_else(|err| panic!("Cannot read {}: {err}", ast_dir.display()))
+            .map(|entry| entry.unwrap().path())
+            .filter(|path| path.extension().is_some_and(|ext| ext == "rs"))
+            .collect::<Vec<_>>();
+        paths.sort();
+
+        let mut items = vec![];
+        for path in paths {
+            let source = std::fs::read_to_string(&path).unwrap();
+            let file = syn::parse_file(&source)
+                .unwrap_or_else(|err| panic!("Cannot parse {}: {err}", path.display()));
+            items.extend(file.items.into_iter().filter(|item| match item {
+                Item::Struct(item) => derives_ast_tree(&item.attrs),
+                Item::Enum(item) => derives_ast_tree(&item.attrs),
+                _ => false,
+            }));
+        }
+
+        let mut visit_names = HashMap::new();
+        for item in items.iter() {
+            let (ident, attrs) = match item {
+                Item::Struct(item) => (&item.ident, &item.attrs),
+                Item::Enum(item) => (&item.ident, &item.attrs),
+                _ => unreachable!(),
+            };
+            let name = ident.to_string();
+            let visit_name = VisitAttrs::parse(attrs)
+                .name
+                .unwrap_or_else(|| to_snake_case(&name));
+            visit_names.insert(name, visit_name);
+        }
+
+        let mut schema = Schema {
+            structs: vec![],
+            enums: vec![],
+            visit_names,
+        };
+        for item in items {
+            match item {
+                Item::Struct(item) => {
+                    let def = schema.build_struct(item);
+                    schema.structs.push(def);
+                }
+                Item::Enum(item) => {
+                    let def = schema.build_enum(item);
+                    schema.enums.push(def);
+                }
+                _ => unreachable!(),
+            }
+        }
+        schema
+    }
+
+    /// The name used by the `visit_*` and `walk_*` methods of the target
+    pub fn visit_name<'s>(&'s self, target: &'s Target) -> std::borrow::Cow<'s, str> {
+        match target {
+            Target::Svelte(name) => self.visit_names[name].as_str().into(),
+            Target::Js(name) => to_snake_case(name).into(),
+        }
+    }
+
+    fn build_struct(&self, item: syn::ItemStruct) -> StructDef {
+        let name = item.ident.to_string();
+        let attrs = VisitAttrs::parse(&item.attrs);
+        let Fields::Named(named) = item.fields else {
+            panic!("{name} should have named fields");
+        };
+
+        let mut fields = vec![];
+        for field in named.named {
+            let field_attrs = VisitAttrs::parse(&field.attrs);
+            if field_attrs.ignore {
+                continue;
+            }
+            let Some(ty) = self.parse_type(&field.ty) else {
+                continue;
+            };
+            fields.push(FieldDef {
+                name: field.ident.unwrap().to_string(),
+                ty,
+                scope: field_attrs.scope,
+            });
+        }
+
+        if let Some(order) = attrs.order {
+            let mut ordered = vec![];
+            for field_name in order {
+                let Some(pos) = fields.iter().position(|field| field.name == field_name) else {
+                    panic!("{name}.{field_name} in `#[visit(order(...))]` is not a walked field");
+                };
+                ordered.push(fields.remove(pos));
+            }
+            if let Some(field) = fields.first() {
+                panic!("{name}.{} is missing in `#[visit(order(...))]`", field.name);
+            }
+            fields = ordered;
+        }
+
+        StructDef {
+            visit_name: self.visit_names[&name].clone(),
+            name,
+            has_lifetime: !item.generics.params.is_empty(),
+            fields,
+        }
+    }
+
+    fn build_enum(&self, item: syn::ItemEnum) -> EnumDef {
+        let name = item.ident.to_string();
+        let variants = item
+            .variants
+            .into_iter()
+            .map(|variant| {
+                let ty = match &variant.fields {
+                    Fields::Unit => None,
+                    Fields::Unnamed(fields) if fields.unnamed.len() == 1 => {
+                        let ty = self.parse_type(&fields.unnamed[0].ty).unwrap_or_else(|| {
+                            panic!("{name}::{} does not hold a node", variant.ident)
+                        });
+                        Some(ty)
+                    }
+                    _ => panic!("{name}::{} should hold a single node", variant.ident),
+                };
+                VariantDef {
+                    name: variant.ident.to_string(),
+                    ty,
+                }
+            })
+            .collect();
+        EnumDef {
+            visit_name: self.visit_names[&name].clone(),
+            name,
+            has_lifetime: !item.generics.params.is_empty(),
+            variants,
+        }
+    }
+
+    /// Returns `None` if the type does not contain a node, e.g. `&'a str` or `Span`.
+    fn parse_type(&self, ty: &Type) -> Option<FieldType> {
+        let (ident, argument) = last_segment(ty)?;
+        let wrapper = match ident.to_string().as_str() {
+            "Option" => Wrapper::Option,
+            "Vec" => Wrapper::Vec,
+            _ => {
+                return self.parse_target(ty).map(|target| FieldType {
+                    wrapper: Wrapper::None,
+                    target,
+                })
+            }
+        };
+        let target = self.parse_target(argument?)?;
+        Some(FieldType { wrapper, target })
+    }
+
+    fn parse_target(&self, ty: &Type) -> Option<Target> {
+        let (ident, argument) = last_segment(ty)?;
+        let name = ident.to_string();
+        if name == "Box" {
+            return self.parse_target(argument?);
+        }
+        if self.visit_names.contains_key(&name) {
+            Some(Target::Svelte(name))
+        } else if JS_TYPES.contains(&name.as_str()) {
+            Some(Target::Js(name))
+        } else {
+            None
+        }
+    }
+}
+
+/// Returns the last segment of a path type along with its first type argument.
+fn last_segment(ty: &Type) -> Option<(&Ident, Option<&Type>)> {
+    let Type::Path(path) = ty else {
+        return None;
+    };
+    let segment = path.path.segments.last()?;
+    let argument = match &segment.arguments {
+        PathArguments::AngleBracketed(arguments) => {
+            arguments.args.iter().find_map(|argument| match argument {
+                GenericArgument::Type(ty) => Some(ty),
+                _ => None,
+            })
+        }
+        _ => None,
+    };
+    Some((&segment.ident, argument))
+}
+
+fn derives_ast_tree(attrs: &[Attribute]) -> bool {
+    attrs
+        .iter()
+        .filter(|attr| attr.path().is_ident("derive"))
+        .any(|attr| {
+            attr.parse_args_with(Punctuated::<syn::Path, Token![,]>::parse_terminated)
+                .is_ok_and(|paths| paths.iter().any(|path| path.is_ident("AstTree")))
+        })
+}
+
+#[derive(Default)]
+struct VisitAttrs {
+    name: Option<String>,
+    scope: Option<String>,
+    order: Option<Vec<String>>,
+    ignore: bool,
+}
+
+impl VisitAttrs {
+    fn parse(attrs: &[Attribute]) -> Self {
+        let mut result = Self::default();
+        for attr in attrs.iter().filter(|attr| attr.path().is_ident("visit")) {
+            attr.parse_nested_meta(|meta| {
+                if meta.path.is_ident("name") {
+                    let value: Ident = meta.value()?.parse()?;
+                    result.name = Some(value.to_string());
+                } else if meta.path.is_ident("scope") {
+                    let value: Ident = meta.value()?.parse()?;
+                    result.scope = Some(value.to_string());
+                } else if meta.path.is_ident("ignore") {
+                    result.ignore = true;
+                } else if meta.path.is_ident("order") {
+                    let mut order = vec![];
+                    meta.parse_nested_meta(|field| {
+                        let ident = field.path.require_ident()?;
+                        order.push(ident.to_string());
+                        Ok(())
+                    })?;
+                    result.order = Some(order);
+                } else {
+                    return Err(meta.error("unknown `visit` attribute"));
+                }
+                Ok(())
+            })
+            .unwrap_or_else(|err| panic!("Invalid `#[visit(...)]` attribute: {err}"));
+        }
+        result
+    }
+}
+
+/// `CSSBlock` -> `css_block`, `IfBlock` -> `if_block`
+pub fn to_snake_case(name: &str) -> String {
+    let chars = name.chars().collect::<Vec<_>>();
+    let mut result = String::new();
+    for (i, c) in chars.iter().enumerate() {
+        if c.is_uppercase() && i > 0 {
+            let prev_is_lower = chars[i - 1].is_lowercase();
+            let next_is_lower = chars.get(i + 1).is_some_and(|c| c.is_lowercase());
+            if prev_is_lower || (chars[i - 1].is_uppercase() && next_is_lower) {
+                result.push('_');
+            }
+        }
+        result.extend(c.to_lowercase());
+    }
+    result
+}
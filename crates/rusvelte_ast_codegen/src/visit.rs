@@ -0,0 +1,251 @@
+//! Generates `Visit` and `VisitMut`. Both share the same walking order, the mutable visitor
+//! additionally collects the statements returned by the Svelte nodes it visits.
+
+use crate::{
+    schema::{EnumDef, FieldType, Schema, StructDef, Target, Wrapper},
+    type_with_lifetime,
+};
+
+struct Generator<'s> {
+    schema: &'s Schema,
+    is_mut: bool,
+}
+
+pub fn generate(schema: &Schema, is_mut: bool) -> String {
+    let generator = Generator { schema, is_mut };
+
+    let mut methods = String::new();
+    let mut walkers = String::new();
+    for def in schema.structs.iter() {
+        methods.push_str(&generator.visit_method(&def.name, &def.visit_name, def.has_lifetime));
+        walkers.push_str(&generator.walk_struct(def));
+    }
+    for def in schema.enums.iter() {
+        methods.push_str(&generator.visit_method(&def.name, &def.visit_name, def.has_lifetime));
+        walkers.push_str(&generator.walk_enum(def));
+    }
+
+    if is_mut {
+        format!(
+            "#![allow(unused_variables)]
+pub use oxc_ast::VisitMut as JsVisitMut;
+
+use oxc_allocator::Vec as OxcVec;
+use oxc_ast::ast::Statement;
+
+use crate::{{ast::*, ast_kind::SvelteAstType}};
+use walk_mut::*;
+
+pub trait VisitMut<'a>: JsVisitMut<'a> {{
+    fn enter_svelte_node(&mut self, kind: SvelteAstType) {{}}
+    fn leave_svelte_node(&mut self, kind: SvelteAstType) {{}}
+    fn vec<T, const N: usize>(&self, array: [T; N]) -> OxcVec<'a, T>;
+
+{methods}}}
+
+pub mod walk_mut {{
+    use oxc_syntax::scope::ScopeFlags;
+
+    use super::*;
+
+{walkers}}}
+"
+        )
+    } else {
+        format!(
+            "#![allow(unused_variables)]
+pub use oxc_ast::Visit as JsVisit;
+
+use crate::{{ast::*, ast_kind::SvelteAstKind}};
+use walk::*;
+
+pub use oxc_ast::VisitMut as JsVisitMut;
+
+pub trait Visit<'a>: JsVisit<'a> {{
+    fn enter_svelte_node(&mut self, kind: SvelteAstKind<'a>) {{}}
+    fn leave_svelte_node(&mut self, kind: SvelteAstKind<'a>) {{}}
+
+{methods}}}
+
+pub mod walk {{
+    use oxc_syntax::scope::ScopeFlags;
+
+    use super::*;
+
+{walkers}}}
+"
+        )
+    }
+}
+
+impl Generator<'_> {
+    fn reference(&self) -> &'static str {
+        if self.is_mut {
+            "&mut "
+        } else {
+            "&"
+        }
+    }
+
+    fn return_type(&self) -> &'static str {
+        if self.is_mut {
+            " -> OxcVec<'a, Statement<'a>>"
+        } else {
+            ""
+        }
+    }
+
+    fn visitor_bound(&self) -> &'static str {
+        if self.is_mut {
+            "VisitMut<'a>"
+        } else {
+            "Visit<'a>"
+        }
+    }
+
+    fn visit_method(&self, name: &str, visit_name: &str, has_lifetime: bool) -> String {
+        let semicolon = if self.is_mut { "" } else { ";" };
+        format!(
+            "fn visit_{visit_name}(&mut self, it: {reference}{ty}){return_type} {{
+    walk_{visit_name}(self, it){semicolon}
+}}
+",
+            reference = self.reference(),
+            ty = type_with_lifetime(name, has_lifetime),
+            return_type = self.return_type(),
+        )
+    }
+
+    fn walk_signature(&self, name: &str, visit_name: &str, has_lifetime: bool) -> String {
+        format!(
+            "pub fn walk_{visit_name}<'a, V: {bound}>(visitor: &mut V, it: {reference}{ty}){return_type}",
+            bound = self.visitor_bound(),
+            reference = self.reference(),
+            ty = type_with_lifetime(name, has_lifetime),
+            return_type = self.return_type(),
+        )
+    }
+
+    fn walk_struct(&self, def: &StructDef) -> String {
+        let mut body = String::new();
+        if self.is_mut {
+            body.push_str(&format!("let kind = SvelteAstType::{};\n", def.name));
+        } else {
+            body.push_str(&format!(
+                "let kind = SvelteAstKind::{}(visitor.alloc(it));\n",
+                def.name
+            ));
+        }
+        let collects_result = self.is_mut
+            && def
+                .fields
+                .iter()
+                .any(|field| matches!(field.ty.target, Target::Svelte(_)));
+        if collects_result {
+            body.push_str("let mut result = visitor.vec([]);\n");
+        }
+        body.push_str("visitor.enter_svelte_node(kind);\n");
+
+        let mut current_scope: Option<&str> = None;
+        for field in def.fields.iter() {
+            let scope = field.scope.as_deref();
+            if scope != current_scope {
+                if current_scope.is_some() {
+                    body.push_str("visitor.leave_scope();\n");
+                }
+                if let Some(scope) = scope {
+                    body.push_str(&format!(
+                        "visitor.enter_scope(ScopeFlags::empty(), &it.{scope});\n"
+                    ));
+                }
+                current_scope = scope;
+            }
+            let access = if self.is_mut {
+                format!("&mut it.{}", field.name)
+            } else {
+                format!("&it.{}", field.name)
+            };
+            body.push_str(&self.visit_field(&access, &field.name, &field.ty, "result"));
+        }
+        if current_scope.is_some() {
+            body.push_str("visitor.leave_scope();\n");
+        }
+
+        body.push_str("visitor.leave_svelte_node(kind);\n");
+        if collects_result {
+            body.push_str("result\n");
+        } else if self.is_mut {
+            body.push_str("visitor.vec([])\n");
+        }
+
+        format!(
+            "{} {{\n{body}}}\n\n",
+            self.walk_signature(&def.name, &def.visit_name, def.has_lifetime)
+        )
+    }
+
+    fn walk_enum(&self, def: &EnumDef) -> String {
+        let mut arms = String::new();
+        for variant in def.variants.iter() {
+            let Some(ty) = &variant.ty else {
+                let value = if self.is_mut { "visitor.vec([])" } else { "{}" };
+                arms.push_str(&format!("{}::{} => {value},\n", def.name, variant.name));
+                continue;
+            };
+            let pattern = format!("{}::{}(it)", def.name, variant.name);
+            let visit = self.visit_target(&ty.target, "it");
+            let arm = match (&ty.wrapper, &ty.target) {
+                (Wrapper::None, Target::Svelte(_)) => visit,
+                (Wrapper::None, Target::Js(_)) if !self.is_mut => visit,
+                (Wrapper::None, Target::Js(_)) => format!("{{ {visit}; visitor.vec([]) }}"),
+                _ => {
+                    let visit = self.visit_field("it", "it", ty, "result");
+                    if self.is_mut && matches!(ty.target, Target::Svelte(_)) {
+                        format!("{{ let mut result = visitor.vec([]);\n{visit}result }}")
+                    } else if self.is_mut {
+                        format!("{{ {visit}visitor.vec([]) }}")
+                    } else {
+                        format!("{{ {visit}}}")
+                    }
+                }
+            };
+            arms.push_str(&format!("{pattern} => {arm},\n"));
+        }
+
+        format!(
+            "{} {{\nmatch it {{\n{arms}}}\n}}\n\n",
+            self.walk_signature(&def.name, &def.visit_name, def.has_lifetime)
+        )
+    }
+
+    /// Visits a field whose value is found at `access`. The statements returned by the Svelte
+    /// nodes are appended to `result` by the mutable visitor.
+    fn visit_field(&self, access: &str, binding: &str, ty: &FieldType, result: &str) -> String {
+        let collect = |visit: String| {
+            if self.is_mut && matches!(ty.target, Target::Svelte(_)) {
+                format!("{result}.append(&mut {visit});\n")
+            } else {
+                format!("{visit};\n")
+            }
+        };
+        match ty.wrapper {
+            Wrapper::None => collect(self.visit_target(&ty.target, access)),
+            Wrapper::Option => format!(
+                "if let Some({binding}) = {access} {{\n{}}}\n",
+                collect(self.visit_target(&ty.target, binding))
+            ),
+            Wrapper::Vec => {
+                let iter = if self.is_mut { "iter_mut" } else { "iter" };
+                let access = access.trim_start_matches("&mut ").trim_start_matches('&');
+                format!(
+                    "for item in {access}.{iter}() {{\n{}}}\n",
+                    collect(self.visit_target(&ty.target, "item"))
+                )
+            }
+        }
+    }
+
+    fn visit_target(&self, target: &Target, value: &str) -> String {
+        format!("visitor.visit_{}({value})", self.schema.visit_name(target))
+    }
+}
@@ -0,0 +1,14 @@
+use rusvelte_ast_codegen::{generate, workspace_root};
+
+#[test]
+fn generated_files_are_up_to_date() {
+    let root = workspace_root();
+    for output in generate(&root) {
+        let current = std::fs::read_to_string(root.join(&output.path)).unwrap_or_default();
+        assert!(
+            current == output.content,
+            "{} is outdated, run `just codegen` to regenerate it",
+            output.path.display()
+        );
+    }
+}
@@ -0,0 +1,15 @@
+use std::fs;
+
+use rusvelte_compiler::{compile, CompileOptions};
+
+fn main() -> Result<(), Box<dyn std::error::Error>> {
+    let source = fs::read_to_string("input.svelte")?;
+    let result = compile(&source, CompileOptions::new("App".to_string()))?;
+
+    println!("{}", result.js.code);
+    if let Some(css) = result.css {
+        println!("{}", css.code);
+    }
+
+    Ok(())
+}
@@ -0,0 +1,92 @@
+use oxc_allocator::Allocator;
+use oxc_codegen::Codegen;
+use oxc_span::Span;
+use rusvelte_analyzer::{error::AnalyzerError, Analyzer, CssMode, Generate};
+use rusvelte_parser::{error::ParserError, ParseReturn, Parser};
+use rusvelte_transformer::{render_stylesheet, Transformer};
+
+pub use rusvelte_analyzer::{
+    CompileOptions, CssHashArgs, CssHashGetter, CssMode, Generate, Namespace,
+};
+
+#[derive(Debug)]
+pub struct CompileResult {
+    /// The compiled JavaScript, empty if `generate` is [`Generate::False`]
+    pub js: CompileOutput,
+    /// The compiled CSS, `None` if the component doesn't have a `<style>` element
+    /// or the styles are injected
+    pub css: Option<CompileOutput>,
+    /// Warnings that were emitted during compilation
+    pub warnings: Vec<Warning>,
+    pub metadata: CompileMetadata,
+}
+
+#[derive(Debug)]
+pub struct CompileOutput {
+    pub code: String,
+}
+
+#[derive(Debug, Default)]
+pub struct CompileMetadata {
+    /// Whether the file was compiled in runes mode, either because of an explicit option
+    /// or inferred from usage.
+    pub runes: bool,
+}
+
+#[derive(Debug, Clone)]
+pub struct Warning {
+    pub code: &'static str,
+    pub message: String,
+    pub span: Span,
+}
+
+#[derive(Debug, thiserror::Error)]
+pub enum CompileError {
+    #[error("Failed to parse the component: {0:?}")]
+    Parse(Vec<ParserError>),
+    #[error("Failed to analyze the component: {0:?}")]
+    Analyze(Vec<AnalyzerError>),
+}
+
+/// Converts the source code of a component into a JavaScript module that exports the component.
+pub fn compile(source: &str, options: CompileOptions) -> Result<CompileResult, CompileError> {
+    let allocator = Allocator::default();
+    let ParseReturn { mut root, errors } = Parser::new(source, &allocator).parse();
+    if !errors.is_empty() {
+        return Err(CompileError::Parse(errors));
+    }
+
+    let mut analysis = Analyzer::new(options, &root).analyze(&root);
+    if !analysis.errors.is_empty() {
+        return Err(CompileError::Analyze(std::mem::take(&mut analysis.errors)));
+    }
+    let metadata = CompileMetadata {
+        runes: analysis.runes,
+    };
+    let css = match analysis.options.css {
+        CssMode::External => root.css.as_ref().map(|css| CompileOutput {
+            code: render_stylesheet(css, &analysis.css_hash),
+        }),
+        CssMode::Injected => None,
+    };
+
+    let code = match analysis.options.generate {
+        Generate::Client => {
+            let program = Transformer::new(&allocator, analysis).client_transform(&mut root);
+            Codegen::new().build(&program).code
+        }
+        Generate::Server => {
+            let program = Transformer::new(&allocator, analysis).server_transform(&mut root);
+            Codegen::new().build(&program).code
+        }
+        Generate::False => String::new(),
+    };
+    let js = CompileOutput { code };
+
+    Ok(CompileResult {
+        js,
+        css,
+        warnings: vec![],
+        metadata,
+    })
+}
@@ -0,0 +1,402 @@
+use rusvelte_compiler::{
+    compile, CompileError, CompileOptions, CssHashArgs, CssMode, Generate, Namespace,
+};
+
+#[test]
+fn compile_component() {
+    let source = r#"<script>
+  let count = $state(0);
+</script>
+{count}
+<style>
+  p { color: red; }
+</style>"#;
+    let result = compile(source, CompileOptions::new("App".to_string())).unwrap();
+    assert!(result
+        .js
+        .code
+        .contains("export default function App($$anchor)"));
+    let css = result.css.unwrap().code;
+    assert!(css.trim().starts_with("p.svelte-"));
+    assert!(css.trim().ends_with(" { color: red; }"));
+    assert!(result.warnings.is_empty());
+}
+
+#[test]
+fn compile_error() {
+    let result = compile("{#if true}", CompileOptions::new("App".to_string()));
+    assert!(matches!(result, Err(CompileError::Parse(_))));
+}
+
+#[test]
+fn compile_invalid_transition_modifier() {
+    let source = r#"<div transition:fade|once></div>"#;
+    let result = compile(source, CompileOptions::new("App".to_string()));
+    assert!(matches!(result, Err(CompileError::Parse(_))));
+}
+
+#[test]
+fn compile_invalid_animations() {
+    let source = r#"<script>
+  import { flip } from "svelte/animate";
+  import { fade } from "svelte/transition";
+  let items = $state([1, 2, 3]);
+</script>
+<div animate:flip></div>
+{#each items as item}
+  <div animate:flip></div>
+{/each}
+{#each items as item (item)}
+  <div animate:flip animate:fade></div>
+  <p></p>
+{/each}"#;
+    let result = compile(source, CompileOptions::new("App".to_string()));
+    let Err(CompileError::Analyze(errors)) = result else {
+        panic!("expected the animations to be rejected");
+    };
+    let messages: Vec<String> = errors.iter().map(|error| error.kind.to_string()).collect();
+    let invalid_placement = "An element that uses the `animate:` directive must be the only child \
+                             of a keyed `{#each ...}` block";
+    assert_eq!(
+        messages,
+        [
+            invalid_placement,
+            "An element that uses the `animate:` directive must be the immediate child of a keyed \
+             each block",
+            invalid_placement,
+            invalid_placement,
+            "An element can only have one 'animate' directive",
+        ]
+    );
+}
+
+#[test]
+fn compile_invalid_effect() {
+    let source = r#"<script>
+  const cleanup = $effect(() => {});
+</script>"#;
+    let result = compile(source, CompileOptions::new("App".to_string()));
+    assert!(matches!(result, Err(CompileError::Analyze(errors)) if errors.len() == 1));
+}
+
+#[test]
+fn compile_invalid_props_pattern() {
+    let source = r#"<script>
+  let { a: { b }, [key]: c } = $props();
+</script>"#;
+    let result = compile(source, CompileOptions::new("App".to_string()));
+    assert!(matches!(result, Err(CompileError::Analyze(errors)) if errors.len() == 2));
+}
+
+#[test]
+fn compile_invalid_bindings() {
+    let source = r#"<script>
+  const name = "world";
+  let checked = $state(false);
+  let value = $state("");
+  let plain = "";
+</script>
+<input bind:value={name}>
+<input type="radio" bind:checked>
+<div bind:value></div>
+<input bind:value={value.trim()}>
+<div bind:clientWidth={value}></div>
+<input bind:value={plain}>"#;
+    let result = compile(source, CompileOptions::new("App".to_string()));
+    let Err(CompileError::Analyze(errors)) = result else {
+        panic!("expected the bindings to be rejected");
+    };
+    let messages: Vec<String> = errors.iter().map(|error| error.kind.to_string()).collect();
+    assert_eq!(
+        messages,
+        [
+            "Cannot bind to constant",
+            "`bind:checked` can only be used with `<input type=\"checkbox\">`",
+            "`bind:value` can only be used with `<input>`, `<textarea>`, `<select>`",
+            "Can only bind to an Identifier or MemberExpression",
+            "Can only bind to state or props",
+        ]
+    );
+}
+
+#[test]
+fn compile_let_directive() {
+    let source = r#"<script>
+  import List from "./List.svelte";
+</script>
+<List let:item>{item}</List>"#;
+    let result = compile(source, CompileOptions::new("App".to_string()));
+    assert!(matches!(result, Err(CompileError::Analyze(errors)) if errors.len() == 1));
+}
+
+#[test]
+fn compile_invalid_style_modifier() {
+    let source = r#"<div style:color|bold="red"></div>"#;
+    let result = compile(source, CompileOptions::new("App".to_string()));
+    assert!(matches!(result, Err(CompileError::Parse(_))));
+}
+
+#[test]
+fn compile_inspect() {
+    let source = r#"<script>
+  let count = $state(0);
+  $inspect(count).with(console.trace);
+</script>"#;
+    let options = CompileOptions {
+        dev: true,
+        ..CompileOptions::new("App".to_string())
+    };
+    let code = compile(source, options).unwrap().js.code;
+    assert!(code.contains("$.inspect(() => [$.get(count)], console.trace);"));
+
+    let code = compile(source, CompileOptions::new("App".to_string()))
+        .unwrap()
+        .js
+        .code;
+    assert!(!code.contains("inspect"));
+}
+
+#[test]
+fn compile_infers_runes_mode() {
+    let options = || CompileOptions::new("App".to_string());
+    let result = compile("<script>let count = $state(0);</script>", options()).unwrap();
+    assert!(result.metadata.runes);
+
+    let source = r#"<script>
+  export let count = 0;
+  $: doubled = count * 2;
+</script>
+{doubled}"#;
+    let result = compile(source, options()).unwrap();
+    assert!(!result.metadata.runes);
+    assert!(result.js.code.contains("$.push($$props, false);"));
+    assert!(result.js.code.contains("$.legacy_pre_effect("));
+
+    let result = compile(
+        "<svelte:options runes={true} /><script>let count = 0;</script>",
+        options(),
+    )
+    .unwrap();
+    assert!(result.metadata.runes);
+}
+
+#[test]
+fn compile_with_options() {
+    let source = r#"<p>hello</p>
+<style>
+  p { color: red; }
+</style>"#;
+    let options = CompileOptions {
+        filename: Some("/home/user/project/src/Counter.svelte".to_string()),
+        root_dir: Some("/home/user/project".to_string()),
+        dev: true,
+        css: CssMode::Injected,
+        disclose_version: false,
+        ..Default::default()
+    };
+    let result = compile(source, options).unwrap();
+    let code = result.js.code;
+    assert!(code.contains("export default function Counter($$anchor)"));
+    assert!(code.contains(r#"Counter[$.FILENAME] = "src/Counter.svelte""#));
+    assert!(code.contains("$.append_styles($$anchor, $$css)"));
+    assert!(!code.contains("svelte/internal/disclose-version"));
+    assert!(result.css.is_none());
+}
+
+#[test]
+fn compile_with_custom_css_hash() {
+    let source = r#"<p class="a">hello</p>
+<span>world</span>
+<style>
+  p:hover, div > :global(.b) span::before { color: red; }
+  @keyframes spin { from { opacity: 0; } }
+</style>"#;
+    fn css_hash(args: CssHashArgs) -> String {
+        format!("custom-{}", args.name.to_lowercase())
+    }
+    let options = || CompileOptions {
+        css_hash: Some(css_hash),
+        ..CompileOptions::new("App".to_string())
+    };
+    let result = compile(source, options()).unwrap();
+    assert!(result
+        .js
+        .code
+        .contains(r#"<p class="a custom-app">hello</p>"#));
+    assert!(result
+        .js
+        .code
+        .contains(r#"<span class="custom-app">world</span>"#));
+    assert_eq!(
+        result.css.unwrap().code.trim(),
+        "p.custom-app:hover, div.custom-app > .b span:where(.custom-app)::before { color: red; }
+  @keyframes spin { from { opacity: 0; } }"
+    );
+
+    let server_options = CompileOptions {
+        generate: Generate::Server,
+        ..options()
+    };
+    let code = compile(source, server_options).unwrap().js.code;
+    assert!(code.contains(r#"<p class="a custom-app">hello</p>"#));
+
+    let code = compile(source, CompileOptions::new("App".to_string()))
+        .unwrap()
+        .js
+        .code;
+    assert!(!code.contains("custom-app"));
+    assert!(code.contains(r#"<p class="a svelte-"#));
+}
+
+#[test]
+fn compile_with_hmr() {
+    let options = CompileOptions {
+        hmr: true,
+        ..CompileOptions::new("App".to_string())
+    };
+    let code = compile("<p>hello</p>", options).unwrap().js.code;
+    assert!(code.contains("if (import.meta.hot)"));
+    assert!(code.contains("export default App;"));
+}
+
+#[test]
+fn compile_without_output() {
+    let options = CompileOptions {
+        generate: Generate::False,
+        ..CompileOptions::new("App".to_string())
+    };
+    let result = compile("<p>hello</p>", options).unwrap();
+    assert!(result.js.code.is_empty());
+}
+
+#[test]
+fn compile_for_server() {
+    let options = CompileOptions {
+        generate: Generate::Server,
+        ..CompileOptions::new("App".to_string())
+    };
+    let code = compile("<p>{1 + 1}</p>", options).unwrap().js.code;
+    assert!(code.contains(r#"import * as $ from "svelte/internal/server";"#));
+    assert!(code.contains("export default function App($$payload, $$props)"));
+    assert!(code.contains("$$payload.out += `<p>${$.escape(1 + 1)}</p>`;"));
+}
+
+#[test]
+fn compile_special_nodes_for_server() {
+    let source = r#"<svelte:options runes={true} />
+<script>
+  import Button from "./Button.svelte";
+  let props = $state({});
+</script>
+<svelte:head><title>{props.title}</title></svelte:head>
+<!-- comment -->
+<div class="a {props.class}" {...props} hidden>
+  {@html "<b>bold</b>"}
+  {#each [1, 2] as item}
+    {@const double = item * 2}
+    <Button label="x">{double}</Button>
+  {/each}
+</div>
+<style>
+  div > b, .a::before { color: red; }
+  @media (min-width: 100px) { div { color: blue; } }
+</style>"#;
+    let options = CompileOptions {
+        generate: Generate::Server,
+        ..CompileOptions::new("App".to_string())
+    };
+    let code = compile(source, options).unwrap().js.code;
+    assert!(code.contains("Button($$payload"));
+    assert!(code.contains("const double = item * 2;"));
+}
+
+#[test]
+fn compile_html_in_svg() {
+    let source = r#"<script>
+  let shape = "<circle r=\"1\" />";
+</script>
+<svg>{@html shape}</svg>"#;
+    let code = compile(source, CompileOptions::new("App".to_string()))
+        .unwrap()
+        .js
+        .code;
+    assert!(code.contains("$.html(node, () => shape, true, false);"));
+}
+
+#[test]
+fn compile_whitespace_in_svg() {
+    let source = r#"<svg>
+  <circle r="1" />
+  <rect />
+</svg>"#;
+    for generate in [Generate::Client, Generate::Server] {
+        let options = CompileOptions {
+            generate,
+            ..CompileOptions::new("App".to_string())
+        };
+        let code = compile(source, options).unwrap().js.code;
+        assert!(code.contains(r#"<svg><circle r="1"></circle><rect></rect></svg>"#));
+    }
+
+    let source = "<foreignObject><p>a</p> <p>b</p></foreignObject>";
+    let options = CompileOptions {
+        namespace: Namespace::Svg,
+        ..CompileOptions::new("App".to_string())
+    };
+    let code = compile(source, options).unwrap().js.code;
+    assert!(code.contains("<p>a</p> <p>b</p>"));
+}
+
+#[test]
+fn compile_svg_attribute_case() {
+    let source = r#"<script>
+  let size = $state(10);
+</script>
+<svg viewBox="0 0 {size} {size}"></svg>
+<div tabIndex={size}></div>"#;
+    let code = compile(source, CompileOptions::new("App".to_string()))
+        .unwrap()
+        .js
+        .code;
+    assert!(code.contains("$.set_attribute(svg, \"viewBox\", "));
+    assert!(code.contains("$.set_attribute(div, \"tabindex\", "));
+}
+
+#[test]
+fn compile_debug_tag() {
+    let source = r#"<script>
+  let count = $state(0);
+  let name = "world";
+</script>
+{@debug count, name}"#;
+    let options = CompileOptions {
+        dev: true,
+        ..CompileOptions::new("App".to_string())
+    };
+    let code = compile(source, options).unwrap().js.code;
+    assert!(code.contains("console.log"));
+    assert!(code.contains("count: $.get(count)"));
+    assert!(code.contains("debugger;"));
+
+    let code = compile(source, CompileOptions::new("App".to_string()))
+        .unwrap()
+        .js
+        .code;
+    assert!(!code.contains("debugger"));
+
+    let options = CompileOptions {
+        dev: true,
+        generate: Generate::Server,
+        ..CompileOptions::new("App".to_string())
+    };
+    let code = compile(source, options).unwrap().js.code;
+    assert!(code.contains("console.log("));
+    assert!(code.contains("debugger;"));
+
+    let options = CompileOptions {
+        generate: Generate::Server,
+        ..CompileOptions::new("App".to_string())
+    };
+    let code = compile(source, options).unwrap().js.code;
+    assert!(!code.contains("debugger"));
+}
@@ -95,6 +95,8 @@ pub enum ParserErrorKind {
     ElementInvalidClosingTagAutoClosed { reason: String, name: String },
     #[error("`</{0}>` attempted to close an element that was not open")]
     ElementInvalidClosingTag(String),
+    #[error("`<{0}>` attempted to implicitly close an element that was not open")]
+    ElementInvalidAutoClose(String),
     #[error("{{#{name} ...}} block cannot be {location}")]
     BlockInvalidPlacement { name: String, location: String },
     #[error("{{@{name} ...}} tag cannot be {location}")]
@@ -103,12 +105,20 @@ pub enum ParserErrorKind {
     DirectiveMissingName(String),
     #[error("Directive value must be a JavaScript expression enclosed in curly braces")]
     DirectiveInvalidValue,
+    #[error("`style:` directive can only use the `important` modifier")]
+    StyleDirectiveInvalidModifier,
+    #[error("Valid modifiers for transitions are `local` and `global`")]
+    TransitionDirectiveInvalidModifier,
     #[error("Attributes need to be unique")]
     AttributeDuplicate,
     #[error("Expected whitespace")]
     ExpectedWhitespace,
     #[error("Block was left open")]
     BlockUnclosed,
+    #[error("Unexpected block closing tag")]
+    BlockUnexpectedClose,
+    #[error("{{:...}} block is invalid at this position (did you forget to close the preceding element or block?)")]
+    BlockInvalidContinuationPlacement,
     #[error("'elseif' should be 'else if'")]
     BlockInvalidElseif,
     #[error("Expected an identifier")]
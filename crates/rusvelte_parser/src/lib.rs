@@ -52,10 +52,20 @@ pub struct Parser<'a> {
     last_auto_closed_tag: Option<LastAutoClosedTag<'a>>,
     options: Option<SvelteOptions<'a>>,
     ast: AstBuilder<'a>,
+    parse_options: ParseOptions,
+    errors: Vec<ParserError>,
+}
+
+#[derive(Debug, Default, Clone, Copy)]
+pub struct ParseOptions {
+    /// Recover from errors instead of stopping at the first one, so that a best-effort AST
+    /// can be produced for incomplete or broken components, e.g. while the user is typing.
+    pub loose: bool,
 }
 
 pub struct ParseReturn<'a> {
     pub root: Root<'a>,
+    /// All the collected errors. Without the `loose` option, it contains at most one error.
     pub errors: Vec<ParserError>,
 }
 
@@ -83,9 +93,16 @@ impl<'a> Parser<'a> {
             context_stack: vec![],
             options: None,
             ast,
+            parse_options: ParseOptions::default(),
+            errors: vec![],
         }
     }
 
+    pub fn with_options(mut self, options: ParseOptions) -> Self {
+        self.parse_options = options;
+        self
+    }
+
     fn offset_usize(&self) -> usize {
         self.offset as usize
     }
@@ -100,13 +117,14 @@ impl<'a> Parser<'a> {
             instance: None,
             options: None,
         };
-        root.fragment = match self.parse_fragment(false) {
+        root.fragment = match self.parse_root_fragment() {
             Ok(f) => f,
             Err(e) => {
+                self.errors.push(e);
                 return ParseReturn {
                     root,
-                    errors: vec![e],
-                }
+                    errors: std::mem::take(&mut self.errors),
+                };
             }
         };
         let start = root.fragment.nodes.first().map_or(0, |node| {
@@ -138,10 +156,54 @@ impl<'a> Parser<'a> {
 
         ParseReturn {
             root,
-            errors: vec![],
+            errors: std::mem::take(&mut self.errors),
+        }
+    }
+
+    /// Record the error and keep parsing in loose mode, otherwise return it.
+    fn recover(&mut self, error: ParserError) -> Result<(), ParserError> {
+        if self.parse_options.loose {
+            self.errors.push(error);
+            Ok(())
+        } else {
+            Err(error)
         }
     }
 
+    /// Like [`Parser::parse_expression`], but in loose mode a broken expression is recorded as an error
+    /// and replaced by an empty identifier that spans until the closing `}`.
+    fn parse_expression_or_recover(&mut self) -> Result<Expression<'a>, ParserError> {
+        let start = self.offset;
+        let error = match self.parse_expression() {
+            Ok(expr) => return Ok(expr),
+            Err(error) => error,
+        };
+        self.offset = start;
+        self.recover(error)?;
+
+        let mut depth = 0;
+        while let Some(ch) = self.peek() {
+            match ch {
+                '{' | '(' | '[' => depth += 1,
+                ')' | ']' => depth -= 1,
+                '}' if depth == 0 => break,
+                '}' => depth -= 1,
+                '<' if depth == 0 => break,
+                _ => (),
+            }
+            self.next();
+        }
+        let identifier = IdentifierReference {
+            span: Span::new(start, self.offset),
+            name: "".into(),
+            reference_id: Cell::default(),
+        };
+        Ok(Expression::Identifier(oxc_allocator::Box::new_in(
+            identifier,
+            self.allocator,
+        )))
+    }
+
     pub fn parse_expression(&mut self) -> Result<Expression<'a>, ParserError> {
         let mut expr = OxcParser::new(self.allocator, self.remain(), self.source_type)
             .parse_expression()
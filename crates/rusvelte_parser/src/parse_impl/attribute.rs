@@ -160,12 +160,23 @@ impl<'a> Parser<'a> {
             }
 
             if directive_kind == DirectiveKind::StyleDirective {
+                let important = match modifiers.as_slice() {
+                    [] => false,
+                    ["important"] => true,
+                    _ => {
+                        return Err(ParserError::new(
+                            Span::new(start, end),
+                            ParserErrorKind::StyleDirectiveInvalidModifier,
+                        ))
+                    }
+                };
                 return Ok(Some(Attribute::Directive(Directive::StyleDirective(
                     StyleDirective {
                         span: Span::new(start, end),
                         name: directive_name,
                         value,
-                        modifiers,
+                        important,
+                        expression_metadata: Default::default(),
                     },
                 ))));
             }
@@ -206,7 +217,13 @@ impl<'a> Parser<'a> {
                 directive_name,
                 expression,
                 modifiers,
-            );
+            )
+            .map_err(|_| {
+                ParserError::new(
+                    Span::new(start, end),
+                    ParserErrorKind::TransitionDirectiveInvalidModifier,
+                )
+            })?;
 
             return Ok(Some(Attribute::Directive(directive)));
         }
@@ -9,12 +9,10 @@ use std::{cell::Cell, sync::LazyLock};
 use crate::{
     context::Context,
     error::{ParserError, ParserErrorKind},
-    regex_pattern::REGEX_START_WHITESPACE_WITH_CLOSING_CURLY_BRACE,
+    regex_pattern::{REGEX_START_NEXT_BLOCK, REGEX_START_WHITESPACE_WITH_CLOSING_CURLY_BRACE},
     Parser,
 };
 
-static REGEX_START_NEXT_BLOCK: LazyLock<Regex> =
-    LazyLock::new(|| Regex::new(r#"^\{\s*:"#).unwrap());
 static REGEX_START_CLOSE_BLOCK: LazyLock<Regex> =
     LazyLock::new(|| Regex::new(r#"^\{\s*\/"#).unwrap());
 
@@ -29,11 +27,10 @@ impl<'a> Parser<'a> {
         self.expect('#')?;
         if self.eat_str(IF_STR) {
             let (is_closed, if_block) = self.parse_if_block(start)?;
-            if is_closed {
-                Ok(Block::IfBlock(if_block))
-            } else {
-                Err(self.error_at(start, ParserErrorKind::BlockUnclosed))
+            if !is_closed {
+                self.recover(self.error_at(start, ParserErrorKind::BlockUnclosed))?;
             }
+            Ok(Block::IfBlock(if_block))
         } else if self.eat_str(EACH_STR) {
             Ok(Block::EachBlock(self.parse_each_block(start)?))
         } else if self.eat_str(AWAIT_STR) {
@@ -50,7 +47,7 @@ impl<'a> Parser<'a> {
     /// return whether the if block if closed and the if block
     fn parse_if_block(&mut self, start: u32) -> Result<(bool, IfBlock<'a>), ParserError> {
         self.expect_whitespace()?;
-        let test = self.parse_expression()?;
+        let test = self.parse_expression_or_recover()?;
         self.skip_whitespace();
         self.expect('}')?;
 
@@ -204,7 +201,7 @@ impl<'a> Parser<'a> {
         }
 
         self.pop_context();
-        self.expect_close_block(EACH_STR)?;
+        self.expect_close_block(EACH_STR, start)?;
 
         Ok(EachBlock {
             span: Span::new(start, self.offset),
@@ -214,12 +211,15 @@ impl<'a> Parser<'a> {
             fallback,
             index,
             key,
+            expression_metadata: Default::default(),
+            scope_id: Cell::default(),
+            is_controlled: Cell::default(),
         })
     }
 
     fn parse_await_block(&mut self, start: u32) -> Result<AwaitBlock<'a>, ParserError> {
         self.expect_whitespace()?;
-        let expression = self.parse_expression()?;
+        let expression = self.parse_expression_or_recover()?;
         self.skip_whitespace();
 
         let mut value = None;
@@ -310,7 +310,7 @@ impl<'a> Parser<'a> {
         }
 
         self.pop_context();
-        self.expect_close_block(AWAIT_STR)?;
+        self.expect_close_block(AWAIT_STR, start)?;
 
         Ok(AwaitBlock {
             span: Span::new(start, self.offset),
@@ -320,24 +320,27 @@ impl<'a> Parser<'a> {
             pending,
             then,
             catch,
+            then_scope_id: Cell::default(),
+            catch_scope_id: Cell::default(),
         })
     }
 
     fn parse_key_block(&mut self, start: u32) -> Result<KeyBlock<'a>, ParserError> {
         self.expect_whitespace()?;
-        let expression = self.parse_expression()?;
+        let expression = self.parse_expression_or_recover()?;
         self.skip_whitespace();
         self.expect('}')?;
         self.push_context(Context::block_context(KEY_STR));
         let fragment = self.parse_fragment(false)?;
         self.pop_context();
 
-        self.expect_close_block(KEY_STR)?;
+        self.expect_close_block(KEY_STR, start)?;
 
         Ok(KeyBlock {
             span: Span::new(start, self.offset),
             expression,
             fragment,
+            expression_metadata: Default::default(),
         })
     }
 
@@ -368,21 +371,35 @@ impl<'a> Parser<'a> {
         self.push_context(Context::block_context(SNIPPET_STR));
         let body = self.parse_fragment(false)?;
         self.pop_context();
-        self.expect_close_block(SNIPPET_STR)?;
+        self.expect_close_block(SNIPPET_STR, start)?;
 
         Ok(SnippetBlock {
             span: Span::new(start, self.offset),
             expression,
             parameters,
             body,
+            scope_id: Cell::default(),
         })
     }
 
-    fn expect_close_block(&mut self, name: &'a str) -> Result<(), ParserError> {
-        self.expect_regex(&REGEX_START_CLOSE_BLOCK)?;
-        self.expect_str(name)?;
-        self.skip_whitespace();
-        self.expect('}')?;
+    fn expect_close_block(&mut self, name: &'a str, start: u32) -> Result<(), ParserError> {
+        let close_start = self.offset;
+        let result = self
+            .expect_regex(&REGEX_START_CLOSE_BLOCK)
+            .and_then(|_| self.expect_str(name))
+            .and_then(|_| {
+                self.skip_whitespace();
+                self.expect('}')
+            });
+        if let Err(error) = result {
+            if !self.parse_options.loose {
+                return Err(error);
+            }
+            // leave the unmatched notation to the parent
+            self.offset = close_start;
+            self.errors
+                .push(self.error_at(start, ParserErrorKind::BlockUnclosed));
+        }
         Ok(())
     }
 }
@@ -191,7 +191,7 @@ impl<'a> ParseElementReturn<'a> {
                 name,
                 attributes,
                 fragment,
-                dynamic: false,
+                dynamic: Cell::new(false),
             }),
             "title" => Element::TitleElement(TitleElement {
                 span,
@@ -327,12 +327,37 @@ impl<'a> Parser<'a> {
             ));
         }
 
-        let closing_tag_name = self
-            .peek_closing_tag_name()
-            .ok_or(self.error(ParserErrorKind::ExpectedClosingTag))?;
+        let Some(closing_tag_name) = self.peek_closing_tag_name() else {
+            if !self.parse_options.loose {
+                return Err(self.error(ParserErrorKind::ExpectedClosingTag));
+            }
+            // treat the element as closed where its fragment ends
+            self.errors
+                .push(self.error_at(start, ParserErrorKind::ElementUnclosed(name.to_string())));
+            return ParseElementReturn::element(
+                self.allocator,
+                Span::new(start, self.offset),
+                name,
+                attributes,
+                fragment,
+            );
+        };
         if closing_tag_name != name {
             // close any elements that don't have their own closing tags, e.g. <div><p></div>
             if !self.is_parent_regular_element() {
+                if self.parse_options.loose {
+                    // leave the closing tag to the ancestors
+                    self.errors.push(
+                        self.error_at(start, ParserErrorKind::ElementUnclosed(name.to_string())),
+                    );
+                    return ParseElementReturn::element(
+                        self.allocator,
+                        Span::new(start, self.offset),
+                        name,
+                        attributes,
+                        fragment,
+                    );
+                }
                 match self.last_auto_closed_tag.as_ref() {
                     Some(last_auto_closed_tag) if last_auto_closed_tag.tag == name => {
                         return Err(self.error_at(
@@ -1,6 +1,10 @@
 use std::sync::LazyLock;
 
-use crate::{constants::SVELTE_OPTIONS_TAG, Parser, ParserError, ParserErrorKind};
+use crate::{
+    constants::SVELTE_OPTIONS_TAG,
+    regex_pattern::{REGEX_START_NEXT_BLOCK, REGEX_WHITESPACE_OR_SLASH_OR_CLOSING_TAG},
+    Parser, ParserError, ParserErrorKind,
+};
 use oxc_allocator::CloneIn;
 use oxc_ast::ast::{Expression, ObjectPropertyKind};
 use oxc_span::{GetSpan, Span};
@@ -46,7 +50,23 @@ impl<'a> Parser<'a> {
     pub fn parse_fragment(&mut self, transparent: bool) -> Result<Fragment<'a>, ParserError> {
         let mut nodes = self.ast.vec([]);
         while self.offset_usize() < self.source.len() && !self.match_str("</") {
-            match self.parse_fragment_node()? {
+            let start = self.offset;
+            let depth = self.context_stack.len();
+            let node = match self.parse_fragment_node() {
+                Ok(node) => node,
+                Err(error) => {
+                    self.recover(error)?;
+                    // skip the broken node as text so that the parsing can move forward
+                    self.context_stack.truncate(depth);
+                    self.offset = start;
+                    self.next();
+                    self.parse_text();
+                    let text = self.create_text(Span::new(start, self.offset));
+                    nodes.push(FragmentNode::Text(text));
+                    continue;
+                }
+            };
+            match node {
                 ParseFragmentNodeReturn::Node(node) => {
                     nodes.push(node);
                 }
@@ -58,19 +78,21 @@ impl<'a> Parser<'a> {
                     match script.context {
                         ScriptContext::Default => {
                             if self.instance.is_some() {
-                                return Err(ParserError::new(
+                                self.recover(ParserError::new(
                                     script.span,
                                     ParserErrorKind::ScriptDuplicate,
-                                ));
+                                ))?;
+                                continue;
                             }
                             self.instance = Some(script)
                         }
                         ScriptContext::Module => {
                             if self.module.is_some() {
-                                return Err(ParserError::new(
+                                self.recover(ParserError::new(
                                     script.span,
                                     ParserErrorKind::ScriptDuplicate,
-                                ));
+                                ))?;
+                                continue;
                             }
                             self.module = Some(script)
                         }
@@ -78,10 +100,11 @@ impl<'a> Parser<'a> {
                 }
                 ParseFragmentNodeReturn::StyleSheet(mut style_sheet) => {
                     if self.css.is_some() {
-                        return Err(ParserError::new(
+                        self.recover(ParserError::new(
                             style_sheet.span,
                             ParserErrorKind::StyleDuplicate,
-                        ));
+                        ))?;
+                        continue;
                     }
                     style_sheet.content.comment = self.find_leading_comment(&nodes);
                     self.css = Some(style_sheet);
@@ -92,6 +115,39 @@ impl<'a> Parser<'a> {
         Ok(self.ast.fragment(nodes, transparent))
     }
 
+    /// Parse the top-level fragment. The stray closing tags and blocks which end a fragment early
+    /// are errors, in loose mode they are recorded and skipped.
+    pub fn parse_root_fragment(&mut self) -> Result<Fragment<'a>, ParserError> {
+        let mut fragment = self.parse_fragment(false)?;
+        while self.offset_usize() < self.source.len() {
+            let start = self.offset;
+            let kind = if self.eat_str("</") {
+                let name = self.eat_until(&REGEX_WHITESPACE_OR_SLASH_OR_CLOSING_TAG);
+                ParserErrorKind::ElementInvalidClosingTag(name.to_string())
+            } else if self.eat_regex(&REGEX_START_NEXT_BLOCK).is_some() {
+                ParserErrorKind::BlockInvalidContinuationPlacement
+            } else if self.eat_str("<") {
+                // an element which implicitly closes its parent, e.g. a `<p>` inside a `<p>`
+                let name = self.eat_until(&REGEX_WHITESPACE_OR_SLASH_OR_CLOSING_TAG);
+                ParserErrorKind::ElementInvalidAutoClose(name.to_string())
+            } else {
+                self.next();
+                ParserErrorKind::BlockUnexpectedClose
+            };
+            self.recover(self.error_at(start, kind))?;
+            while let Some(ch) = self.next() {
+                if matches!(ch, '>' | '}') {
+                    break;
+                }
+            }
+
+            let rest = self.parse_fragment(false)?;
+            fragment.nodes.extend(rest.nodes);
+        }
+
+        Ok(fragment)
+    }
+
     fn parse_fragment_node(&mut self) -> Result<ParseFragmentNodeReturn<'a>, ParserError> {
         let node = if self.match_str("<") {
             let parse_element_return = self.parse_element()?;
@@ -1,3 +1,5 @@
+use std::cell::Cell;
+
 use oxc_ast::ast::{ChainElement, Expression};
 use oxc_span::{GetSpan, Span};
 use rusvelte_ast::ast::{ConstTag, DebugTag, HtmlTag, RenderTag, RenderTagExpression, Tag};
@@ -15,7 +17,7 @@ impl<'a> Parser<'a> {
         if self.eat('@') {
             if self.eat_str("html") {
                 self.expect_whitespace()?;
-                let expression = self.parse_expression()?;
+                let expression = self.parse_expression_or_recover()?;
                 self.expect_close_tag()?;
                 Ok(Tag::HtmlTag(HtmlTag {
                     span: get_span(self),
@@ -90,14 +92,14 @@ impl<'a> Parser<'a> {
                 Ok(Tag::RenderTag(RenderTag {
                     span: get_span(self),
                     expression,
-                    dynamic: false,
+                    dynamic: Cell::new(false),
                 }))
             } else {
                 // Svelte parser doesn't return error here, weird
                 Err(self.error(ParserErrorKind::ExpectedTagType))
             }
         } else {
-            let expr = self.parse_expression()?;
+            let expr = self.parse_expression_or_recover()?;
             self.skip_whitespace();
             self.expect('}')?;
 
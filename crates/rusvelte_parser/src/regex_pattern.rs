@@ -11,3 +11,5 @@ pub static REGEX_START_WHITESPACE_WITH_CLOSING_CURLY_BRACE: LazyLock<Regex> =
 pub static REGEX_VALID_COMPONENT_NAME: LazyLock<Regex> = LazyLock::new(|| {
     Regex::new(r"^(?:\p{Lu}[$\u200c\u200d\p{ID_Continue}.]*|\p{ID_Start}[$\u200c\u200d\p{ID_Continue}]*(?:\.[$\u200c\u200d\p{ID_Continue}]+)+)$").unwrap()
 });
+pub static REGEX_START_NEXT_BLOCK: LazyLock<Regex> =
+    LazyLock::new(|| Regex::new(r#"^\{\s*:"#).unwrap());
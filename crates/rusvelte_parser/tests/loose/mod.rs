@@ -0,0 +1,79 @@
+use oxc_allocator::Allocator;
+use rusvelte_ast::ast::{Block, Element, FragmentNode, Tag};
+use rusvelte_parser::{error::ParserErrorKind, ParseOptions, ParseReturn, Parser};
+
+fn parse_loose<'a>(source: &'a str, allocator: &'a Allocator) -> ParseReturn<'a> {
+    Parser::new(source, allocator)
+        .with_options(ParseOptions { loose: true })
+        .parse()
+}
+
+#[test]
+fn unclosed_element() {
+    let allocator = Allocator::default();
+    let ret = parse_loose("<div><span>hello", &allocator);
+    assert_eq!(ret.errors.len(), 2);
+    assert!(ret
+        .errors
+        .iter()
+        .all(|error| matches!(error.kind, ParserErrorKind::ElementUnclosed(_))));
+    let FragmentNode::Element(element) = &ret.root.fragment.nodes[0] else {
+        panic!("Expected an element");
+    };
+    assert!(matches!(element.as_ref(), Element::RegularElement(div) if div.name == "div"));
+}
+
+#[test]
+fn unclosed_block() {
+    let allocator = Allocator::default();
+    let ret = parse_loose("{#if a}<p>{a}</p>", &allocator);
+    assert_eq!(ret.errors.len(), 1);
+    assert!(matches!(ret.errors[0].kind, ParserErrorKind::BlockUnclosed));
+    assert!(matches!(
+        ret.root.fragment.nodes[0],
+        FragmentNode::Block(Block::IfBlock(_))
+    ));
+}
+
+#[test]
+fn broken_expression() {
+    let allocator = Allocator::default();
+    let ret = parse_loose("<p>{a.}</p><p>{b}</p>", &allocator);
+    assert_eq!(ret.errors.len(), 1);
+    assert_eq!(ret.root.fragment.nodes.len(), 2);
+    let FragmentNode::Element(element) = &ret.root.fragment.nodes[0] else {
+        panic!("Expected an element");
+    };
+    let Element::RegularElement(p) = element.as_ref() else {
+        panic!("Expected a regular element");
+    };
+    assert!(matches!(
+        p.fragment.nodes[0],
+        FragmentNode::Tag(Tag::ExpressionTag(_))
+    ));
+}
+
+#[test]
+fn multiple_errors() {
+    let allocator = Allocator::default();
+    let ret = parse_loose("{/each}<p>{a +}</p>{#key}", &allocator);
+    assert_eq!(ret.errors.len(), 3);
+    assert!(matches!(
+        ret.errors[0].kind,
+        ParserErrorKind::BlockUnexpectedClose
+    ));
+}
+
+#[test]
+fn strict_mode_stops_at_first_error() {
+    let allocator = Allocator::default();
+    let ret = Parser::new("{/each}<p>{a +}</p>{#key}", &allocator).parse();
+    assert_eq!(ret.errors.len(), 1);
+    assert!(matches!(
+        ret.errors[0].kind,
+        ParserErrorKind::BlockUnexpectedClose
+    ));
+    let ret = Parser::new("<p>{a +}</p>{#if a}", &allocator).parse();
+    assert_eq!(ret.errors.len(), 1);
+    assert!(ret.root.fragment.nodes.is_empty());
+}
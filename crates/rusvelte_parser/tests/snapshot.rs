@@ -2,6 +2,7 @@ mod attributes;
 mod block;
 mod css;
 mod element;
+mod loose;
 mod script;
 mod simple;
 mod tag;
@@ -1,7 +1,7 @@
 use std::fs;
 
 use oxc_codegen::Codegen;
-use rusvelte_analyzer::{Analysis, Analyzer, CompileOptions};
+use rusvelte_analyzer::{Analyzer, CompileOptions};
 use rusvelte_parser::Parser;
 use rusvelte_transformer::Transformer;
 
@@ -12,14 +12,9 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
     let mut parser = Parser::new(&source, &allocator);
     let mut root = parser.parse().root;
     let analyzer = Analyzer::new(CompileOptions::new("App".to_string()), &root);
-    let Analysis {
-        scopes,
-        symbols,
-        references,
-        ..
-    } = analyzer.analyze(&root);
+    let analysis = analyzer.analyze(&root);
 
-    let transformer = Transformer::new(&allocator, scopes, symbols, references);
+    let transformer = Transformer::new(&allocator, analysis);
     let program = transformer.client_transform(&mut root);
 
     let instance = Codegen::new().build(&program);
@@ -0,0 +1,143 @@
+use oxc_span::{GetSpan, Span};
+use rusvelte_ast::ast::{
+    AtRule, BlockChild, CSSBlock, ComplexSelector, RelativeSelector, Rule, SimpleSelector,
+    StyleSheet, StyleSheetChild,
+};
+
+/// Returns the styles of the component with every selector scoped to its elements, e.g.
+/// `p:hover` becomes `p.svelte-xyz:hover`. The selectors in `:global(...)` are left as is.
+pub fn render_stylesheet(css: &StyleSheet, hash: &str) -> String {
+    let mut scoper = Scoper {
+        class: format!(".{hash}"),
+        edits: vec![],
+    };
+    for child in css.children.iter() {
+        match child {
+            StyleSheetChild::Rule(rule) => scoper.scope_rule(rule),
+            StyleSheetChild::AtRule(at_rule) => scoper.scope_at_rule(at_rule),
+        }
+    }
+
+    let styles = css.content.styles;
+    let offset = css.content.span.start;
+    let mut edits = scoper.edits;
+    edits.sort_by_key(|(span, _)| span.start);
+    let mut result = String::with_capacity(styles.len());
+    let mut last = 0;
+    for (span, replacement) in edits {
+        let start = (span.start - offset) as usize;
+        result.push_str(&styles[last..start]);
+        result.push_str(&replacement);
+        last = (span.end - offset) as usize;
+    }
+    result.push_str(&styles[last..]);
+    result
+}
+
+struct Scoper {
+    /// `.svelte-xyz`
+    class: String,
+    /// The replacements of the source, an empty span being an insertion
+    edits: Vec<(Span, String)>,
+}
+
+impl Scoper {
+    fn scope_rule(&mut self, rule: &Rule) {
+        for selector in rule.prelude.children.iter() {
+            self.scope_complex_selector(selector);
+        }
+        self.scope_block(&rule.block);
+    }
+
+    fn scope_at_rule(&mut self, at_rule: &AtRule) {
+        // the percentages of the keyframes are not selectors
+        if at_rule.name.ends_with("keyframes") {
+            return;
+        }
+        if let Some(block) = &at_rule.block {
+            self.scope_block(block);
+        }
+    }
+
+    fn scope_block(&mut self, block: &CSSBlock) {
+        for child in block.children.iter() {
+            match child {
+                BlockChild::Rule(rule) => self.scope_rule(rule),
+                BlockChild::AtRule(at_rule) => self.scope_at_rule(at_rule),
+                BlockChild::Declaration(_) => {}
+            }
+        }
+    }
+
+    /// Only the first scoped selector raises the specificity, the others are wrapped in
+    /// `:where(...)`.
+    fn scope_complex_selector(&mut self, selector: &ComplexSelector) {
+        let mut bumped = false;
+        for relative in selector.children.iter() {
+            if self.unwrap_global(relative) {
+                continue;
+            }
+            // `&` refers to the parent selector, which is already scoped
+            if relative
+                .selectors
+                .iter()
+                .any(|selector| matches!(selector, SimpleSelector::NestingSelector(_)))
+            {
+                continue;
+            }
+            let modifier = if bumped {
+                format!(":where({})", self.class)
+            } else {
+                self.class.clone()
+            };
+            bumped = true;
+            self.scope_relative_selector(relative, modifier);
+        }
+    }
+
+    /// Appends the modifier to the last selector which isn't a pseudo class or element
+    fn scope_relative_selector(&mut self, relative: &RelativeSelector, modifier: String) {
+        for (index, selector) in relative.selectors.iter().enumerate().rev() {
+            match selector {
+                SimpleSelector::PseudoClassSelector(pseudo)
+                    if matches!(pseudo.name, "root" | "host") => {}
+                SimpleSelector::PseudoClassSelector(_)
+                | SimpleSelector::PseudoElementSelector(_) => {
+                    if index == 0 {
+                        let start = selector.span().start;
+                        self.edits.push((Span::new(start, start), modifier));
+                        return;
+                    }
+                }
+                SimpleSelector::TypeSelector(type_selector) if type_selector.name == "*" => {
+                    self.edits.push((type_selector.span, modifier));
+                    return;
+                }
+                _ => {
+                    let end = selector.span().end;
+                    self.edits.push((Span::new(end, end), modifier));
+                    return;
+                }
+            }
+        }
+    }
+
+    /// Removes the `:global(...)` around a selector, returning whether it was global
+    fn unwrap_global(&mut self, relative: &RelativeSelector) -> bool {
+        let mut is_global = false;
+        for selector in relative.selectors.iter() {
+            let SimpleSelector::PseudoClassSelector(pseudo) = selector else {
+                continue;
+            };
+            let Some(args) = pseudo.args.as_ref().filter(|_| pseudo.name == "global") else {
+                continue;
+            };
+            is_global = true;
+            self.edits
+                .push((Span::new(pseudo.span.start, args.span.start), String::new()));
+            self.edits
+                .push((Span::new(args.span.end, pseudo.span.end), String::new()));
+        }
+        is_global
+    }
+}
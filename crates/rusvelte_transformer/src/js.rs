@@ -1,12 +1,22 @@
 use std::cell::Cell;
 
-use rusvelte_analyzer::{binding::BindingKind, ScopeFlags, ScopeId};
+use oxc_allocator::Vec as OxcVec;
+use oxc_span::Atom;
+use rusvelte_analyzer::{
+    binding::BindingKind,
+    rune::{get_expression_rune, get_rune, Rune},
+    ScopeFlags, ScopeId, SymbolId,
+};
+use rusvelte_ast::traits::extract_identifier::ExtractIdentifier;
 use rusvelte_ast::visit::JsVisitMut;
 
 use rusvelte_ast::js_ast::*;
 use rusvelte_ast::js_walk::walk_mut::*;
+use rusvelte_utils::constants::{
+    PROPS_IS_BINDABLE, PROPS_IS_IMMUTABLE, PROPS_IS_LAZY_INITIAL, PROPS_IS_RUNES, PROPS_IS_UPDATED,
+};
 
-use crate::Transformer;
+use crate::{ReadTransform, Transformer};
 
 impl<'a> JsVisitMut<'a> for Transformer<'a> {
     fn enter_scope(&mut self, _flags: ScopeFlags, scope_id: &Cell<Option<ScopeId>>) {
@@ -19,15 +29,80 @@ impl<'a> JsVisitMut<'a> for Transformer<'a> {
         }
     }
 
+    fn visit_variable_declaration(&mut self, decl: &mut VariableDeclaration<'a>) {
+        let declarators = std::mem::replace(&mut decl.declarations, self.ast.vec([]));
+        for mut declarator in declarators {
+            if declarator.init.as_ref().and_then(get_expression_rune) == Some(Rune::Props) {
+                self.build_props_declarators(declarator, &mut decl.declarations);
+            } else {
+                self.visit_variable_declarator(&mut declarator);
+                decl.declarations.push(declarator);
+            }
+        }
+    }
+
     fn visit_variable_declarator(&mut self, decl: &mut VariableDeclarator<'a>) {
-        if let Some(ident) = decl.id.get_binding_identifier() {
-            let symbol_id = ident.symbol_id();
-            let binding = self.symbols.get_binding(symbol_id);
-            if binding.kind() == BindingKind::State {
-                if let Expression::CallExpression(expr) = decl.init.as_mut().unwrap() {
-                    expr.callee = self.ast.expression_identifier_reference("$.state")
+        let rune = decl.init.as_ref().and_then(get_expression_rune);
+        let binding = decl
+            .id
+            .get_binding_identifier()
+            .map(|ident| (ident.name, ident.symbol_id()));
+        let kind = binding.map(|(_, symbol_id)| self.symbols.get_binding(symbol_id).kind());
+        // `export let name = fallback`
+        if let (false, Some((name, symbol_id)), Some(BindingKind::BindableProp)) =
+            (self.runes, binding, kind)
+        {
+            let mut initial = decl.init.take();
+            if let Some(initial) = initial.as_mut() {
+                self.visit_expression(initial);
+            }
+            self.uses_props = true;
+            // the props are synchronized with the context of the component
+            self.needs_context = true;
+            self.read_transforms.insert(symbol_id, ReadTransform::Call);
+            decl.init = Some(self.build_prop_source(&name, symbol_id, initial));
+            return;
+        }
+        walk_variable_declarator(self, decl);
+        // `let count = 0` read by the template and updated
+        if !self.runes && rune.is_none() && kind == Some(BindingKind::State) {
+            let args = match decl.init.take() {
+                Some(init) => self.ast.vec([init.into()]),
+                None => self.ast.vec([]),
+            };
+            decl.init = Some(self.ast.expression_call_with_atom("$.mutable_source", args));
+            return;
+        }
+        let Some(Expression::CallExpression(call)) = decl.init.as_mut() else {
+            return;
+        };
+        match rune {
+            Some(Rune::State | Rune::StateRaw) => {
+                call.callee = self.ast.expression_identifier_reference("$.state");
+            }
+            // `$derived(value)` -> `$.derived(() => value)`
+            Some(Rune::Derived) => {
+                call.callee = self.ast.expression_identifier_reference("$.derived");
+                if let Some(value) = call
+                    .arguments
+                    .first_mut()
+                    .and_then(Argument::as_expression_mut)
+                {
+                    let value = self.ast.move_expression(value);
+                    call.arguments[0] = self
+                        .ast
+                        .expression_arrow(
+                            self.ast.vec([]),
+                            self.ast.vec([self.ast.statement_expression(value)]),
+                        )
+                        .into();
                 }
             }
+            // `$derived.by(fn)` -> `$.derived(fn)`
+            Some(Rune::DerivedBy) => {
+                call.callee = self.ast.expression_identifier_reference("$.derived");
+            }
+            _ => {}
         }
     }
 
@@ -37,31 +112,117 @@ impl<'a> JsVisitMut<'a> for Transformer<'a> {
     }
 
     fn visit_statement(&mut self, stmt: &mut Statement<'a>) {
+        if !self.runes && self.current_scope_id == self.scopes.root_scope_id() {
+            // `export let name` declares a prop, the export itself goes away
+            let declaration = match stmt {
+                Statement::ExportNamedDeclaration(export)
+                    if matches!(
+                        &export.declaration,
+                        Some(Declaration::VariableDeclaration(decl))
+                            if decl.kind != VariableDeclarationKind::Const
+                    ) =>
+                {
+                    export.declaration.take()
+                }
+                _ => None,
+            };
+            if let Some(Declaration::VariableDeclaration(decl)) = declaration {
+                *stmt = Statement::VariableDeclaration(decl);
+            }
+            // `$: doubled = count * 2`
+            if let Statement::LabeledStatement(labeled) = stmt {
+                if labeled.label.name == "$" {
+                    self.collect_reactive_statement(labeled);
+                    *stmt = self.ast.statement_empty();
+                    return;
+                }
+            }
+        }
+        // `$inspect` is a no-op in production
+        if let Statement::ExpressionStatement(it) = stmt {
+            if !self.options.dev
+                && matches!(
+                    get_expression_rune(&it.expression),
+                    Some(Rune::Inspect | Rune::InspectWith)
+                )
+            {
+                *stmt = self.ast.statement_empty();
+                return;
+            }
+        }
         walk_statement(self, stmt);
-        if matches!(stmt, Statement::FunctionDeclaration(_)) && self.state.should_hoist_function {
-            self.state.should_hoist_function = false;
-            let stmt = self.ast.move_statement(stmt);
-            self.hoisted.push(stmt);
+        // e.g. `let { a } = $props()` when `a` is read from `$$props` directly
+        if let Statement::VariableDeclaration(decl) = stmt {
+            if decl.declarations.is_empty() {
+                *stmt = self.ast.statement_empty();
+            }
         }
     }
 
     fn visit_expression(&mut self, expr: &mut Expression<'a>) {
         match expr {
             Expression::Identifier(ident) => {
-                let (_, binding) = if let Some(v) = self.find_binding(&ident.name) {
+                let (symbol_id, binding) = if let Some(v) = self.find_binding(&ident.name) {
                     v
                 } else {
                     return;
                 };
                 // TODO: Svelte seems has different getter inside the `transform` object
-                if binding.is_init_by_state() {
-                    let call_expr = self.ast.call_with_atom(
+                let transform = match binding.kind() {
+                    BindingKind::State
+                    | BindingKind::RawState
+                    | BindingKind::Derived
+                    | BindingKind::LegacyReactive => Some(ReadTransform::Get),
+                    BindingKind::StoreSub => Some(ReadTransform::Call),
+                    _ => self.read_transforms.get(&symbol_id).cloned(),
+                };
+                *expr = match transform {
+                    Some(ReadTransform::Get) => self.ast.expression_call_with_atom(
                         "$.get",
                         self.ast
                             .vec([self.ast.expression_identifier_reference(&ident.name).into()]),
-                    );
-                    *expr = Expression::CallExpression(self.ast.alloc(call_expr))
-                }
+                    ),
+                    Some(ReadTransform::Call) => self
+                        .ast
+                        .expression_call_with_atom(ident.name.as_str(), self.ast.vec([])),
+                    Some(ReadTransform::Prop(key)) => self.ast.expression_member(
+                        self.ast.expression_identifier_reference("$$props"),
+                        &key,
+                    ),
+                    Some(ReadTransform::ConstMember(object)) => self.ast.expression_static_member(
+                        self.ast.expression_call_with_atom(
+                            "$.get",
+                            self.ast
+                                .vec([self.ast.expression_identifier_reference(&object).into()]),
+                        ),
+                        &ident.name,
+                    ),
+                    None => return,
+                };
+            }
+            // `object.key = value` -> `$.mutate(object, $.get(object).key = value)`
+            Expression::AssignmentExpression(assignment_expr)
+                if assignment_expr.left.as_member_expression().is_some() =>
+            {
+                let mutated = assignment_expr
+                    .left
+                    .as_member_expression()
+                    .and_then(|member| member.extract_identifier())
+                    .map(|ident| ident.name);
+                walk_assignment_expression(self, assignment_expr.as_mut());
+                self.build_mutation(mutated, expr);
+            }
+            // `object.key++` -> `$.mutate(object, $.get(object).key++)`
+            Expression::UpdateExpression(update)
+                if update.argument.as_member_expression().is_some() =>
+            {
+                let mutated = update
+                    .argument
+                    .as_member_expression()
+                    .and_then(|member| member.extract_identifier())
+                    .map(|ident| ident.name);
+                walk_update_expression(self, update.as_mut());
+                self.build_mutation(mutated, expr);
             }
             Expression::AssignmentExpression(assignment_expr) => {
                 walk_assignment_expression(self, assignment_expr.as_mut());
@@ -74,27 +235,421 @@ impl<'a> JsVisitMut<'a> for Transformer<'a> {
                 } else {
                     return;
                 };
-                if binding.is_init_by_state() {
-                    let name = self.symbols.get_name(symbol_id);
-                    let right = self.ast.move_expression(&mut assignment_expr.right);
-                    let left = self.ast.expression_call_with_atom(
-                        "$.get",
-                        self.ast
-                            .vec([self.ast.expression_identifier_reference(name).into()]),
-                    );
-                    let call_expr = self.ast.call_with_atom(
-                        "$.set",
-                        self.ast.vec([
-                            self.ast.expression_identifier_reference(name).into(),
+                let kind = binding.kind();
+                let name = self.symbols.get_name(symbol_id);
+                match kind {
+                    BindingKind::State | BindingKind::RawState | BindingKind::LegacyReactive => {
+                        let right = self.ast.move_expression(&mut assignment_expr.right);
+                        let left = self.ast.expression_call_with_atom(
+                            "$.get",
                             self.ast
+                                .vec([self.ast.expression_identifier_reference(name).into()]),
+                        );
+                        *expr = self.ast.expression_call_with_atom(
+                            "$.set",
+                            self.ast.vec([
+                                self.ast.expression_identifier_reference(name).into(),
+                                self.ast
+                                    .build_assignment_value(assignment_expr.operator, left, right)
+                                    .into(),
+                            ]),
+                        );
+                    }
+                    // `$.store_set(store, value)`
+                    BindingKind::StoreSub => {
+                        let right = self.ast.move_expression(&mut assignment_expr.right);
+                        let left = self.ast.expression_call_with_atom(name, self.ast.vec([]));
+                        *expr = self.ast.expression_call_with_atom(
+                            "$.store_set",
+                            self.ast.vec([
+                                self.ast.expression_identifier_reference(&name[1..]).into(),
+                                self.ast
+                                    .build_assignment_value(assignment_expr.operator, left, right)
+                                    .into(),
+                            ]),
+                        );
+                    }
+                    // `name(value)` for a prop source
+                    BindingKind::Prop | BindingKind::BindableProp
+                        if matches!(
+                            self.read_transforms.get(&symbol_id),
+                            Some(ReadTransform::Call)
+                        ) =>
+                    {
+                        let right = self.ast.move_expression(&mut assignment_expr.right);
+                        let left = self.ast.expression_call_with_atom(name, self.ast.vec([]));
+                        *expr = self.ast.expression_call_with_atom(
+                            name,
+                            self.ast.vec([self
+                                .ast
                                 .build_assignment_value(assignment_expr.operator, left, right)
-                                .into(),
-                        ]),
-                    );
-                    *expr = Expression::CallExpression(self.ast.alloc(call_expr));
+                                .into()]),
+                        );
+                    }
+                    _ => {}
+                }
+            }
+            // `count++` -> `$.update(count)`, `--count` -> `$.update_pre(count, -1)`
+            Expression::UpdateExpression(update) => {
+                let Some((symbol_id, binding)) = update
+                    .argument
+                    .get_identifier()
+                    .and_then(|name| self.find_binding(name))
+                else {
+                    return walk_expression(self, expr);
+                };
+                let kind = binding.kind();
+                // `$count++` -> `$.update_store(count, $count())`, the old value is returned
+                if kind == BindingKind::StoreSub {
+                    let name = self.symbols.get_name(symbol_id);
+                    let callee = if update.prefix {
+                        "$.update_pre_store"
+                    } else {
+                        "$.update_store"
+                    };
+                    let mut args = self.ast.vec([
+                        self.ast.expression_identifier_reference(&name[1..]).into(),
+                        self.ast
+                            .expression_call_with_atom(name, self.ast.vec([]))
+                            .into(),
+                    ]);
+                    if update.operator == UpdateOperator::Decrement {
+                        args.push(self.ast.expression_numeric_literal(-1.0).into());
+                    }
+                    *expr = self.ast.expression_call_with_atom(callee, args);
+                    return;
                 }
+                let is_prop_source = matches!(
+                    self.read_transforms.get(&symbol_id),
+                    Some(ReadTransform::Call)
+                );
+                let callee = match (kind, update.prefix) {
+                    (
+                        BindingKind::State | BindingKind::RawState | BindingKind::LegacyReactive,
+                        false,
+                    ) => "$.update",
+                    (
+                        BindingKind::State | BindingKind::RawState | BindingKind::LegacyReactive,
+                        true,
+                    ) => "$.update_pre",
+                    (BindingKind::Prop | BindingKind::BindableProp, false) if is_prop_source => {
+                        "$.update_prop"
+                    }
+                    (BindingKind::Prop | BindingKind::BindableProp, true) if is_prop_source => {
+                        "$.update_pre_prop"
+                    }
+                    _ => return,
+                };
+                let mut args = self.ast.vec([self
+                    .ast
+                    .expression_identifier_reference(self.symbols.get_name(symbol_id))
+                    .into()]);
+                if update.operator == UpdateOperator::Decrement {
+                    args.push(self.ast.expression_numeric_literal(-1.0).into());
+                }
+                *expr = self.ast.expression_call_with_atom(callee, args);
             }
+            Expression::CallExpression(call) => match get_rune(call) {
+                Some(
+                    rune @ (Rune::Effect
+                    | Rune::EffectPre
+                    | Rune::EffectRoot
+                    | Rune::EffectTracking),
+                ) => {
+                    let callee = match rune {
+                        Rune::Effect => "$.user_effect",
+                        Rune::EffectPre => "$.user_pre_effect",
+                        Rune::EffectRoot => "$.effect_root",
+                        _ => "$.effect_tracking",
+                    };
+                    // the effects are scheduled once the component is mounted
+                    self.needs_context |= matches!(rune, Rune::Effect | Rune::EffectPre);
+                    call.callee = self.ast.expression_identifier_reference(callee);
+                    self.visit_arguments(&mut call.arguments);
+                }
+                Some(Rune::Inspect | Rune::InspectWith) => *expr = self.build_inspect(call),
+                _ => walk_expression(self, expr),
+            },
             _ => walk_expression(self, expr),
         }
     }
 }
+
+impl<'a> Transformer<'a> {
+    /// ```js
+    /// // $inspect(a, b)
+    /// $.inspect(() => [a, b]);
+    /// // $inspect(a).with(callback)
+    /// $.inspect(() => [a], callback);
+    /// ```
+    fn build_inspect(&mut self, call: &mut CallExpression<'a>) -> Expression<'a> {
+        if !self.options.dev {
+            return self.ast.expression_void_zero();
+        }
+        let mut callback = None;
+        let args = match &mut call.callee {
+            Expression::StaticMemberExpression(member) => {
+                callback = call
+                    .arguments
+                    .first_mut()
+                    .and_then(Argument::as_expression_mut)
+                    .map(|callback| self.ast.move_expression(callback));
+                let Expression::CallExpression(inspect) = &mut member.object else {
+                    unreachable!("`$inspect().with` is called on the result of `$inspect`")
+                };
+                &mut inspect.arguments
+            }
+            _ => &mut call.arguments,
+        };
+        self.visit_arguments(args);
+        if let Some(callback) = callback.as_mut() {
+            self.visit_expression(callback);
+        }
+
+        let values = self
+            .ast
+            .expression_array(self.ast.vec_from_iter(args.drain(..).map(|arg| match arg {
+                Argument::SpreadElement(spread) => ArrayExpressionElement::SpreadElement(spread),
+                arg => arg.into_expression().into(),
+            })));
+        let mut inspect_args = self.ast.vec([self
+            .ast
+            .expression_arrow(
+                self.ast.vec([]),
+                self.ast.vec([self.ast.statement_expression(values)]),
+            )
+            .into()]);
+        if let Some(callback) = callback {
+            inspect_args.push(callback.into());
+        }
+        self.ast
+            .expression_call_with_atom("$.inspect", inspect_args)
+    }
+
+    /// Invalidates the binding whose member is mutated by `mutation`, once it is transformed:
+    /// ```js
+    /// $.mutate(object, $.get(object).key = value); // a mutable source
+    /// object(object().key = value, true); // a bindable prop
+    /// ```
+    fn build_mutation(&mut self, mutated: Option<Atom<'a>>, mutation: &mut Expression<'a>) {
+        let Some((symbol_id, kind)) = mutated
+            .and_then(|name| self.find_binding(&name))
+            .map(|(symbol_id, binding)| (symbol_id, binding.kind()))
+        else {
+            return;
+        };
+        let is_prop_source = matches!(
+            self.read_transforms.get(&symbol_id),
+            Some(ReadTransform::Call)
+        );
+        let name = self.symbols.get_name(symbol_id);
+        if self.is_mutable_source(kind) {
+            let value = self.ast.move_expression(mutation);
+            *mutation = self.ast.expression_call_with_atom(
+                "$.mutate",
+                self.ast.vec([
+                    self.ast.expression_identifier_reference(name).into(),
+                    value.into(),
+                ]),
+            );
+        } else if kind == BindingKind::BindableProp && is_prop_source {
+            let value = self.ast.move_expression(mutation);
+            *mutation = self.ast.expression_call_with_atom(
+                name,
+                self.ast.vec([
+                    value.into(),
+                    self.ast.expression_boolean_literal(true).into(),
+                ]),
+            );
+        }
+    }
+
+    /// Whether the signal of a binding is a mutable source, whose object is invalidated when
+    /// one of its members is assigned
+    fn is_mutable_source(&self, kind: BindingKind) -> bool {
+        kind == BindingKind::LegacyReactive || (!self.runes && kind == BindingKind::State)
+    }
+
+    /// Turns a `$props()` declarator into accessors of `$$props`:
+    /// ```js
+    /// // let { a, b = 1, c = $bindable(), ...rest } = $props();
+    /// let b = $.prop($$props, "b", 3, 1),
+    ///     c = $.prop($$props, "c", 11),
+    ///     rest = $.rest_props($$props, ["$$slots", "$$events", "$$legacy", "a", "b", "c"]);
+    /// ```
+    /// `a` is never updated and has no fallback, so it's read from `$$props.a` directly.
+    fn build_props_declarators(
+        &mut self,
+        declarator: VariableDeclarator<'a>,
+        declarations: &mut OxcVec<'a, VariableDeclarator<'a>>,
+    ) {
+        self.uses_props = true;
+        let kind = declarator.kind;
+        let mut seen = vec!["$$slots".to_string(), "$$events".into(), "$$legacy".into()];
+        if self.options.custom_element {
+            seen.push("$$host".into());
+        }
+
+        let mut id = declarator.id;
+        let rest = if let BindingPatternKind::ObjectPattern(pattern) = &mut id.kind {
+            for property in pattern.properties.iter_mut() {
+                // computed keys are reported by the analyzer
+                let Some(key) = property.key.static_name() else {
+                    continue;
+                };
+                let key = key.to_string();
+                let (name, symbol_id, initial) = match &mut property.value.kind {
+                    BindingPatternKind::BindingIdentifier(ident) => {
+                        (ident.name, ident.symbol_id(), None)
+                    }
+                    BindingPatternKind::AssignmentPattern(assignment) => {
+                        let Some(ident) = assignment.left.get_binding_identifier() else {
+                            continue;
+                        };
+                        let (name, symbol_id) = (ident.name, ident.symbol_id());
+                        let initial = self.ast.move_expression(&mut assignment.right);
+                        (name, symbol_id, Some(initial))
+                    }
+                    // nested patterns are reported by the analyzer
+                    _ => continue,
+                };
+                seen.push(key.clone());
+
+                let binding = self.symbols.get_binding(symbol_id);
+                let is_bindable = binding.kind() == BindingKind::BindableProp;
+                let is_updated = binding.binding_flags.is_updated();
+                // `$bindable(fallback)`
+                let mut initial = match initial {
+                    Some(Expression::CallExpression(mut call))
+                        if get_rune(&call) == Some(Rune::Bindable) =>
+                    {
+                        call.arguments
+                            .first_mut()
+                            .and_then(Argument::as_expression_mut)
+                            .map(|value| self.ast.move_expression(value))
+                    }
+                    initial => initial,
+                };
+                if let Some(initial) = initial.as_mut() {
+                    self.visit_expression(initial);
+                }
+
+                if !is_bindable && !is_updated && initial.is_none() {
+                    self.read_transforms
+                        .insert(symbol_id, ReadTransform::Prop(key.into()));
+                    continue;
+                }
+                self.read_transforms.insert(symbol_id, ReadTransform::Call);
+                declarations.push(self.ast.variable_declarator(
+                    kind,
+                    self.ast.binding_pattern_identifier(name),
+                    self.build_prop_source(&key, symbol_id, initial),
+                ));
+            }
+            pattern.rest.take().map(|rest| rest.unbox().argument)
+        } else {
+            // `let props = $props()`
+            Some(id)
+        };
+
+        if let Some(rest) = rest {
+            let mut args = self.ast.vec([
+                self.ast.expression_identifier_reference("$$props").into(),
+                self.ast
+                    .expression_array(
+                        self.ast
+                            .vec_from_iter(seen.iter().map(|key| {
+                                self.ast.expression_string_literal(key.as_str()).into()
+                            })),
+                    )
+                    .into(),
+            ]);
+            if self.options.dev {
+                if let Some(ident) = rest.get_binding_identifier() {
+                    args.push(self.ast.expression_string_literal(ident.name).into());
+                }
+            }
+            declarations.push(self.ast.variable_declarator(
+                kind,
+                rest,
+                self.ast.expression_call_with_atom("$.rest_props", args),
+            ));
+        }
+    }
+
+    /// `$.prop($$props, "key", flags, fallback)`, the accessor of a prop which is bindable, updated
+    /// or has a fallback
+    fn build_prop_source(
+        &self,
+        key: &str,
+        symbol_id: SymbolId,
+        initial: Option<Expression<'a>>,
+    ) -> Expression<'a> {
+        let binding = self.symbols.get_binding(symbol_id);
+        let mut flags = 0;
+        if self.runes {
+            flags |= PROPS_IS_IMMUTABLE | PROPS_IS_RUNES;
+        }
+        if binding.kind() == BindingKind::BindableProp {
+            flags |= PROPS_IS_BINDABLE;
+        }
+        // outside of runes mode a mutated prop has to be updated as well
+        let is_updated = if self.runes {
+            binding.binding_flags.is_reassigned()
+        } else {
+            binding.binding_flags.is_updated()
+        };
+        if is_updated {
+            flags |= PROPS_IS_UPDATED;
+        }
+        let fallback = initial.map(|initial| {
+            if is_simple_expression(&initial) {
+                return initial;
+            }
+            flags |= PROPS_IS_LAZY_INITIAL;
+            match initial {
+                Expression::CallExpression(mut call)
+                    if call.arguments.is_empty()
+                        && matches!(call.callee, Expression::Identifier(_)) =>
+                {
+                    self.ast.move_expression(&mut call.callee)
+                }
+                initial => self.ast.expression_arrow(
+                    self.ast.vec([]),
+                    self.ast.vec([self.ast.statement_expression(initial)]),
+                ),
+            }
+        });
+
+        let mut args = self.ast.vec([
+            self.ast.expression_identifier_reference("$$props").into(),
+            self.ast.expression_string_literal(key).into(),
+            self.ast.expression_numeric_literal(flags as f64).into(),
+        ]);
+        if let Some(fallback) = fallback {
+            args.push(fallback.into());
+        }
+        self.ast.expression_call_with_atom("$.prop", args)
+    }
+}
+
+/// Whether the expression is cheap enough to be evaluated eagerly, e.g. as the fallback of a prop
+pub(crate) fn is_simple_expression(expr: &Expression) -> bool {
+    match expr {
+        Expression::Identifier(_)
+        | Expression::ArrowFunctionExpression(_)
+        | Expression::FunctionExpression(_) => true,
+        Expression::ConditionalExpression(expr) => {
+            is_simple_expression(&expr.test)
+                && is_simple_expression(&expr.consequent)
+                && is_simple_expression(&expr.alternate)
+        }
+        Expression::BinaryExpression(expr) => {
+            is_simple_expression(&expr.left) && is_simple_expression(&expr.right)
+        }
+        Expression::LogicalExpression(expr) => {
+            is_simple_expression(&expr.left) && is_simple_expression(&expr.right)
+        }
+        expr => expr.is_literal(),
+    }
+}
@@ -0,0 +1,232 @@
+//! Svelte 4 syntax, compiled when the component isn't in runes mode
+
+use std::collections::HashSet;
+
+use oxc_allocator::Vec as OxcVec;
+use rusvelte_analyzer::{
+    binding::{BindingKind, DeclarationKind},
+    ReferenceId, SymbolId,
+};
+use rusvelte_ast::{
+    js_ast::*,
+    traits::{
+        extract_identifier::ExtractIdentifier,
+        unwrap_pattern::{UnwrapPattern, UnwrapPatternItem},
+    },
+    visit::{JsVisit, JsVisitMut},
+};
+
+use crate::Transformer;
+
+/// A `$:` statement of the instance script
+pub(crate) struct ReactiveStatement<'a> {
+    /// The top-level bindings assigned by the statement
+    assignments: HashSet<SymbolId>,
+    /// The top-level bindings read by the statement, the statement reruns when they change
+    dependencies: Vec<SymbolId>,
+    /// The transformed body of the statement
+    pub(crate) body: Statement<'a>,
+}
+
+/// Collects the references of a `$:` statement before they are transformed
+#[derive(Default)]
+struct ReactiveStatementCollector {
+    assignments: Vec<ReferenceId>,
+    dependencies: Vec<ReferenceId>,
+}
+
+impl<'a> JsVisit<'a> for ReactiveStatementCollector {
+    fn visit_identifier_reference(&mut self, it: &IdentifierReference<'a>) {
+        self.dependencies.push(it.reference_id());
+    }
+
+    fn visit_assignment_expression(&mut self, it: &AssignmentExpression<'a>) {
+        for item in it.left.unwrap_pattern() {
+            match item {
+                UnwrapPatternItem::IdentifierReference(ident) => {
+                    self.assignments.push(ident.reference_id());
+                }
+                // `object.key = value` reads `object`
+                item => {
+                    if let Some(ident) = item.extract_identifier() {
+                        self.dependencies.push(ident.reference_id());
+                    }
+                }
+            }
+        }
+        self.visit_expression(&it.right);
+    }
+}
+
+impl<'a> Transformer<'a> {
+    /// Collects the top-level bindings assigned and read by the body of a `$:` statement, which
+    /// is not transformed yet
+    pub(crate) fn new_reactive_statement(&self, body: &Statement<'a>) -> ReactiveStatement<'a> {
+        let mut collector = ReactiveStatementCollector::default();
+        collector.visit_statement(body);
+
+        let root_scope_id = self.scopes.root_scope_id();
+        let assignments: HashSet<SymbolId> = collector
+            .assignments
+            .iter()
+            .filter_map(|&reference_id| self.references.get_reference(reference_id).symbol_id())
+            .collect();
+        let mut dependencies = vec![];
+        for reference_id in collector.dependencies {
+            let Some(symbol_id) = self.references.get_reference(reference_id).symbol_id() else {
+                continue;
+            };
+            if !assignments.contains(&symbol_id)
+                && !dependencies.contains(&symbol_id)
+                && self.symbols.get_binding(symbol_id).scope_id() == root_scope_id
+            {
+                dependencies.push(symbol_id);
+            }
+        }
+        ReactiveStatement {
+            assignments,
+            dependencies,
+            body: self.ast.statement_empty(),
+        }
+    }
+
+    /// Visits the body of a `$:` statement, which runs once all the statements are sorted
+    pub(crate) fn collect_reactive_statement(&mut self, labeled: &mut LabeledStatement<'a>) {
+        let mut statement = self.new_reactive_statement(&labeled.body);
+        self.visit_statement(&mut labeled.body);
+        statement.body = self.ast.move_statement(&mut labeled.body);
+        self.reactive_statements.push(statement);
+        // the statements are scheduled along with the component context
+        self.needs_context = true;
+    }
+
+    /// ```js
+    /// $.legacy_pre_effect(() => $.get(count), () => {
+    ///     $.set(doubled, $.get(count) * 2);
+    /// });
+    /// $.legacy_pre_effect_reset();
+    /// ```
+    /// A statement runs after the ones assigning its dependencies.
+    pub(crate) fn build_reactive_statements(&mut self) -> OxcVec<'a, Statement<'a>> {
+        let statements = sort_reactive_statements(std::mem::take(&mut self.reactive_statements));
+        let mut result = self.ast.vec([]);
+        for statement in statements {
+            let mut dependencies = self.ast.vec([]);
+            for symbol_id in statement.dependencies {
+                let binding = self.symbols.get_binding(symbol_id);
+                if binding.kind() == BindingKind::Normal
+                    && binding.declaration_kind() != DeclarationKind::Import
+                {
+                    continue;
+                }
+                let is_bindable = binding.kind() == BindingKind::BindableProp;
+                let mut dependency = self.ast.expression_identifier_reference(binding.name());
+                self.visit_expression(&mut dependency);
+                // a prop may hold the fine-grained state of a runes component
+                if is_bindable {
+                    dependency = self.ast.expression_call_with_atom(
+                        "$.deep_read_state",
+                        self.ast.vec([dependency.into()]),
+                    );
+                }
+                dependencies.push(dependency);
+            }
+            let dependencies = match dependencies.len() {
+                0 => self.ast.vec([]),
+                1 => self
+                    .ast
+                    .vec([self.ast.statement_expression(dependencies.remove(0))]),
+                _ => self.ast.vec([self
+                    .ast
+                    .statement_expression(self.ast.expression_sequence(dependencies))]),
+            };
+            let body = match statement.body {
+                Statement::BlockStatement(block) => block.unbox().body,
+                body => self.ast.vec([body]),
+            };
+            result.push(
+                self.ast.statement_expression(
+                    self.ast.expression_call_with_atom(
+                        "$.legacy_pre_effect",
+                        self.ast.vec([
+                            self.ast
+                                .expression_arrow(self.ast.vec([]), dependencies)
+                                .into(),
+                            self.ast
+                                .expression_arrow_block(self.ast.vec([]), body)
+                                .into(),
+                        ]),
+                    ),
+                ),
+            );
+        }
+        result.push(
+            self.ast.statement_expression(
+                self.ast
+                    .expression_call_with_atom("$.legacy_pre_effect_reset", self.ast.vec([])),
+            ),
+        );
+        result
+    }
+
+    /// ```js
+    /// let doubled = $.mutable_source();
+    /// ```
+    /// for the bindings implicitly declared by `$: doubled = count * 2`
+    pub(crate) fn append_legacy_reactive_declarations(&self, body: &mut OxcVec<'a, Statement<'a>>) {
+        for (_, binding) in self.symbols.iter() {
+            if binding.kind() != BindingKind::LegacyReactive {
+                continue;
+            }
+            body.push(
+                self.ast.statement_let(
+                    self.ast.binding_pattern_identifier(binding.name()),
+                    self.ast
+                        .expression_call_with_atom("$.mutable_source", self.ast.vec([])),
+                ),
+            );
+        }
+    }
+}
+
+/// Orders the `$:` statements so that a statement runs after the ones assigning its dependencies
+pub(crate) fn sort_reactive_statements(
+    statements: Vec<ReactiveStatement<'_>>,
+) -> Vec<ReactiveStatement<'_>> {
+    let mut visited = vec![false; statements.len()];
+    let mut order = Vec::with_capacity(statements.len());
+    for index in 0..statements.len() {
+        sort_reactive_statement(index, &statements, &mut visited, &mut order);
+    }
+    let mut statements: Vec<Option<ReactiveStatement>> = statements.into_iter().map(Some).collect();
+    order
+        .into_iter()
+        .filter_map(|index| statements[index].take())
+        .collect()
+}
+
+/// Depth-first topological sort, the statements assigning the dependencies of a statement are
+/// pushed before it. Cycles are broken by the source order.
+fn sort_reactive_statement(
+    index: usize,
+    statements: &[ReactiveStatement],
+    visited: &mut [bool],
+    order: &mut Vec<usize>,
+) {
+    if visited[index] {
+        return;
+    }
+    visited[index] = true;
+    let statement = &statements[index];
+    for (other_index, other) in statements.iter().enumerate() {
+        if other_index != index
+            && statement
+                .dependencies
+                .iter()
+                .any(|symbol_id| other.assignments.contains(symbol_id))
+        {
+            sort_reactive_statement(other_index, statements, visited, order);
+        }
+    }
+    order.push(index);
+}
@@ -1,113 +1,289 @@
-use std::cell::Cell;
+use std::{cell::Cell, collections::HashMap};
 
 use oxc_allocator::{Allocator, Vec as OxcVec};
-use oxc_span::{SourceType, SPAN};
+use oxc_span::{CompactStr, SourceType, SPAN};
 use rusvelte_analyzer::{
-    binding::{Binding, BindingTable},
+    binding::{Binding, BindingKind, BindingTable},
     reference::ReferenceTable,
     scope::ScopeTable,
-    ScopeId, SymbolId,
+    Analysis, CompileOptions, CssMode, Namespace, ScopeId, SymbolId,
 };
 use rusvelte_ast::{
-    ast::Root,
+    ast::{Root, StyleSheet},
     ast_builder::AstBuilder,
     js_ast::{Program, Statement},
     visit_mut::{JsVisitMut, VisitMut},
 };
 
+mod css;
 mod js;
+mod legacy;
+mod server;
 mod svelte;
 
+pub use css::render_stylesheet;
+use legacy::ReactiveStatement;
+use server::ServerTransformer;
+
 struct TransformState<'a> {
     allocator: &'a Allocator,
+    /// Statements that run once when the fragment is created
+    init: OxcVec<'a, Statement<'a>>,
+    /// Statements that run whenever the state they depend on changes
     update: OxcVec<'a, Statement<'a>>,
-    should_hoist_function: bool,
+    /// Statements that run once the template effect is set up, e.g. to attach event listeners
+    after_update: OxcVec<'a, Statement<'a>>,
+    /// The static HTML of the fragment
+    template: Vec<String>,
+    /// The DOM node the currently visited node is anchored to
+    node: CompactStr,
 }
 
 impl<'a> TransformState<'a> {
     fn new(allocator: &'a Allocator) -> Self {
         Self {
             allocator,
+            init: OxcVec::new_in(allocator),
             update: OxcVec::new_in(allocator),
-            // always true for POC simplicity
-            should_hoist_function: true,
+            after_update: OxcVec::new_in(allocator),
+            template: vec![],
+            node: "$$anchor".into(),
         }
     }
 
+    fn take_init(&mut self) -> OxcVec<'a, Statement<'a>> {
+        std::mem::replace(&mut self.init, OxcVec::new_in(self.allocator))
+    }
+
     fn take_update(&mut self) -> OxcVec<'a, Statement<'a>> {
         std::mem::replace(&mut self.update, OxcVec::new_in(self.allocator))
     }
+
+    fn take_after_update(&mut self) -> OxcVec<'a, Statement<'a>> {
+        std::mem::replace(&mut self.after_update, OxcVec::new_in(self.allocator))
+    }
+}
+
+/// How to read a binding whose value isn't stored in a plain variable.
+#[derive(Debug, Clone)]
+enum ReadTransform {
+    /// `$.get(name)`
+    Get,
+    /// `name()`
+    Call,
+    /// `$$props.key`
+    Prop(CompactStr),
+    /// `$.get(object).name`, for a binding destructured by a `{@const}` tag
+    ConstMember(CompactStr),
 }
 
 pub struct Transformer<'a> {
     ast: AstBuilder<'a>,
     allocator: &'a Allocator,
     hoisted: OxcVec<'a, Statement<'a>>,
+    /// Top-level snippets, declared in the component body so the `<script>` can reference them
+    instance_level_snippets: OxcVec<'a, Statement<'a>>,
     scopes: ScopeTable,
     symbols: BindingTable,
     references: ReferenceTable,
     current_scope_id: ScopeId,
+    read_transforms: HashMap<SymbolId, ReadTransform>,
+    /// Whether the component reads `$$props`, which is then passed as its second parameter
+    uses_props: bool,
+    /// Whether the component has to push its own context, e.g. to schedule its effects
+    needs_context: bool,
+    /// Whether the component is compiled in runes mode
+    runes: bool,
+    /// The `$:` statements of the instance script, emitted after its other statements
+    reactive_statements: Vec<ReactiveStatement<'a>>,
+    /// The events handled through delegation, e.g. `click` for `onclick={handler}`
+    delegated_events: Vec<&'a str>,
+    /// The arrays shared by the inputs of each `bind:group`, keyed by the bound keypath
+    binding_groups: Vec<(Option<String>, CompactStr)>,
+    /// The index of each enclosing each block, named on demand when the block doesn't declare
+    /// one, and whether it is read through `$.get`
+    each_block_indexes: Vec<(Option<CompactStr>, bool)>,
+    /// The namespace of the nodes being transformed, e.g. `svg` inside an `<svg>` element
+    namespace: Namespace,
     state: TransformState<'a>,
+    options: CompileOptions,
+    name: String,
+    css_hash: String,
 }
 
 impl<'a> Transformer<'a> {
-    pub fn new(
-        allocator: &'a Allocator,
-        scopes: ScopeTable,
-        symbols: BindingTable,
-        reference_table: ReferenceTable,
-    ) -> Self {
+    pub fn new(allocator: &'a Allocator, analysis: Analysis<'a>) -> Self {
+        let Analysis {
+            scopes,
+            symbols,
+            references,
+            options,
+            name,
+            css_hash,
+            runes,
+            ..
+        } = analysis;
         let ast = AstBuilder::new(allocator);
-        let hoisted = ast
-            .vec([ast.statement_import_declaration(ast.import_all("$", "svelte/internal/client"))]);
+        let hoisted = ast.vec([]);
+        let instance_level_snippets = ast.vec([]);
         let current_scope_id = scopes.root_scope_id();
 
         Self {
             allocator,
             hoisted,
+            instance_level_snippets,
             ast,
             scopes,
             symbols,
-            references: reference_table,
+            references,
             current_scope_id,
+            read_transforms: HashMap::new(),
+            uses_props: false,
+            needs_context: false,
+            runes,
+            reactive_statements: vec![],
+            delegated_events: vec![],
+            binding_groups: vec![],
+            each_block_indexes: vec![],
+            namespace: options.namespace,
             state: TransformState::new(allocator),
+            options,
+            name,
+            css_hash,
         }
     }
 
     pub fn client_transform(mut self, root: &mut Root<'a>) -> Program<'a> {
+        let mut imports = self.ast.vec([]);
         let mut instance_body = self.ast.vec([]);
         if let Some(script) = root.instance.as_mut() {
             self.visit_program(&mut script.content);
-            instance_body = std::mem::replace(&mut script.content.body, instance_body);
+            let body = std::mem::replace(&mut script.content.body, self.ast.vec([]));
+            for stmt in body {
+                if matches!(stmt, Statement::ImportDeclaration(_)) {
+                    imports.push(stmt);
+                } else {
+                    instance_body.push(stmt);
+                }
+            }
         }
 
         let template_body = self.visit_fragment(&mut root.fragment);
 
-        let component_block = OxcVec::from_iter_in(
-            instance_body.into_iter().chain(template_body),
-            self.allocator,
-        );
-        let component = self.ast.function_declaration(
-            "App",
-            self.ast.vec([self
-                .ast
-                .formal_parameter(self.ast.binding_pattern_identifier("$$anchor"))]),
-            component_block,
-        );
+        let mut component_block = self.ast.vec([]);
+        if self.options.css == CssMode::Injected {
+            if let Some(css) = &root.css {
+                self.hoisted.push(self.build_css(css));
+                component_block.push(self.ast.statement_expression(
+                    self.ast.expression_call_with_atom(
+                        "$.append_styles",
+                        self.ast.vec([
+                            self.ast.expression_identifier_reference("$$anchor").into(),
+                            self.ast.expression_identifier_reference("$$css").into(),
+                        ]),
+                    ),
+                ));
+            }
+        }
+        if self.needs_context {
+            component_block.push(self.build_push());
+        }
+        let has_stores = self.append_store_setup(&mut component_block);
+        self.append_legacy_reactive_declarations(&mut component_block);
+        for (_, id) in &self.binding_groups {
+            component_block.push(self.ast.statement_const(
+                self.ast.binding_pattern_identifier(id.as_str()),
+                self.ast.expression_array(self.ast.vec([])),
+            ));
+        }
+        component_block.extend(instance_body);
+        if !self.reactive_statements.is_empty() {
+            let mut reactive_statements = self.build_reactive_statements();
+            component_block.append(&mut reactive_statements);
+        }
+        if self.needs_context && !self.runes {
+            component_block.push(
+                self.ast.statement_expression(
+                    self.ast
+                        .expression_call_with_atom("$.init", self.ast.vec([])),
+                ),
+            );
+        }
+        component_block.append(&mut self.instance_level_snippets);
+        component_block.extend(template_body);
+        if self.needs_context {
+            component_block.push(
+                self.ast.statement_expression(
+                    self.ast
+                        .expression_call_with_atom("$.pop", self.ast.vec([])),
+                ),
+            );
+        }
+        // the subscriptions are cleaned up last so that they outlive the user effects
+        if has_stores {
+            component_block.push(
+                self.ast.statement_expression(
+                    self.ast
+                        .expression_call_with_atom("$$cleanup", self.ast.vec([])),
+                ),
+            );
+        }
+        let mut params = self.ast.vec([self
+            .ast
+            .formal_parameter(self.ast.binding_pattern_identifier("$$anchor"))]);
+        if self.uses_props || self.needs_context {
+            params.push(
+                self.ast
+                    .formal_parameter(self.ast.binding_pattern_identifier("$$props")),
+            );
+        }
+        let component = self
+            .ast
+            .function_declaration(self.name.as_str(), params, component_block);
 
         let mut body = self.ast.vec([]);
-        // TODO: check option.discloseVersion
+        if self.options.disclose_version {
+            body.push(self.ast.statement_import_declaration_without_specifier(
+                "svelte/internal/disclose-version",
+            ));
+        }
         body.push(
             self.ast
-                .statement_import_declaration_without_specifier("svelte/internal/disclose-version"),
+                .statement_import_declaration(self.ast.import_all("$", "svelte/internal/client")),
         );
+        body.extend(imports);
         body.append(&mut self.hoisted);
-        // TODO: check option.hmr
-        body.push(
-            self.ast
-                .statement_export_default_function_declaration(component),
-        );
+        if let Some(filename) = self.build_filename() {
+            body.push(filename);
+        }
+        if self.options.hmr {
+            body.push(self.ast.statement_function_declaration(component));
+            body.push(self.build_hmr());
+            body.push(
+                self.ast
+                    .statement_export_default_identifier(self.name.as_str()),
+            );
+        } else {
+            body.push(
+                self.ast
+                    .statement_export_default_function_declaration(component),
+            );
+        }
+        if !self.delegated_events.is_empty() {
+            body.push(self.build_delegate());
+        }
+        if self.options.custom_element {
+            body.push(self.build_custom_element(root));
+        }
 
+        self.program(body)
+    }
+
+    pub fn server_transform(self, root: &mut Root<'a>) -> Program<'a> {
+        ServerTransformer::new(self).transform(root)
+    }
+
+    fn program(&self, body: OxcVec<'a, Statement<'a>>) -> Program<'a> {
         Program {
             span: SPAN,
             source_type: SourceType::mjs(),
@@ -120,6 +296,225 @@ impl<'a> Transformer<'a> {
         }
     }
 
+    /// ```js
+    /// const $$css = { hash: "svelte-xyz", code: "..." };
+    /// ```
+    fn build_css(&self, css: &StyleSheet<'a>) -> Statement<'a> {
+        let code = render_stylesheet(css, &self.css_hash);
+        self.ast.statement_const(
+            self.ast.binding_pattern_identifier("$$css"),
+            self.ast.expression_object(
+                self.ast.vec([
+                    self.ast.object_property(
+                        "hash",
+                        self.ast.expression_string_literal(self.css_hash.as_str()),
+                    ),
+                    self.ast
+                        .object_property("code", self.ast.expression_string_literal(code.as_str())),
+                ]),
+            ),
+        )
+    }
+
+    /// ```js
+    /// const $count = () => $.store_get(count, "$count", $$stores);
+    /// const [$$stores, $$cleanup] = $.setup_stores();
+    /// ```
+    /// Returns whether the component subscribes to any store.
+    fn append_store_setup(&self, body: &mut OxcVec<'a, Statement<'a>>) -> bool {
+        let mut has_stores = false;
+        for (_, binding) in self.symbols.iter() {
+            if binding.kind() != BindingKind::StoreSub {
+                continue;
+            }
+            has_stores = true;
+            let name = binding.name();
+            let store_get = self.ast.expression_call_with_atom(
+                "$.store_get",
+                self.ast.vec([
+                    self.ast.expression_identifier_reference(&name[1..]).into(),
+                    self.ast.expression_string_literal(name).into(),
+                    self.ast.expression_identifier_reference("$$stores").into(),
+                ]),
+            );
+            body.push(self.ast.statement_const(
+                self.ast.binding_pattern_identifier(name),
+                self.ast.expression_arrow(
+                    self.ast.vec([]),
+                    self.ast.vec([self.ast.statement_expression(store_get)]),
+                ),
+            ));
+        }
+        if has_stores {
+            body.push(
+                self.ast.statement_const(
+                    self.ast.binding_pattern_array(&["$$stores", "$$cleanup"]),
+                    self.ast
+                        .expression_call_with_atom("$.setup_stores", self.ast.vec([])),
+                ),
+            );
+        }
+        has_stores
+    }
+
+    /// ```js
+    /// $.push($$props, true, Component);
+    /// ```
+    fn build_push(&self) -> Statement<'a> {
+        let mut args = self.ast.vec([
+            self.ast.expression_identifier_reference("$$props").into(),
+            self.ast.expression_boolean_literal(self.runes).into(),
+        ]);
+        if self.options.dev {
+            args.push(self.ast.expression_identifier_reference(&self.name).into());
+        }
+        self.ast
+            .statement_expression(self.ast.expression_call_with_atom("$.push", args))
+    }
+
+    /// ```js
+    /// Component[$.FILENAME] = "filename";
+    /// ```
+    fn build_filename(&self) -> Option<Statement<'a>> {
+        if !self.options.dev {
+            return None;
+        }
+        let filename = self.options.relative_filename()?;
+        let left = self.ast.expression_computed_member(
+            self.ast.expression_identifier_reference(&self.name),
+            self.ast.expression_identifier_reference("$.FILENAME"),
+        );
+        Some(
+            self.ast
+                .statement_expression(self.ast.expression_assignment(
+                    left,
+                    self.ast.expression_string_literal(filename.as_str()),
+                )),
+        )
+    }
+
+    /// ```js
+    /// if (import.meta.hot) {
+    ///     Component = $.hmr(Component, () => Component[$.HMR].source);
+    ///     import.meta.hot.accept((module) => {
+    ///         module.default[$.HMR].source = Component[$.HMR].source;
+    ///         $.set(Component[$.HMR].source, module.default[$.HMR].original);
+    ///     });
+    /// }
+    /// ```
+    fn build_hmr(&self) -> Statement<'a> {
+        let existing = || {
+            self.ast.expression_computed_member(
+                self.ast.expression_identifier_reference(&self.name),
+                self.ast.expression_identifier_reference("$.HMR"),
+            )
+        };
+        let incoming = || {
+            self.ast.expression_computed_member(
+                self.ast.expression_identifier_reference("module.default"),
+                self.ast.expression_identifier_reference("$.HMR"),
+            )
+        };
+
+        let accept_fn_body = self.ast.vec([
+            self.ast
+                .statement_expression(self.ast.expression_assignment(
+                    self.ast.expression_static_member(incoming(), "source"),
+                    self.ast.expression_static_member(existing(), "source"),
+                )),
+            self.ast.statement_expression(
+                self.ast.expression_call_with_atom(
+                    "$.set",
+                    self.ast.vec([
+                        self.ast
+                            .expression_static_member(existing(), "source")
+                            .into(),
+                        self.ast
+                            .expression_static_member(incoming(), "original")
+                            .into(),
+                    ]),
+                ),
+            ),
+        ]);
+        let hmr = self.ast.vec([
+            self.ast.statement_expression(
+                self.ast.expression_assignment(
+                    self.ast.expression_identifier_reference(&self.name),
+                    self.ast.expression_call_with_atom(
+                        "$.hmr",
+                        self.ast.vec([
+                            self.ast.expression_identifier_reference(&self.name).into(),
+                            self.ast
+                                .expression_arrow(
+                                    self.ast.vec([]),
+                                    self.ast.vec([self.ast.statement_expression(
+                                        self.ast.expression_static_member(existing(), "source"),
+                                    )]),
+                                )
+                                .into(),
+                        ]),
+                    ),
+                ),
+            ),
+            self.ast.statement_expression(
+                self.ast.expression_call_with_atom(
+                    "import.meta.hot.accept",
+                    self.ast.vec([self
+                        .ast
+                        .expression_arrow(
+                            self.ast.vec([self
+                                .ast
+                                .formal_parameter(self.ast.binding_pattern_identifier("module"))]),
+                            accept_fn_body,
+                        )
+                        .into()]),
+                ),
+            ),
+        ]);
+
+        self.ast.statement_if(
+            self.ast.expression_identifier_reference("import.meta.hot"),
+            hmr,
+            None,
+        )
+    }
+
+    /// ```js
+    /// customElements.define("tag", $.create_custom_element(Component, {}, [], [], true));
+    /// ```
+    fn build_custom_element(&self, root: &Root<'a>) -> Statement<'a> {
+        let custom_element = root
+            .options
+            .as_ref()
+            .and_then(|options| options.custom_element.as_ref());
+        // TODO: collect the props, slots and exports of the component
+        let use_shadow_dom =
+            custom_element.and_then(|custom_element| custom_element.shadow) != Some("none");
+        let create = self.ast.expression_call_with_atom(
+            "$.create_custom_element",
+            self.ast.vec([
+                self.ast.expression_identifier_reference(&self.name).into(),
+                self.ast.expression_object(self.ast.vec([])).into(),
+                self.ast.expression_array(self.ast.vec([])).into(),
+                self.ast.expression_array(self.ast.vec([])).into(),
+                self.ast.expression_boolean_literal(use_shadow_dom).into(),
+            ]),
+        );
+
+        match custom_element.and_then(|custom_element| custom_element.tag) {
+            Some(tag) => self
+                .ast
+                .statement_expression(self.ast.expression_call_with_atom(
+                    "customElements.define",
+                    self.ast.vec([
+                        self.ast.expression_string_literal(tag).into(),
+                        create.into(),
+                    ]),
+                )),
+            None => self.ast.statement_expression(create),
+        }
+    }
+
     #[allow(unused)]
     fn find_binding_mut(&mut self, name: &str) -> Option<(SymbolId, &mut Binding)> {
         self.scopes
@@ -0,0 +1,336 @@
+use std::cell::Cell;
+
+use oxc_allocator::Vec as OxcVec;
+use oxc_span::Atom;
+use rusvelte_analyzer::{
+    binding::BindingKind,
+    rune::{get_expression_rune, get_rune, Rune},
+    ScopeFlags, ScopeId,
+};
+use rusvelte_ast::js_ast::*;
+use rusvelte_ast::js_walk::walk_mut::*;
+use rusvelte_ast::visit::JsVisitMut;
+use rusvelte_ast::visit_mut::VisitMut;
+
+use crate::js::is_simple_expression;
+
+use super::ServerTransformer;
+
+impl<'a> JsVisitMut<'a> for ServerTransformer<'a> {
+    fn enter_scope(&mut self, _flags: ScopeFlags, scope_id: &Cell<Option<ScopeId>>) {
+        self.base.current_scope_id = scope_id.get().unwrap();
+    }
+
+    fn leave_scope(&mut self) {
+        if let Some(scope_id) = self.base.scopes.get_parent_id(self.base.current_scope_id) {
+            self.base.current_scope_id = scope_id;
+        }
+    }
+
+    /// The component is rendered once on the server, so the runes are replaced by their values:
+    /// ```js
+    /// let count = $state(0); // let count = 0;
+    /// let doubled = $derived(count * 2); // let doubled = count * 2;
+    /// let total = $derived.by(sum); // let total = sum();
+    /// let { a, b = $bindable(1), ...rest } = $props();
+    /// // let { a, b = 1, $$slots, $$events, ...rest } = $$props;
+    /// export let name = "world"; // let name = $.fallback($$props["name"], "world");
+    /// ```
+    fn visit_variable_declarator(&mut self, decl: &mut VariableDeclarator<'a>) {
+        // `export let name = fallback`
+        if let Some(name) = self.get_legacy_prop_name(&decl.id) {
+            let mut fallback = decl.init.take();
+            if let Some(fallback) = fallback.as_mut() {
+                self.visit_expression(fallback);
+            }
+            decl.init = Some(self.build_legacy_prop(&name, fallback));
+            self.bindable_props
+                .push(self.ast.object_property_shorthand(&name));
+            return;
+        }
+        match decl.init.as_ref().and_then(get_expression_rune) {
+            Some(Rune::State | Rune::StateRaw | Rune::Derived) => {
+                decl.init = Some(self.take_rune_argument(decl.init.take()));
+            }
+            Some(Rune::DerivedBy) => {
+                let callee = self.take_rune_argument(decl.init.take());
+                decl.init = Some(self.ast.expression_call(callee, self.ast.vec([])));
+            }
+            Some(Rune::Props) => {
+                self.build_props_pattern(&mut decl.id);
+                decl.init = Some(self.ast.expression_identifier_reference("$$props"));
+            }
+            _ => {}
+        }
+        walk_variable_declarator(self, decl);
+    }
+
+    fn visit_statements(&mut self, stmts: &mut OxcVec<'a, Statement<'a>>) {
+        walk_statements(self, stmts);
+        stmts.retain(|stmt| !matches!(stmt, Statement::EmptyStatement(_)));
+    }
+
+    fn visit_statement(&mut self, stmt: &mut Statement<'a>) {
+        if !self.base.runes && self.base.current_scope_id == self.base.scopes.root_scope_id() {
+            // `export let name` declares a prop, the export itself goes away
+            let declaration = match stmt {
+                Statement::ExportNamedDeclaration(export)
+                    if matches!(
+                        &export.declaration,
+                        Some(Declaration::VariableDeclaration(decl))
+                            if decl.kind != VariableDeclarationKind::Const
+                    ) =>
+                {
+                    export.declaration.take()
+                }
+                _ => None,
+            };
+            if let Some(Declaration::VariableDeclaration(decl)) = declaration {
+                *stmt = Statement::VariableDeclaration(decl);
+            }
+            // `$: doubled = count * 2` runs once, after the other statements are sorted
+            if let Statement::LabeledStatement(labeled) = stmt {
+                if labeled.label.name == "$" {
+                    let mut statement = self.base.new_reactive_statement(&labeled.body);
+                    self.visit_statement(&mut labeled.body);
+                    statement.body = self.ast.move_statement(&mut labeled.body);
+                    self.base.reactive_statements.push(statement);
+                    *stmt = self.ast.statement_empty();
+                    return;
+                }
+            }
+        }
+        // the effects never run on the server, and there is nothing to inspect
+        if let Statement::ExpressionStatement(it) = stmt {
+            if matches!(
+                get_expression_rune(&it.expression),
+                Some(
+                    Rune::Effect
+                        | Rune::EffectPre
+                        | Rune::Inspect
+                        | Rune::InspectWith
+                        | Rune::InspectTrace
+                )
+            ) {
+                *stmt = self.ast.statement_empty();
+                return;
+            }
+        }
+        walk_statement(self, stmt);
+    }
+
+    fn visit_expression(&mut self, expr: &mut Expression<'a>) {
+        match expr {
+            Expression::Identifier(ident) => {
+                let name = ident.name;
+                if self.is_store_sub(&name) {
+                    *expr = self.build_store_get(&name);
+                }
+            }
+            // `$.store_set(count, value)`
+            Expression::AssignmentExpression(assignment_expr) => {
+                walk_assignment_expression(self, assignment_expr.as_mut());
+                let Some(name) = assignment_expr.left.get_identifier().map(str::to_string) else {
+                    return;
+                };
+                if !self.is_store_sub(&name) {
+                    return;
+                }
+                let right = self.ast.move_expression(&mut assignment_expr.right);
+                let left = self.build_store_get(&name);
+                *expr = self.ast.expression_call_with_atom(
+                    "$.store_set",
+                    self.ast.vec([
+                        self.ast.expression_identifier_reference(&name[1..]).into(),
+                        self.ast
+                            .build_assignment_value(assignment_expr.operator, left, right)
+                            .into(),
+                    ]),
+                );
+            }
+            // `$.update_store($$store_subs ??= {}, "$count", count)`
+            Expression::UpdateExpression(update) => {
+                let Some(name) = update.argument.get_identifier().map(str::to_string) else {
+                    return;
+                };
+                if !self.is_store_sub(&name) {
+                    return;
+                }
+                let callee = if update.prefix {
+                    "$.update_store_pre"
+                } else {
+                    "$.update_store"
+                };
+                let mut args = self.build_store_arguments(&name);
+                if update.operator == UpdateOperator::Decrement {
+                    args.push(self.ast.expression_numeric_literal(-1.0).into());
+                }
+                *expr = self.ast.expression_call_with_atom(callee, args);
+            }
+            Expression::CallExpression(call) => match get_rune(call) {
+                Some(Rune::Inspect | Rune::InspectWith | Rune::InspectTrace) => {
+                    *expr = self.ast.expression_void_zero();
+                }
+                // `$effect.root(fn)` -> `() => {}`, the effects never run so there is nothing
+                // to clean up
+                Some(Rune::EffectRoot) => {
+                    *expr = self
+                        .ast
+                        .expression_arrow(self.ast.vec([]), self.ast.vec([]));
+                }
+                Some(Rune::EffectTracking) => *expr = self.ast.expression_boolean_literal(false),
+                // `$state.snapshot(value)` -> `$.snapshot(value)`
+                Some(Rune::StateSnapshot) => {
+                    call.callee = self.ast.expression_identifier_reference("$.snapshot");
+                    self.visit_arguments(&mut call.arguments);
+                }
+                // the component is never rendered as a custom element on the server
+                Some(Rune::Host) => *expr = self.ast.expression_identifier_reference("undefined"),
+                _ => walk_expression(self, expr),
+            },
+            _ => walk_expression(self, expr),
+        }
+    }
+}
+
+impl<'a> ServerTransformer<'a> {
+    /// Takes the first argument of a rune call, e.g. `value` of `$state(value)`.
+    fn take_rune_argument(&self, init: Option<Expression<'a>>) -> Expression<'a> {
+        match init {
+            Some(Expression::CallExpression(mut call)) => call
+                .arguments
+                .first_mut()
+                .and_then(Argument::as_expression_mut)
+                .map(|value| self.ast.move_expression(value)),
+            _ => None,
+        }
+        .unwrap_or_else(|| self.ast.expression_identifier_reference("undefined"))
+    }
+
+    /// Replaces the `$bindable` fallbacks of the destructured props by their values, and keeps
+    /// `$$slots` and `$$events` out of the rest element.
+    fn build_props_pattern(&mut self, id: &mut BindingPattern<'a>) {
+        let BindingPatternKind::ObjectPattern(pattern) = &mut id.kind else {
+            return;
+        };
+        for property in pattern.properties.iter_mut() {
+            let BindingPatternKind::AssignmentPattern(assignment) = &mut property.value.kind else {
+                continue;
+            };
+            if !matches!(
+                &assignment.right,
+                Expression::CallExpression(call) if get_rune(call) == Some(Rune::Bindable)
+            ) {
+                continue;
+            }
+            // the parent reads the bound props back once the component is rendered
+            if let (Some(key), Some(ident)) = (
+                property.key.static_name(),
+                assignment.left.get_binding_identifier(),
+            ) {
+                self.bindable_props.push(if key == ident.name.as_str() {
+                    self.ast.object_property_shorthand(&key)
+                } else {
+                    self.ast.object_property(
+                        &key,
+                        self.ast.expression_identifier_reference(&ident.name),
+                    )
+                });
+            }
+            let bindable = self.ast.move_expression(&mut assignment.right);
+            assignment.right = self.take_rune_argument(Some(bindable));
+        }
+        if pattern.rest.is_some() {
+            for name in ["$$slots", "$$events"] {
+                pattern
+                    .properties
+                    .push(self.ast.binding_property_shorthand(name));
+            }
+        }
+    }
+
+    /// The name of the prop declared by `export let name` outside of runes mode
+    fn get_legacy_prop_name(&self, id: &BindingPattern<'a>) -> Option<Atom<'a>> {
+        if self.base.runes {
+            return None;
+        }
+        let ident = id.get_binding_identifier()?;
+        let kind = self.base.symbols.get_binding(ident.symbol_id()).kind();
+        (kind == BindingKind::BindableProp).then_some(ident.name)
+    }
+
+    /// ```js
+    /// $.fallback($$props["name"], fallback)
+    /// $.fallback($$props["name"], () => fallback, true) // evaluated only when it is needed
+    /// ```
+    fn build_legacy_prop(&self, name: &str, fallback: Option<Expression<'a>>) -> Expression<'a> {
+        let prop = self.ast.expression_computed_member(
+            self.ast.expression_identifier_reference("$$props"),
+            self.ast.expression_string_literal(name),
+        );
+        let Some(fallback) = fallback else {
+            return prop;
+        };
+        let mut args = self.ast.vec([prop.into()]);
+        if is_simple_expression(&fallback) {
+            args.push(fallback.into());
+        } else {
+            args.push(
+                self.ast
+                    .expression_arrow(
+                        self.ast.vec([]),
+                        self.ast.vec([self.ast.statement_expression(fallback)]),
+                    )
+                    .into(),
+            );
+            args.push(self.ast.expression_boolean_literal(true).into());
+        }
+        self.ast.expression_call_with_atom("$.fallback", args)
+    }
+
+    /// Whether `name` is the subscription to a store, e.g. `$count`
+    fn is_store_sub(&mut self, name: &str) -> bool {
+        self.base
+            .find_binding(name)
+            .is_some_and(|(_, binding)| binding.kind() == BindingKind::StoreSub)
+    }
+
+    /// ```js
+    /// $.store_get($$store_subs ??= {}, "$count", count)
+    /// ```
+    fn build_store_get(&self, name: &str) -> Expression<'a> {
+        self.ast
+            .expression_call_with_atom("$.store_get", self.build_store_arguments(name))
+    }
+
+    /// `$$store_subs ??= {}, "$count", count`, the store subscriptions followed by the store
+    fn build_store_arguments(&self, name: &str) -> OxcVec<'a, Argument<'a>> {
+        self.ast.vec([
+            self.ast
+                .expression_compound_assignment(
+                    AssignmentOperator::LogicalNullish,
+                    self.ast.expression_identifier_reference("$$store_subs"),
+                    self.ast.expression_object(self.ast.vec([])),
+                )
+                .into(),
+            self.ast.expression_string_literal(name).into(),
+            self.ast.expression_identifier_reference(&name[1..]).into(),
+        ])
+    }
+}
+
+/// Lowers the expressions of the template before it's rendered, e.g. the store reads of `{$count}`,
+/// since the rendered output moves them as they are.
+pub(super) struct TemplateExpressions<'s, 'a>(pub(super) &'s mut ServerTransformer<'a>);
+
+impl<'a> JsVisitMut<'a> for TemplateExpressions<'_, 'a> {
+    fn visit_expression(&mut self, expr: &mut Expression<'a>) {
+        self.0.visit_expression(expr);
+    }
+}
+
+impl<'a> VisitMut<'a> for TemplateExpressions<'_, 'a> {
+    fn vec<T, const N: usize>(&self, array: [T; N]) -> OxcVec<'a, T> {
+        self.0.ast.vec(array)
+    }
+}
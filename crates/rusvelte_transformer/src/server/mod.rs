@@ -0,0 +1,347 @@
+use oxc_allocator::Vec as OxcVec;
+use rusvelte_analyzer::{binding::BindingKind, CssMode};
+use rusvelte_ast::{
+    ast::Root,
+    ast_builder::AstBuilder,
+    js_ast::{AssignmentOperator, Expression, ObjectPropertyKind, Program, Statement},
+    visit_mut::{JsVisitMut, VisitMut},
+};
+use rusvelte_utils::escape::sanitize_template_string;
+
+use crate::{legacy::sort_reactive_statements, Transformer};
+
+mod js;
+mod svelte;
+
+use js::TemplateExpressions;
+
+/// Inserted between adjacent text nodes or component outputs so that they are not glued
+/// together during hydration.
+const EMPTY_COMMENT: &str = "<!---->";
+const BLOCK_OPEN: &str = "<!--[-->";
+const BLOCK_OPEN_ELSE: &str = "<!--[!-->";
+const BLOCK_CLOSE: &str = "<!--]-->";
+
+const PAYLOAD_OUT: &str = "$$payload.out";
+
+/// A piece of the rendered output. Consecutive strings and expressions are concatenated into
+/// a template literal that is appended to `$$payload.out`, while statements are emitted as is.
+enum TemplatePart<'a> {
+    String(String),
+    Expression(Expression<'a>),
+    Statement(Statement<'a>),
+}
+
+#[derive(Default)]
+struct ServerState<'a> {
+    /// Statements that must be placed before the template of the current fragment.
+    init: Vec<Statement<'a>>,
+    template: Vec<TemplatePart<'a>>,
+    /// A standalone component or render tag doesn't need the hydration boundaries around it.
+    skip_hydration_boundaries: bool,
+}
+
+pub(crate) struct ServerTransformer<'a> {
+    base: Transformer<'a>,
+    ast: AstBuilder<'a>,
+    state: ServerState<'a>,
+    /// The `$bindable` props, passed back to the parent by `$.bind_props`
+    bindable_props: Vec<ObjectPropertyKind<'a>>,
+    /// Whether a prop of a component is bound, in which case the template is rendered until
+    /// the bound values settle
+    has_component_bindings: bool,
+}
+
+impl<'a> ServerTransformer<'a> {
+    pub fn new(base: Transformer<'a>) -> Self {
+        Self {
+            ast: base.ast,
+            base,
+            state: ServerState::default(),
+            bindable_props: vec![],
+            has_component_bindings: false,
+        }
+    }
+
+    pub fn transform(mut self, root: &mut Root<'a>) -> Program<'a> {
+        let mut body = self.ast.vec([self
+            .ast
+            .statement_import_declaration(self.ast.import_all("$", "svelte/internal/server"))]);
+        let mut component_block = self.ast.vec([]);
+
+        if let Some(script) = root.instance.as_mut() {
+            self.visit_program(&mut script.content);
+            // `let doubled;` for the bindings implicitly declared by `$: doubled = count * 2`
+            for (_, binding) in self.base.symbols.iter() {
+                if binding.kind() == BindingKind::LegacyReactive {
+                    component_block.push(
+                        self.ast.statement_let_uninit(
+                            self.ast.binding_pattern_identifier(binding.name()),
+                        ),
+                    );
+                }
+            }
+            let instance_body = std::mem::replace(&mut script.content.body, self.ast.vec([]));
+            for stmt in instance_body {
+                if matches!(stmt, Statement::ImportDeclaration(_)) {
+                    body.push(stmt);
+                } else {
+                    component_block.push(stmt);
+                }
+            }
+            // the `$:` statements run once, in the order of their dependencies
+            let reactive_statements =
+                sort_reactive_statements(std::mem::take(&mut self.base.reactive_statements));
+            component_block.extend(
+                reactive_statements
+                    .into_iter()
+                    .map(|statement| statement.body),
+            );
+        }
+
+        if self.base.options.css == CssMode::Injected {
+            if let Some(css) = &root.css {
+                body.push(self.base.build_css(css));
+                component_block.insert(
+                    0,
+                    self.ast.statement_expression(
+                        self.ast.expression_call_with_atom(
+                            "$$payload.css.add",
+                            self.ast
+                                .vec([self.ast.expression_identifier_reference("$$css").into()]),
+                        ),
+                    ),
+                );
+            }
+        }
+        if let Some(filename) = self.base.build_filename() {
+            body.push(filename);
+        }
+
+        let has_stores = self
+            .base
+            .symbols
+            .iter()
+            .any(|(_, binding)| binding.kind() == BindingKind::StoreSub);
+        if has_stores {
+            TemplateExpressions(&mut self).visit_fragment(&mut root.fragment);
+        }
+        let template = self.visit_fragment(&mut root.fragment);
+        if self.has_component_bindings {
+            component_block.extend(self.build_settle_loop(template));
+        } else {
+            component_block.extend(template);
+        }
+        if !self.bindable_props.is_empty() {
+            component_block.push(self.build_bind_props());
+        }
+        if has_stores {
+            component_block.insert(
+                0,
+                self.ast
+                    .statement_let_uninit(self.ast.binding_pattern_identifier("$$store_subs")),
+            );
+            component_block.push(self.build_unsubscribe_stores());
+        }
+        let component = self.ast.function_declaration(
+            self.base.name.as_str(),
+            self.ast.vec([
+                self.ast
+                    .formal_parameter(self.ast.binding_pattern_identifier("$$payload")),
+                self.ast
+                    .formal_parameter(self.ast.binding_pattern_identifier("$$props")),
+            ]),
+            component_block,
+        );
+        body.push(
+            self.ast
+                .statement_export_default_function_declaration(component),
+        );
+
+        self.base.program(body)
+    }
+
+    /// ```js
+    /// let $$settled = true;
+    /// let $$inner_payload;
+    /// function $$render_inner($$payload) { ... }
+    /// do {
+    ///     $$settled = true;
+    ///     $$inner_payload = $.copy_payload($$payload);
+    ///     $$render_inner($$inner_payload);
+    /// } while (!$$settled);
+    /// $.assign_payload($$payload, $$inner_payload);
+    /// ```
+    fn build_settle_loop(&self, template: OxcVec<'a, Statement<'a>>) -> OxcVec<'a, Statement<'a>> {
+        let ast = self.ast;
+        let identifier = |name| ast.expression_identifier_reference(name);
+        let render_inner = ast.function_declaration(
+            "$$render_inner",
+            ast.vec([ast.formal_parameter(ast.binding_pattern_identifier("$$payload"))]),
+            template,
+        );
+        let body = ast.vec([
+            ast.statement_expression(ast.expression_assignment(
+                identifier("$$settled"),
+                ast.expression_boolean_literal(true),
+            )),
+            ast.statement_expression(ast.expression_assignment(
+                identifier("$$inner_payload"),
+                ast.expression_call_with_atom(
+                    "$.copy_payload",
+                    ast.vec([identifier("$$payload").into()]),
+                ),
+            )),
+            ast.statement_expression(ast.expression_call(
+                identifier("$$render_inner"),
+                ast.vec([identifier("$$inner_payload").into()]),
+            )),
+        ]);
+        ast.vec([
+            ast.statement_let(
+                ast.binding_pattern_identifier("$$settled"),
+                ast.expression_boolean_literal(true),
+            ),
+            ast.statement_let_uninit(ast.binding_pattern_identifier("$$inner_payload")),
+            ast.statement_function_declaration(render_inner),
+            ast.statement_do_while(body, ast.expression_not(identifier("$$settled"))),
+            ast.statement_expression(ast.expression_call_with_atom(
+                "$.assign_payload",
+                ast.vec([
+                    identifier("$$payload").into(),
+                    identifier("$$inner_payload").into(),
+                ]),
+            )),
+        ])
+    }
+
+    /// ```js
+    /// $.bind_props($$props, { value });
+    /// ```
+    fn build_bind_props(&mut self) -> Statement<'a> {
+        let props = self
+            .ast
+            .vec_from_iter(std::mem::take(&mut self.bindable_props));
+        self.ast
+            .statement_expression(self.ast.expression_call_with_atom(
+                "$.bind_props",
+                self.ast.vec([
+                    self.ast.expression_identifier_reference("$$props").into(),
+                    self.ast.expression_object(props).into(),
+                ]),
+            ))
+    }
+
+    /// ```js
+    /// if ($$store_subs) $.unsubscribe_stores($$store_subs);
+    /// ```
+    fn build_unsubscribe_stores(&self) -> Statement<'a> {
+        let store_subs = || self.ast.expression_identifier_reference("$$store_subs");
+        self.ast.statement_if_unbraced(
+            store_subs(),
+            self.ast
+                .statement_expression(self.ast.expression_call_with_atom(
+                    "$.unsubscribe_stores",
+                    self.ast.vec([store_subs().into()]),
+                )),
+            None,
+        )
+    }
+
+    fn push_string<S: Into<String>>(&mut self, value: S) {
+        self.state.template.push(TemplatePart::String(value.into()));
+    }
+
+    fn push_expression(&mut self, expr: Expression<'a>) {
+        self.state.template.push(TemplatePart::Expression(expr));
+    }
+
+    fn push_statement(&mut self, stmt: Statement<'a>) {
+        self.state.template.push(TemplatePart::Statement(stmt));
+    }
+
+    /// Pushes the empty comment unless the hydration boundaries are skipped.
+    fn push_empty_comment(&mut self) {
+        if !self.state.skip_hydration_boundaries {
+            self.push_string(EMPTY_COMMENT);
+        }
+    }
+
+    /// ```js
+    /// $$payload.out += "<!--[-->";
+    /// ```
+    fn build_payload_out_literal(&self, value: &str) -> Statement<'a> {
+        self.ast
+            .statement_expression(self.ast.expression_compound_assignment(
+                AssignmentOperator::Addition,
+                self.ast.expression_identifier_reference(PAYLOAD_OUT),
+                self.ast.expression_string_literal(value),
+            ))
+    }
+
+    /// Concatenates the consecutive strings and expressions of the template into template literals
+    /// that are assigned to `target`.
+    fn build_template(
+        &self,
+        template: Vec<TemplatePart<'a>>,
+        target: &str,
+        operator: AssignmentOperator,
+    ) -> OxcVec<'a, Statement<'a>> {
+        let mut statements = self.ast.vec([]);
+        let mut quasis = vec![String::new()];
+        let mut expressions = self.ast.vec([]);
+        for part in template {
+            match part {
+                TemplatePart::String(value) => quasis
+                    .last_mut()
+                    .unwrap()
+                    .push_str(&sanitize_template_string(&value)),
+                TemplatePart::Expression(expr) => {
+                    expressions.push(expr);
+                    quasis.push(String::new());
+                }
+                TemplatePart::Statement(stmt) => {
+                    self.flush_template(
+                        &mut statements,
+                        &mut quasis,
+                        &mut expressions,
+                        target,
+                        operator,
+                    );
+                    statements.push(stmt);
+                }
+            }
+        }
+        self.flush_template(
+            &mut statements,
+            &mut quasis,
+            &mut expressions,
+            target,
+            operator,
+        );
+        statements
+    }
+
+    fn flush_template(
+        &self,
+        statements: &mut OxcVec<'a, Statement<'a>>,
+        quasis: &mut Vec<String>,
+        expressions: &mut OxcVec<'a, Expression<'a>>,
+        target: &str,
+        operator: AssignmentOperator,
+    ) {
+        if expressions.is_empty() && quasis[0].is_empty() {
+            return;
+        }
+        let quasis = std::mem::replace(quasis, vec![String::new()]);
+        let expressions = std::mem::replace(expressions, self.ast.vec([]));
+        statements.push(
+            self.ast
+                .statement_expression(self.ast.expression_compound_assignment(
+                    operator,
+                    self.ast.expression_identifier_reference(target),
+                    self.ast.expression_template_literal(&quasis, expressions),
+                )),
+        );
+    }
+}
@@ -0,0 +1,1111 @@
+use oxc_allocator::{CloneIn, Vec as OxcVec};
+use rusvelte_analyzer::Namespace;
+use rusvelte_ast::{
+    ast::*,
+    ast_builder::AstBuilder,
+    ast_kind::SvelteAstType,
+    js_ast::{
+        Argument, AssignmentOperator, BinaryOperator, ChainElement, Expression, ObjectPropertyKind,
+        Statement,
+    },
+    visit_mut::VisitMut,
+};
+use rusvelte_utils::{
+    boolean_attribute::is_boolean_attribute,
+    escape::{escape_html, sanitize_template_string},
+    void_element::is_void,
+};
+
+use crate::svelte::clean_nodes::CleanNodesReturn;
+
+use super::{
+    ServerState, ServerTransformer, TemplatePart, BLOCK_CLOSE, BLOCK_OPEN, BLOCK_OPEN_ELSE,
+    EMPTY_COMMENT, PAYLOAD_OUT,
+};
+
+impl<'a> VisitMut<'a> for ServerTransformer<'a> {
+    fn vec<T, const N: usize>(&self, array: [T; N]) -> OxcVec<'a, T> {
+        self.ast.vec(array)
+    }
+
+    fn visit_fragment(&mut self, fragment: &mut Fragment<'a>) -> OxcVec<'a, Statement<'a>> {
+        self.transform_fragment(SvelteAstType::Fragment, fragment)
+    }
+}
+
+impl<'a> ServerTransformer<'a> {
+    /// Renders the nodes of a fragment owned by a node of type `parent`.
+    fn transform_fragment(
+        &mut self,
+        parent: SvelteAstType,
+        fragment: &mut Fragment<'a>,
+    ) -> OxcVec<'a, Statement<'a>> {
+        let CleanNodesReturn {
+            hoisted,
+            trimmed,
+            is_standalone,
+            is_text_first,
+        } = self.base.clean_nodes(parent, "", fragment);
+
+        let parent_state = std::mem::replace(
+            &mut self.state,
+            ServerState {
+                skip_hydration_boundaries: is_standalone,
+                ..Default::default()
+            },
+        );
+
+        for node in hoisted {
+            self.transform_node(node);
+        }
+        if is_text_first {
+            // insert `<!---->` to prevent this from being glued to the previous fragment
+            self.push_string(EMPTY_COMMENT);
+        }
+        for node in trimmed {
+            self.transform_node(node);
+        }
+
+        let state = std::mem::replace(&mut self.state, parent_state);
+        let mut body = self.ast.vec_from_iter(state.init);
+        body.extend(self.build_template(state.template, PAYLOAD_OUT, AssignmentOperator::Addition));
+        body
+    }
+
+    fn transform_node(&mut self, node: FragmentNode<'a>) {
+        match node {
+            FragmentNode::Text(text) => self.push_string(escape_html(&text.data, false)),
+            FragmentNode::Comment(comment) => self.push_string(format!("<!--{}-->", comment.data)),
+            FragmentNode::Element(element) => self.transform_element(*element),
+            FragmentNode::Tag(tag) => self.transform_tag(tag),
+            FragmentNode::Block(block) => self.transform_block(block),
+        }
+    }
+
+    fn transform_element(&mut self, element: Element<'a>) {
+        match element {
+            Element::RegularElement(element) => self.transform_regular_element(element),
+            Element::Component(mut component) => {
+                let callee = self.ast.expression_identifier_reference(component.name);
+                self.build_inline_component(
+                    callee,
+                    &mut component.attributes,
+                    &mut component.fragment,
+                    SvelteAstType::Component,
+                    false,
+                );
+            }
+            Element::SvelteSelf(mut svelte_self) => {
+                let callee = self
+                    .ast
+                    .expression_identifier_reference(self.base.name.as_str());
+                self.build_inline_component(
+                    callee,
+                    &mut svelte_self.attributes,
+                    &mut svelte_self.fragment,
+                    SvelteAstType::SvelteSelf,
+                    false,
+                );
+            }
+            Element::SvelteComponent(mut component) => {
+                let callee = self.ast.move_expression(&mut component.expression);
+                self.build_inline_component(
+                    callee,
+                    &mut component.attributes,
+                    &mut component.fragment,
+                    SvelteAstType::SvelteComponent,
+                    true,
+                );
+            }
+            Element::SvelteElement(element) => self.transform_svelte_element(element),
+            Element::SvelteFragment(mut fragment) => {
+                let body =
+                    self.transform_fragment(SvelteAstType::SvelteFragment, &mut fragment.fragment);
+                self.push_statement(self.ast.statement_block(body));
+            }
+            Element::SvelteHead(mut head) => {
+                // $.head($$payload, ($$payload) => { ... })
+                let body = self.transform_fragment(SvelteAstType::SvelteHead, &mut head.fragment);
+                let stmt = self
+                    .ast
+                    .statement_expression(self.ast.expression_call_with_atom(
+                        "$.head",
+                        self.ast.vec([
+                            self.ast.expression_identifier_reference("$$payload").into(),
+                            self.build_payload_arrow(body).into(),
+                        ]),
+                    ));
+                self.push_statement(stmt);
+            }
+            Element::TitleElement(mut title) => {
+                // $$payload.title = `<title>...</title>`
+                let parent_template = std::mem::take(&mut self.state.template);
+                self.push_string("<title>");
+                for node in self.ast.move_fragment_nodes(&mut title.fragment) {
+                    self.transform_node(node);
+                }
+                self.push_string("</title>");
+                let template = std::mem::replace(&mut self.state.template, parent_template);
+                let stmts =
+                    self.build_template(template, "$$payload.title", AssignmentOperator::Assign);
+                self.state.init.extend(stmts);
+            }
+            Element::SlotElement(slot) => self.transform_slot_element(slot),
+            // These elements only make sense in the browser
+            Element::SvelteBody(_) | Element::SvelteWindow(_) | Element::SvelteDocument(_) => {}
+        }
+    }
+
+    fn transform_regular_element(&mut self, mut element: RegularElement<'a>) {
+        let parent_namespace = self.base.namespace;
+        match element.name {
+            "svg" => self.base.namespace = Namespace::Svg,
+            "math" => self.base.namespace = Namespace::Mathml,
+            _ => {}
+        }
+        self.push_string(format!("<{}", element.name));
+        let content = self.build_element_attributes(element.name, &mut element.attributes);
+        self.push_string(">");
+
+        if let Some(content) = content {
+            // the content is bound, e.g. `<textarea bind:value>`
+            self.push_expression(content);
+        } else {
+            // the children of `<foreignObject>` are HTML again
+            if element.name == "foreignObject" {
+                self.base.namespace = Namespace::Html;
+            }
+            let CleanNodesReturn {
+                hoisted, trimmed, ..
+            } = self.base.clean_nodes(
+                SvelteAstType::RegularElement,
+                element.name,
+                &mut element.fragment,
+            );
+            for node in hoisted.into_iter().chain(trimmed) {
+                self.transform_node(node);
+            }
+        }
+        self.base.namespace = parent_namespace;
+
+        if !is_void(element.name) {
+            self.push_string(format!("</{}>", element.name));
+        }
+    }
+
+    /// ```js
+    /// $.element($$payload, tag, () => { attributes }, () => { children })
+    /// ```
+    fn transform_svelte_element(&mut self, mut element: SvelteElement<'a>) {
+        let parent_template = std::mem::take(&mut self.state.template);
+        let content = self.build_element_attributes("svelte:element", &mut element.attributes);
+        let template = std::mem::replace(&mut self.state.template, parent_template);
+        let attributes = self.build_template(template, PAYLOAD_OUT, AssignmentOperator::Addition);
+        let children = match content {
+            Some(content) => self.build_template(
+                vec![TemplatePart::Expression(content)],
+                PAYLOAD_OUT,
+                AssignmentOperator::Addition,
+            ),
+            None => self.transform_fragment(SvelteAstType::SvelteElement, &mut element.fragment),
+        };
+
+        let mut args = self.ast.vec([
+            self.ast.expression_identifier_reference("$$payload").into(),
+            self.ast.move_expression(&mut element.tag).into(),
+        ]);
+        if !attributes.is_empty() || !children.is_empty() {
+            args.push(self.build_thunk(attributes).into());
+        }
+        if !children.is_empty() {
+            args.push(self.build_thunk(children).into());
+        }
+        self.push_statement(
+            self.ast
+                .statement_expression(self.ast.expression_call_with_atom("$.element", args)),
+        );
+        self.push_empty_comment();
+    }
+
+    /// ```js
+    /// $.slot($$payload, $$props, "name", { ...props }, () => { fallback })
+    /// ```
+    fn transform_slot_element(&mut self, mut slot: SlotElement<'a>) {
+        let mut name = self.ast.expression_string_literal("default");
+        let mut props = self.ast.vec([]);
+        let mut spreads = vec![];
+        for attribute in slot.attributes.iter_mut() {
+            match attribute {
+                Attribute::NormalAttribute(attribute) => {
+                    let value = self.build_attribute_value(&mut attribute.value);
+                    match attribute.name {
+                        "name" => name = value,
+                        "slot" => {}
+                        _ => props.push(self.ast.object_property(attribute.name, value)),
+                    }
+                }
+                Attribute::SpreadAttribute(spread) => {
+                    spreads.push(self.ast.move_expression(&mut spread.expression));
+                }
+                // the analyzer rejects the `let:` directives
+                Attribute::Directive(_) => {}
+            }
+        }
+        let props = if spreads.is_empty() {
+            self.ast.expression_object(props)
+        } else {
+            let mut elements = self.ast.vec([self.ast.expression_object(props).into()]);
+            elements.extend(spreads.into_iter().map(Into::into));
+            self.ast.expression_call_with_atom(
+                "$.spread_props",
+                self.ast.vec([self.ast.expression_array(elements).into()]),
+            )
+        };
+        let fallback = if slot.fragment.nodes.is_empty() {
+            self.ast.expression_null()
+        } else {
+            let body = self.transform_fragment(SvelteAstType::SlotElement, &mut slot.fragment);
+            self.build_thunk(body)
+        };
+
+        self.push_string(EMPTY_COMMENT);
+        self.push_statement(
+            self.ast
+                .statement_expression(self.ast.expression_call_with_atom(
+                    "$.slot",
+                    self.ast.vec([
+                        self.ast.expression_identifier_reference("$$payload").into(),
+                        self.ast.expression_identifier_reference("$$props").into(),
+                        name.into(),
+                        props.into(),
+                        fallback.into(),
+                    ]),
+                )),
+        );
+        self.push_string(EMPTY_COMMENT);
+    }
+
+    /// Renders the attributes of an element, returning the content of the element when it is
+    /// set by a binding, e.g. `bind:value` on a `<textarea>`.
+    fn build_element_attributes(
+        &mut self,
+        element_name: &str,
+        attributes: &mut OxcVec<'a, Attribute<'a>>,
+    ) -> Option<Expression<'a>> {
+        let mut entries = vec![];
+        let mut class_directives = vec![];
+        let mut style_directives = self.ast.vec([]);
+        let mut bindings = vec![];
+        for attribute in attributes.iter_mut() {
+            match attribute {
+                Attribute::NormalAttribute(attribute) => {
+                    if attribute.is_event_attribute() {
+                        continue;
+                    }
+                    let text = match &attribute.value {
+                        AttributeValue::Quoted(values) => match values.as_slice() {
+                            [QuotedAttributeValue::Text(text)] => Some(text.data.to_string()),
+                            _ => None,
+                        },
+                        _ => None,
+                    };
+                    let entry = if attribute.value.is_true() {
+                        ElementAttribute::True(attribute.name)
+                    } else if let Some(text) = text {
+                        ElementAttribute::Text(attribute.name, text)
+                    } else {
+                        let value = self.build_attribute_value(&mut attribute.value);
+                        ElementAttribute::Expression(attribute.name, value)
+                    };
+                    entries.push(entry);
+                }
+                Attribute::SpreadAttribute(spread) => {
+                    entries.push(ElementAttribute::Spread(
+                        self.ast.move_expression(&mut spread.expression),
+                    ));
+                }
+                Attribute::Directive(Directive::ClassDirective(directive)) => {
+                    let value = self.ast.move_expression(&mut directive.expression);
+                    class_directives.push((directive.name, value));
+                }
+                Attribute::Directive(Directive::StyleDirective(directive)) => {
+                    let mut value = if directive.value.is_true() {
+                        // `style:color` is a shorthand for `style:color={color}`
+                        self.ast.expression_identifier_reference(directive.name)
+                    } else {
+                        self.build_attribute_value(&mut directive.value)
+                    };
+                    if directive.important {
+                        value = self.ast.expression_binary(
+                            value,
+                            BinaryOperator::Addition,
+                            self.ast.expression_string_literal(" !important"),
+                        );
+                    }
+                    style_directives.push(self.ast.object_property(directive.name, value));
+                }
+                Attribute::Directive(Directive::BindDirective(directive)) => {
+                    let value = self.ast.move_expression(&mut directive.expression);
+                    bindings.push((directive.name, value));
+                }
+                // The events, actions, transitions and animations only make sense in the
+                // browser, and the analyzer rejects the `let:` directives
+                Attribute::Directive(_) => {}
+            }
+        }
+
+        let content = self.build_element_bindings(element_name, &mut entries, bindings);
+
+        if entries
+            .iter()
+            .any(|entry| matches!(entry, ElementAttribute::Spread(_)))
+        {
+            self.build_spread_attributes(entries, class_directives, style_directives);
+            return content;
+        }
+
+        if !style_directives.is_empty() {
+            // ${$.add_styles($.merge_styles(style, { color: value }))}
+            let style = entries
+                .iter()
+                .position(|entry| entry.name() == Some("style"))
+                .map(|index| entries.remove(index));
+            let styles = self.ast.expression_object(style_directives);
+            let styles = match style {
+                Some(style) => self.ast.expression_call_with_atom(
+                    "$.merge_styles",
+                    self.ast
+                        .vec([style.into_value(self.ast).into(), styles.into()]),
+                ),
+                None => styles,
+            };
+            self.push_expression(
+                self.ast
+                    .expression_call_with_atom("$.add_styles", self.ast.vec([styles.into()])),
+            );
+        }
+
+        // `class` carries the class directives and the hash of the scoped styles
+        if !class_directives.is_empty() || !self.base.css_hash.is_empty() {
+            let index = entries
+                .iter()
+                .position(|entry| entry.name() == Some("class"));
+            let class = index.map(|index| entries.remove(index));
+            let entry = self.build_class_attribute(class, class_directives);
+            match index {
+                Some(index) => entries.insert(index, entry),
+                None => entries.push(entry),
+            }
+        }
+
+        for entry in entries {
+            match entry {
+                ElementAttribute::True(name) if is_boolean_attribute(name) => {
+                    self.push_string(format!(" {}", name));
+                }
+                ElementAttribute::True(name) => self.push_string(format!(" {}=\"\"", name)),
+                ElementAttribute::Text(name, value) => {
+                    if name == "class" && value.is_empty() {
+                        continue;
+                    }
+                    self.push_string(format!(" {}=\"{}\"", name, escape_html(&value, true)));
+                }
+                ElementAttribute::Expression(name, value) => {
+                    // ${$.attr("name", value, is_boolean)}
+                    let mut args = self.ast.vec([
+                        self.ast.expression_string_literal(name).into(),
+                        value.into(),
+                    ]);
+                    if is_boolean_attribute(name) {
+                        args.push(self.ast.expression_boolean_literal(true).into());
+                    }
+                    self.push_expression(self.ast.expression_call_with_atom("$.attr", args));
+                }
+                ElementAttribute::Spread(_) => unreachable!(),
+            }
+        }
+        content
+    }
+
+    /// Renders the bindings that reflect an attribute or the content of the element. The
+    /// other bindings read from the DOM, which only exists in the browser.
+    fn build_element_bindings(
+        &mut self,
+        element_name: &str,
+        entries: &mut Vec<ElementAttribute<'a>>,
+        bindings: Vec<(&'a str, Expression<'a>)>,
+    ) -> Option<Expression<'a>> {
+        let input_type = entries.iter().find_map(|entry| match entry {
+            ElementAttribute::Text("type", value) => Some(value.clone()),
+            _ => None,
+        });
+        let mut content = None;
+        for (name, expression) in bindings {
+            match name {
+                "value" if element_name == "textarea" => {
+                    content =
+                        Some(self.ast.expression_call_with_atom(
+                            "$.escape",
+                            self.ast.vec([expression.into()]),
+                        ));
+                }
+                "textContent" | "innerText" => {
+                    content =
+                        Some(self.ast.expression_call_with_atom(
+                            "$.escape",
+                            self.ast.vec([expression.into()]),
+                        ));
+                }
+                "innerHTML" => content = Some(expression),
+                // the selected option and the files are only known in the browser
+                "value" if element_name == "select" || input_type.as_deref() == Some("file") => {}
+                "value" | "checked" | "open" => {
+                    entries.push(ElementAttribute::Expression(name, expression));
+                }
+                "group" => {
+                    // `checked` is `group === value` for a radio, `group.includes(value)` for
+                    // a checkbox
+                    let value = entries.iter().find_map(|entry| match entry {
+                        ElementAttribute::Text("value", value) => {
+                            Some(self.ast.expression_string_literal(value.as_str()))
+                        }
+                        ElementAttribute::Expression("value", value) => {
+                            Some(value.clone_in(self.base.allocator))
+                        }
+                        _ => None,
+                    });
+                    let Some(value) = value else {
+                        continue;
+                    };
+                    let checked = if input_type.as_deref() == Some("checkbox") {
+                        self.ast.expression_call(
+                            self.ast.expression_static_member(expression, "includes"),
+                            self.ast.vec([value.into()]),
+                        )
+                    } else {
+                        self.ast.expression_binary(
+                            expression,
+                            BinaryOperator::StrictEquality,
+                            value,
+                        )
+                    };
+                    entries.push(ElementAttribute::Expression("checked", checked));
+                }
+                _ => {}
+            }
+        }
+        content
+    }
+
+    /// ```js
+    /// ${$.spread_attributes({ name: value, ...rest }, { "svelte-xyz": true, active }, { color })}
+    /// ```
+    fn build_spread_attributes(
+        &mut self,
+        entries: Vec<ElementAttribute<'a>>,
+        class_directives: Vec<(&'a str, Expression<'a>)>,
+        style_directives: OxcVec<'a, ObjectPropertyKind<'a>>,
+    ) {
+        let props = self.ast.vec_from_iter(entries.into_iter().map(|entry| {
+            match entry {
+                ElementAttribute::True(name) => self
+                    .ast
+                    .object_property(name, self.ast.expression_boolean_literal(true)),
+                ElementAttribute::Text(name, value) => self
+                    .ast
+                    .object_property(name, self.ast.expression_string_literal(value.as_str())),
+                ElementAttribute::Expression(name, value) => self.ast.object_property(name, value),
+                ElementAttribute::Spread(expression) => self.ast.spread_property(expression),
+            }
+        }));
+        let mut args = self.ast.vec([self.ast.expression_object(props).into()]);
+        let mut classes = self.ast.vec([]);
+        if !self.base.css_hash.is_empty() {
+            classes.push(self.ast.object_property(
+                &self.base.css_hash,
+                self.ast.expression_boolean_literal(true),
+            ));
+        }
+        classes.extend(
+            class_directives
+                .into_iter()
+                .map(|(name, value)| self.ast.object_property(name, value)),
+        );
+        if !classes.is_empty() || !style_directives.is_empty() {
+            let classes = if classes.is_empty() {
+                self.ast.expression_null()
+            } else {
+                self.ast.expression_object(classes)
+            };
+            args.push(classes.into());
+        }
+        if !style_directives.is_empty() {
+            args.push(self.ast.expression_object(style_directives).into());
+        }
+        self.push_expression(
+            self.ast
+                .expression_call_with_atom("$.spread_attributes", args),
+        );
+    }
+
+    /// Appends the class directives and the class of the scoped styles to the `class`
+    /// attribute, e.g. `` `${$.stringify(class)} ${$.stringify(directives)} svelte-xyz` ``
+    fn build_class_attribute(
+        &self,
+        class: Option<ElementAttribute<'a>>,
+        class_directives: Vec<(&'a str, Expression<'a>)>,
+    ) -> ElementAttribute<'a> {
+        let mut quasis = vec![String::new()];
+        let mut expressions = vec![];
+        match class {
+            Some(ElementAttribute::Text(_, class)) if !class.trim().is_empty() => quasis[0] = class,
+            Some(ElementAttribute::Expression(_, class)) => {
+                expressions.push(class);
+                quasis.push(String::new());
+            }
+            _ => {}
+        }
+        let push_separator = |quasis: &mut Vec<String>, has_expressions: bool| {
+            if has_expressions || !quasis[0].is_empty() {
+                quasis.last_mut().unwrap().push(' ');
+            }
+        };
+        if !class_directives.is_empty() {
+            push_separator(&mut quasis, !expressions.is_empty());
+            expressions.push(self.build_class_directives(class_directives));
+            quasis.push(String::new());
+        }
+        if !self.base.css_hash.is_empty() {
+            push_separator(&mut quasis, !expressions.is_empty());
+            quasis.last_mut().unwrap().push_str(&self.base.css_hash);
+        }
+
+        if expressions.is_empty() {
+            return ElementAttribute::Text("class", quasis.swap_remove(0));
+        }
+        if expressions.len() == 1 && quasis.iter().all(String::is_empty) {
+            return ElementAttribute::Expression("class", expressions.swap_remove(0));
+        }
+        let quasis: Vec<_> = quasis
+            .iter()
+            .map(|quasi| sanitize_template_string(quasi))
+            .collect();
+        let expressions = self.ast.vec_from_iter(
+            expressions
+                .into_iter()
+                .map(|expression| self.build_stringify(expression)),
+        );
+        ElementAttribute::Expression(
+            "class",
+            self.ast.expression_template_literal(&quasis, expressions),
+        )
+    }
+
+    /// `[active ? "active" : ""].filter(Boolean).join(" ")`
+    fn build_class_directives(
+        &self,
+        class_directives: Vec<(&'a str, Expression<'a>)>,
+    ) -> Expression<'a> {
+        let classes = self
+            .ast
+            .vec_from_iter(class_directives.into_iter().map(|(name, value)| {
+                self.ast
+                    .expression_conditional(
+                        value,
+                        self.ast.expression_string_literal(name),
+                        self.ast.expression_string_literal(""),
+                    )
+                    .into()
+            }));
+        let filtered = self.ast.expression_call(
+            self.ast
+                .expression_static_member(self.ast.expression_array(classes), "filter"),
+            self.ast
+                .vec([self.ast.expression_identifier_reference("Boolean").into()]),
+        );
+        self.ast.expression_call(
+            self.ast.expression_static_member(filtered, "join"),
+            self.ast
+                .vec([self.ast.expression_string_literal(" ").into()]),
+        )
+    }
+
+    /// `$.stringify(value)`
+    fn build_stringify(&self, value: Expression<'a>) -> Expression<'a> {
+        self.ast
+            .expression_call_with_atom("$.stringify", self.ast.vec([value.into()]))
+    }
+
+    fn build_attribute_value(&self, value: &mut AttributeValue<'a>) -> Expression<'a> {
+        match value {
+            AttributeValue::True => self.ast.expression_boolean_literal(true),
+            AttributeValue::ExpressionTag(tag) => self.ast.move_expression(&mut tag.expression),
+            AttributeValue::Quoted(values) => match values.as_mut_slice() {
+                [] => self.ast.expression_string_literal(""),
+                [QuotedAttributeValue::Text(text)] => {
+                    self.ast.expression_string_literal(text.data.as_ref())
+                }
+                [QuotedAttributeValue::ExpressionTag(tag)] => {
+                    self.ast.move_expression(&mut tag.expression)
+                }
+                values => {
+                    // `a ${b} c` with the expressions stringified
+                    let mut quasis = vec![String::new()];
+                    let mut expressions = self.ast.vec([]);
+                    for value in values.iter_mut() {
+                        match value {
+                            QuotedAttributeValue::Text(text) => quasis
+                                .last_mut()
+                                .unwrap()
+                                .push_str(&sanitize_template_string(&text.data)),
+                            QuotedAttributeValue::ExpressionTag(tag) => {
+                                expressions.push(self.ast.expression_call_with_atom(
+                                    "$.stringify",
+                                    self.ast.vec([
+                                        self.ast.move_expression(&mut tag.expression).into(),
+                                    ]),
+                                ));
+                                quasis.push(String::new());
+                            }
+                        }
+                    }
+                    self.ast.expression_template_literal(&quasis, expressions)
+                }
+            },
+        }
+    }
+
+    /// ```js
+    /// Component($$payload, { prop: value, children: ($$payload) => { ... }, $$slots: { default: true } })
+    /// ```
+    fn build_inline_component(
+        &mut self,
+        callee: Expression<'a>,
+        attributes: &mut OxcVec<'a, Attribute<'a>>,
+        fragment: &mut Fragment<'a>,
+        parent: SvelteAstType,
+        optional: bool,
+    ) {
+        // Each item is either an object of props or a spread expression
+        let mut props_and_spreads = vec![];
+        let mut props = self.ast.vec([]);
+        let mut has_children_prop = false;
+        for attribute in attributes.iter_mut() {
+            match attribute {
+                Attribute::NormalAttribute(attribute) => {
+                    has_children_prop |= attribute.name == "children";
+                    let value = self.build_attribute_value(&mut attribute.value);
+                    props.push(self.ast.object_property(attribute.name, value));
+                }
+                Attribute::SpreadAttribute(spread) => {
+                    if !props.is_empty() {
+                        let props = std::mem::replace(&mut props, self.ast.vec([]));
+                        props_and_spreads.push(self.ast.expression_object(props));
+                    }
+                    props_and_spreads.push(self.ast.move_expression(&mut spread.expression));
+                }
+                // `get value() { return value; }, set value($$value) { value = $$value; ... }`
+                Attribute::Directive(Directive::BindDirective(directive))
+                    if directive.name != "this" =>
+                {
+                    let value = self.ast.move_expression(&mut directive.expression);
+                    let assignment = self.ast.expression_assignment(
+                        value.clone_in(self.base.allocator),
+                        self.ast.expression_identifier_reference("$$value"),
+                    );
+                    // the template is rendered again since the value was read before
+                    let unsettle = self.ast.expression_assignment(
+                        self.ast.expression_identifier_reference("$$settled"),
+                        self.ast.expression_boolean_literal(false),
+                    );
+                    props.push(self.ast.object_property_getter(directive.name, value));
+                    props.push(self.ast.object_property_setter(
+                        directive.name,
+                        "$$value",
+                        self.ast.vec([
+                            self.ast.statement_expression(assignment),
+                            self.ast.statement_expression(unsettle),
+                        ]),
+                    ));
+                    self.has_component_bindings = true;
+                }
+                // `bind:this` and the events only make sense in the browser, and the analyzer
+                // rejects the `let:` directives
+                Attribute::Directive(_) => {}
+            }
+        }
+
+        let mut snippet_declarations = self.ast.vec([]);
+        let mut children: Vec<(&'a str, OxcVec<'a, FragmentNode<'a>>)> =
+            vec![("default", self.ast.vec([]))];
+        for node in self.ast.move_fragment_nodes(fragment) {
+            if let FragmentNode::Block(Block::SnippetBlock(snippet)) = node {
+                props.push(self.ast.object_property_shorthand(&snippet.expression.name));
+                snippet_declarations.push(self.build_snippet(snippet));
+                continue;
+            }
+            let slot_name = match &node {
+                FragmentNode::Element(element) => {
+                    element
+                        .attributes()
+                        .iter()
+                        .find_map(|attribute| match attribute {
+                            Attribute::NormalAttribute(attribute) if attribute.name == "slot" => {
+                                attribute.value.get_static_value()
+                            }
+                            _ => None,
+                        })
+                }
+                _ => None,
+            }
+            .unwrap_or("default");
+            match children.iter_mut().find(|(name, _)| *name == slot_name) {
+                Some((_, nodes)) => nodes.push(node),
+                None => children.push((slot_name, self.ast.vec([node]))),
+            }
+        }
+
+        let mut serialized_slots = self.ast.vec([]);
+        for (slot_name, nodes) in children {
+            let mut fragment = self.ast.fragment(nodes, false);
+            let body = self.transform_fragment(parent, &mut fragment);
+            if body.is_empty() {
+                continue;
+            }
+            let slot_fn = self.build_payload_arrow(body);
+            if slot_name == "default" && !has_children_prop {
+                props.push(self.ast.object_property("children", slot_fn));
+                serialized_slots.push(
+                    self.ast
+                        .object_property("default", self.ast.expression_boolean_literal(true)),
+                );
+            } else {
+                serialized_slots.push(self.ast.object_property(slot_name, slot_fn));
+            }
+        }
+        if !serialized_slots.is_empty() {
+            props.push(
+                self.ast
+                    .object_property("$$slots", self.ast.expression_object(serialized_slots)),
+            );
+        }
+
+        let props = if props_and_spreads.is_empty() {
+            self.ast.expression_object(props)
+        } else {
+            if !props.is_empty() {
+                props_and_spreads.push(self.ast.expression_object(props));
+            }
+            self.ast.expression_call_with_atom(
+                "$.spread_props",
+                self.ast.vec([self
+                    .ast
+                    .expression_array(
+                        self.ast
+                            .vec_from_iter(props_and_spreads.into_iter().map(Into::into)),
+                    )
+                    .into()]),
+            )
+        };
+
+        let args = self.ast.vec([
+            self.ast.expression_identifier_reference("$$payload").into(),
+            props.into(),
+        ]);
+        let call = if optional {
+            self.ast.expression_optional_call(callee, args)
+        } else {
+            self.ast.expression_call(callee, args)
+        };
+        let mut stmt = self.ast.statement_expression(call);
+        if !snippet_declarations.is_empty() {
+            snippet_declarations.push(stmt);
+            stmt = self.ast.statement_block(snippet_declarations);
+        }
+        self.push_statement(stmt);
+        self.push_empty_comment();
+    }
+
+    fn transform_tag(&mut self, tag: Tag<'a>) {
+        match tag {
+            Tag::ExpressionTag(mut tag) => match tag.get_static_value() {
+                Some(value) => self.push_string(escape_html(value, false)),
+                None => {
+                    let expr = self.ast.move_expression(&mut tag.expression);
+                    self.push_expression(
+                        self.ast
+                            .expression_call_with_atom("$.escape", self.ast.vec([expr.into()])),
+                    );
+                }
+            },
+            Tag::HtmlTag(mut tag) => {
+                let expr = self.ast.move_expression(&mut tag.expression);
+                self.push_string(EMPTY_COMMENT);
+                self.push_expression(
+                    self.ast
+                        .expression_call_with_atom("$.html", self.ast.vec([expr.into()])),
+                );
+                self.push_string(EMPTY_COMMENT);
+            }
+            Tag::ConstTag(tag) => {
+                let declaration = Statement::VariableDeclaration(self.ast.alloc(tag.declaration));
+                self.state.init.push(declaration);
+            }
+            Tag::DebugTag(tag) => {
+                if !self.base.options.dev {
+                    return;
+                }
+                // console.log({ a, b }); debugger;
+                let props = self.ast.vec_from_iter(
+                    tag.identifiers
+                        .iter()
+                        .map(|ident| self.ast.object_property_shorthand(&ident.name)),
+                );
+                self.push_statement(self.ast.statement_expression(
+                    self.ast.expression_call_with_atom(
+                        "console.log",
+                        self.ast.vec([self.ast.expression_object(props).into()]),
+                    ),
+                ));
+                self.push_statement(self.ast.statement_debugger());
+            }
+            Tag::RenderTag(tag) => {
+                // snippet($$payload, ...args)
+                let payload = Argument::from(self.ast.expression_identifier_reference("$$payload"));
+                let expr = match tag.expression {
+                    RenderTagExpression::CallExpression(mut call) => {
+                        call.arguments.insert(0, payload);
+                        Expression::CallExpression(self.ast.alloc(call))
+                    }
+                    RenderTagExpression::ChainExpression(mut chain) => {
+                        if let ChainElement::CallExpression(call) = &mut chain.expression {
+                            call.arguments.insert(0, payload);
+                        }
+                        Expression::ChainExpression(self.ast.alloc(chain))
+                    }
+                };
+                self.push_statement(self.ast.statement_expression(expr));
+                self.push_empty_comment();
+            }
+        }
+    }
+
+    fn transform_block(&mut self, block: Block<'a>) {
+        match block {
+            Block::IfBlock(block) => self.transform_if_block(block),
+            Block::EachBlock(block) => self.transform_each_block(block),
+            Block::AwaitBlock(block) => self.transform_await_block(block),
+            Block::KeyBlock(mut block) => {
+                let body = self.transform_fragment(SvelteAstType::KeyBlock, &mut block.fragment);
+                self.push_string(EMPTY_COMMENT);
+                self.push_statement(self.ast.statement_block(body));
+                self.push_string(EMPTY_COMMENT);
+            }
+            Block::SnippetBlock(block) => {
+                let snippet = self.build_snippet(block);
+                self.state.init.push(snippet);
+            }
+        }
+    }
+
+    fn transform_if_block(&mut self, mut block: IfBlock<'a>) {
+        let test = self.ast.move_expression(&mut block.test);
+        let mut consequent = self.transform_fragment(SvelteAstType::IfBlock, &mut block.consequent);
+        consequent.insert(0, self.build_payload_out_literal(BLOCK_OPEN));
+        let mut alternate = match block.alternate.as_mut() {
+            Some(alternate) => self.transform_fragment(SvelteAstType::IfBlock, alternate),
+            None => self.ast.vec([]),
+        };
+        alternate.insert(0, self.build_payload_out_literal(BLOCK_OPEN_ELSE));
+
+        self.push_statement(self.ast.statement_if(
+            test,
+            consequent,
+            Some(self.ast.statement_block(alternate)),
+        ));
+        self.push_string(BLOCK_CLOSE);
+    }
+
+    /// ```js
+    /// const each_array = $.ensure_array_like(collection);
+    /// for (let $$index = 0, $$length = each_array.length; $$index < $$length; $$index++) {
+    ///     let item = each_array[$$index];
+    ///     ...
+    /// }
+    /// ```
+    fn transform_each_block(&mut self, mut block: EachBlock<'a>) {
+        let array_id = self.base.scopes.unique("each_array");
+        let index = match block.index {
+            Some(index) => index.into(),
+            None => self.base.scopes.unique("$$index"),
+        };
+        let collection = self.ast.move_expression(&mut block.expression);
+        self.state.init.push(self.ast.statement_const(
+            self.ast.binding_pattern_identifier(array_id.as_str()),
+            self.ast.expression_call_with_atom(
+                "$.ensure_array_like",
+                self.ast.vec([collection.into()]),
+            ),
+        ));
+
+        let mut each = self.ast.vec([self.ast.statement_let(
+            block.context,
+            self.ast.expression_computed_member(
+                self.ast.expression_identifier_reference(&array_id),
+                self.ast.expression_identifier_reference(&index),
+            ),
+        )]);
+        each.extend(self.transform_fragment(SvelteAstType::EachBlock, &mut block.body));
+        let ast = self.ast;
+        let array_length = || {
+            ast.expression_static_member(ast.expression_identifier_reference(&array_id), "length")
+        };
+        let for_loop = self.ast.statement_for(
+            self.ast.let_declaration([
+                (
+                    self.ast.binding_pattern_identifier(index.as_str()),
+                    self.ast.expression_numeric_literal(0.0),
+                ),
+                (
+                    self.ast.binding_pattern_identifier("$$length"),
+                    array_length(),
+                ),
+            ]),
+            self.ast.expression_binary(
+                self.ast.expression_identifier_reference(&index),
+                BinaryOperator::LessThan,
+                self.ast.expression_identifier_reference("$$length"),
+            ),
+            self.ast.expression_increment(&index),
+            each,
+        );
+
+        if let Some(mut fallback) = block.fallback {
+            let mut fallback = self.transform_fragment(SvelteAstType::EachBlock, &mut fallback);
+            fallback.insert(0, self.build_payload_out_literal(BLOCK_OPEN_ELSE));
+            let consequent = self
+                .ast
+                .vec([self.build_payload_out_literal(BLOCK_OPEN), for_loop]);
+            self.push_statement(self.ast.statement_if(
+                self.ast.expression_binary(
+                    array_length(),
+                    BinaryOperator::StrictInequality,
+                    self.ast.expression_numeric_literal(0.0),
+                ),
+                consequent,
+                Some(self.ast.statement_block(fallback)),
+            ));
+        } else {
+            self.push_string(BLOCK_OPEN);
+            self.push_statement(for_loop);
+        }
+        self.push_string(BLOCK_CLOSE);
+    }
+
+    /// ```js
+    /// $.await(promise, () => { pending }, (value) => { then }, (error) => { catch })
+    /// ```
+    fn transform_await_block(&mut self, mut block: AwaitBlock<'a>) {
+        let expression = self.ast.move_expression(&mut block.expression);
+        let pending = match block.pending.as_mut() {
+            Some(pending) => self.transform_fragment(SvelteAstType::AwaitBlock, pending),
+            None => self.ast.vec([]),
+        };
+        let then = match block.then.as_mut() {
+            Some(then) => self.transform_fragment(SvelteAstType::AwaitBlock, then),
+            None => self.ast.vec([]),
+        };
+        let catch = match block.catch.as_mut() {
+            Some(catch) => self.transform_fragment(SvelteAstType::AwaitBlock, catch),
+            None => self.ast.vec([]),
+        };
+        let then_params = self
+            .ast
+            .vec_from_iter(block.value.map(|value| self.ast.formal_parameter(value)));
+        let catch_params = self
+            .ast
+            .vec_from_iter(block.error.map(|error| self.ast.formal_parameter(error)));
+
+        self.push_string(EMPTY_COMMENT);
+        self.push_statement(
+            self.ast
+                .statement_expression(self.ast.expression_call_with_atom(
+                    "$.await",
+                    self.ast.vec([
+                        expression.into(),
+                        self.build_thunk(pending).into(),
+                        self.ast.expression_arrow(then_params, then).into(),
+                        self.ast.expression_arrow(catch_params, catch).into(),
+                    ]),
+                )),
+        );
+        self.push_string(EMPTY_COMMENT);
+    }
+
+    /// ```js
+    /// function snippet($$payload, ...params) { ... }
+    /// ```
+    fn build_snippet(&mut self, mut block: SnippetBlock<'a>) -> Statement<'a> {
+        let mut params = self.ast.vec([self
+            .ast
+            .formal_parameter(self.ast.binding_pattern_identifier("$$payload"))]);
+        params.extend(
+            block
+                .parameters
+                .into_iter()
+                .map(|param| self.ast.formal_parameter(param)),
+        );
+        let body = self.transform_fragment(SvelteAstType::SnippetBlock, &mut block.body);
+        self.ast
+            .statement_function_declaration(self.ast.function_declaration(
+                block.expression.name,
+                params,
+                body,
+            ))
+    }
+
+    /// `($$payload) => { ... }`
+    fn build_payload_arrow(&self, body: OxcVec<'a, Statement<'a>>) -> Expression<'a> {
+        self.ast.expression_arrow(
+            self.ast.vec([self
+                .ast
+                .formal_parameter(self.ast.binding_pattern_identifier("$$payload"))]),
+            body,
+        )
+    }
+
+    /// `() => { ... }`
+    fn build_thunk(&self, body: OxcVec<'a, Statement<'a>>) -> Expression<'a> {
+        self.ast.expression_arrow(self.ast.vec([]), body)
+    }
+}
+
+/// An attribute of an element, the value of which is rendered as is when it is known at
+/// compile time.
+enum ElementAttribute<'a> {
+    /// `name`
+    True(&'a str),
+    /// `name="text"`
+    Text(&'a str, String),
+    /// `name={value}`
+    Expression(&'a str, Expression<'a>),
+    /// `{...attributes}`
+    Spread(Expression<'a>),
+}
+
+impl<'a> ElementAttribute<'a> {
+    fn name(&self) -> Option<&'a str> {
+        match self {
+            Self::True(name) | Self::Text(name, _) | Self::Expression(name, _) => Some(name),
+            Self::Spread(_) => None,
+        }
+    }
+
+    fn into_value(self, ast: AstBuilder<'a>) -> Expression<'a> {
+        match self {
+            Self::True(_) => ast.expression_boolean_literal(true),
+            Self::Text(_, value) => ast.expression_string_literal(value.as_str()),
+            Self::Expression(_, value) | Self::Spread(value) => value,
+        }
+    }
+}
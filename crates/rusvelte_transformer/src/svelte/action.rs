@@ -0,0 +1,50 @@
+use rusvelte_ast::{ast::*, js_ast::Statement, visit_mut::JsVisitMut};
+
+use crate::Transformer;
+
+impl<'a> Transformer<'a> {
+    /// ```js
+    /// $.action(div, ($$node, $$params) => tooltip($$node, $$params), () => $.get(text));
+    /// ```
+    /// The parameters are read in an effect, so the `update` of the action is called when
+    /// the state they depend on changes.
+    pub(super) fn build_use_directive(
+        &mut self,
+        directive: &mut UseDirective<'a>,
+    ) -> Statement<'a> {
+        let mut params = vec!["$$node"];
+        if directive.expression.is_some() {
+            params.push("$$params");
+        }
+        let action = self.build_directive_name(directive.name);
+        let call = self.ast.expression_call(
+            action,
+            self.ast.vec_from_iter(
+                params
+                    .iter()
+                    .map(|param| self.ast.expression_identifier_reference(param).into()),
+            ),
+        );
+        let mut args = self.ast.vec([
+            self.ast
+                .expression_identifier_reference(&self.state.node)
+                .into(),
+            self.ast
+                .expression_arrow(
+                    self.ast.vec_from_iter(params.iter().map(|param| {
+                        self.ast
+                            .formal_parameter(self.ast.binding_pattern_identifier(*param))
+                    })),
+                    self.ast.vec([self.ast.statement_expression(call)]),
+                )
+                .into(),
+        ]);
+        if let Some(expression) = directive.expression.as_mut() {
+            self.visit_expression(expression);
+            let value = self.ast.move_expression(expression);
+            args.push(self.build_thunk(&[], value).into());
+        }
+        self.ast
+            .statement_expression(self.ast.expression_call_with_atom("$.action", args))
+    }
+}
@@ -0,0 +1,277 @@
+use oxc_allocator::CloneIn;
+use oxc_span::CompactStr;
+use rusvelte_ast::{
+    ast::*,
+    js_ast::{Argument, Expression, Statement},
+    visit_mut::JsVisitMut,
+};
+use rusvelte_utils::binding_property::get_binding_property;
+
+use crate::Transformer;
+
+impl<'a> Transformer<'a> {
+    /// ```js
+    /// $.bind_value(input, () => $.get(value), ($$value) => $.set(value, $$value));
+    /// ```
+    /// `group_value` holds the expressions of the `value` attribute read by `bind:group`.
+    pub(super) fn build_bind_directive(
+        &mut self,
+        element_name: &str,
+        group_value: Vec<Expression<'a>>,
+        directive: &mut BindDirective<'a>,
+    ) -> Statement<'a> {
+        let node = self.ast.expression_identifier_reference(&self.state.node);
+        let name = directive.name;
+        if name == "this" {
+            let call = self.build_bind_this(node, &mut directive.expression);
+            return self.ast.statement_expression(call);
+        }
+
+        let group_id = (name == "group").then(|| self.binding_group_id(&directive.expression));
+        // `() => { value; return $.get(group); }` so that the group is updated along with the
+        // value of the input
+        let group_get = (!group_value.is_empty()).then(|| {
+            let mut body = self.ast.vec([]);
+            for mut expression in group_value {
+                self.visit_expression(&mut expression);
+                body.push(self.ast.statement_expression(expression));
+            }
+            let mut expression = directive.expression.clone_in(self.allocator);
+            self.visit_expression(&mut expression);
+            body.push(self.ast.statement_return(expression));
+            self.ast.expression_arrow_block(self.ast.vec([]), body)
+        });
+        let (mut get, set) = self.build_binding_accessors(&mut directive.expression);
+        if let Some(group_get) = group_get {
+            get = group_get;
+        }
+        let (callee, args) = match name {
+            "value" if element_name == "select" => {
+                ("$.bind_select_value", self.ast.vec([node, get, set]))
+            }
+            "value" => ("$.bind_value", self.ast.vec([node, get, set])),
+            "checked" => ("$.bind_checked", self.ast.vec([node, get, set])),
+            "files" => ("$.bind_files", self.ast.vec([node, get, set])),
+            "group" => {
+                let group_id = group_id.unwrap();
+                (
+                    "$.bind_group",
+                    self.ast.vec([
+                        self.ast.expression_identifier_reference(&group_id),
+                        self.build_each_block_indexes(),
+                        node,
+                        get,
+                        set,
+                    ]),
+                )
+            }
+            "focused" => ("$.bind_focused", self.ast.vec([node, set])),
+            "innerText" | "innerHTML" | "textContent" => (
+                "$.bind_content_editable",
+                self.ast
+                    .vec([self.ast.expression_string_literal(name), node, get, set]),
+            ),
+            "clientWidth" | "clientHeight" | "offsetWidth" | "offsetHeight" => (
+                "$.bind_element_size",
+                self.ast
+                    .vec([node, self.ast.expression_string_literal(name), set]),
+            ),
+            "contentRect" | "contentBoxSize" | "borderBoxSize" | "devicePixelContentBoxSize" => (
+                "$.bind_resize_observer",
+                self.ast
+                    .vec([node, self.ast.expression_string_literal(name), set]),
+            ),
+            "currentTime" => ("$.bind_current_time", self.ast.vec([node, get, set])),
+            "playbackRate" => ("$.bind_playback_rate", self.ast.vec([node, get, set])),
+            "paused" => ("$.bind_paused", self.ast.vec([node, get, set])),
+            "volume" => ("$.bind_volume", self.ast.vec([node, get, set])),
+            "muted" => ("$.bind_muted", self.ast.vec([node, get, set])),
+            "buffered" => ("$.bind_buffered", self.ast.vec([node, set])),
+            "seekable" => ("$.bind_seekable", self.ast.vec([node, set])),
+            "played" => ("$.bind_played", self.ast.vec([node, set])),
+            "seeking" => ("$.bind_seeking", self.ast.vec([node, set])),
+            "ended" => ("$.bind_ended", self.ast.vec([node, set])),
+            "readyState" => ("$.bind_ready_state", self.ast.vec([node, set])),
+            // `$.bind_property("open", "toggle", details, set, get)`
+            name => {
+                let property = get_binding_property(name)
+                    .filter(|property| property.event.is_some())
+                    .expect("the analyzer rejects the bindings the element doesn't support");
+                let mut args = self.ast.vec([
+                    self.ast.expression_string_literal(name),
+                    self.ast.expression_string_literal(property.event.unwrap()),
+                    node,
+                    set,
+                ]);
+                if property.bidirectional {
+                    args.push(get);
+                }
+                ("$.bind_property", args)
+            }
+        };
+
+        self.ast
+            .statement_expression(self.ast.expression_call_with_atom(
+                callee,
+                self.ast.vec_from_iter(args.into_iter().map(Into::into)),
+            ))
+    }
+
+    /// `$.bind_this(value, ($$value) => element = $$value, () => element)`
+    pub(super) fn build_bind_this(
+        &mut self,
+        value: Expression<'a>,
+        expression: &mut Expression<'a>,
+    ) -> Expression<'a> {
+        let (get, set) = self.build_binding_accessors(expression);
+        self.ast.expression_call_with_atom(
+            "$.bind_this",
+            self.ast.vec([value.into(), set.into(), get.into()]),
+        )
+    }
+
+    /// `expression = $$value`, transformed like any other assignment, e.g. into
+    /// `$.set(value, $$value)` for a state
+    pub(super) fn build_binding_assignment(
+        &mut self,
+        expression: &Expression<'a>,
+    ) -> Expression<'a> {
+        let mut assignment = self.ast.expression_assignment(
+            expression.clone_in(self.allocator),
+            self.ast.expression_identifier_reference("$$value"),
+        );
+        self.visit_expression(&mut assignment);
+        assignment
+    }
+
+    /// Returns the getter and the setter of the bound expression
+    fn build_binding_accessors(
+        &mut self,
+        expression: &mut Expression<'a>,
+    ) -> (Expression<'a>, Expression<'a>) {
+        let assignment = self.build_binding_assignment(expression);
+        let set = self.build_thunk(&["$$value"], assignment);
+        self.visit_expression(expression);
+        let get = self.build_thunk(&[], self.ast.move_expression(expression));
+        (get, set)
+    }
+
+    /// `(params) => body`, or `f` itself when the body is `f(params)`, e.g. for a prop
+    pub(super) fn build_thunk(&self, params: &[&str], body: Expression<'a>) -> Expression<'a> {
+        if let Expression::CallExpression(call) = &body {
+            // `$.get(value)` is a call on `$`, which can't be passed as is
+            let forwards_params =
+                match &call.callee {
+                    Expression::Identifier(callee) => {
+                        !callee.name.contains('.')
+                            && call.arguments.len() == params.len()
+                            && call.arguments.iter().zip(params).all(|(argument, param)| {
+                                match argument {
+                                    Argument::Identifier(ident) => ident.name.as_str() == *param,
+                                    _ => false,
+                                }
+                            })
+                    }
+                    _ => false,
+                };
+            if forwards_params {
+                let Expression::CallExpression(call) = body else {
+                    unreachable!()
+                };
+                return call.unbox().callee;
+            }
+        }
+        let params = self.ast.vec_from_iter(params.iter().map(|param| {
+            self.ast
+                .formal_parameter(self.ast.binding_pattern_identifier(*param))
+        }));
+        self.ast
+            .expression_arrow(params, self.ast.vec([self.ast.statement_expression(body)]))
+    }
+
+    /// The array shared by the inputs bound to the same value with `bind:group`, declared at
+    /// the top of the component
+    /// `[i, $.get(j)]`, the indexes of the enclosing each blocks from the outermost
+    fn build_each_block_indexes(&mut self) -> Expression<'a> {
+        for (name, _) in self.each_block_indexes.iter_mut() {
+            if name.is_none() {
+                *name = Some(self.scopes.unique("$$index"));
+            }
+        }
+        let indexes = self
+            .each_block_indexes
+            .iter()
+            .map(|(name, is_reactive)| {
+                let index = self
+                    .ast
+                    .expression_identifier_reference(name.as_ref().unwrap());
+                if *is_reactive {
+                    self.ast
+                        .expression_call_with_atom("$.get", self.ast.vec([index.into()]))
+                } else {
+                    index
+                }
+            })
+            .map(Into::into);
+        self.ast.expression_array(self.ast.vec_from_iter(indexes))
+    }
+
+    /// The expressions of the `value` attribute of an input with a `bind:group`
+    pub(super) fn collect_group_value(&self, attributes: &[Attribute<'a>]) -> Vec<Expression<'a>> {
+        let has_group_binding = attributes.iter().any(|attribute| {
+            matches!(
+                attribute,
+                Attribute::Directive(Directive::BindDirective(directive)) if directive.name == "group"
+            )
+        });
+        let mut expressions = vec![];
+        for attribute in attributes.iter().filter(|_| has_group_binding) {
+            let Attribute::NormalAttribute(attribute) = attribute else {
+                continue;
+            };
+            if attribute.name != "value" {
+                continue;
+            }
+            match &attribute.value {
+                AttributeValue::ExpressionTag(tag) => {
+                    expressions.push(tag.expression.clone_in(self.allocator));
+                }
+                AttributeValue::Quoted(values) => {
+                    for value in values.iter() {
+                        if let QuotedAttributeValue::ExpressionTag(tag) = value {
+                            expressions.push(tag.expression.clone_in(self.allocator));
+                        }
+                    }
+                }
+                AttributeValue::True => {}
+            }
+        }
+        expressions
+    }
+
+    fn binding_group_id(&mut self, expression: &Expression<'a>) -> CompactStr {
+        let keypath = binding_keypath(expression);
+        if let Some((_, id)) = self
+            .binding_groups
+            .iter()
+            .find(|(group_keypath, _)| keypath.is_some() && *group_keypath == keypath)
+        {
+            return id.clone();
+        }
+        let id = self
+            .scopes
+            .generate("binding_group", self.scopes.root_scope_id());
+        self.binding_groups.push((keypath, id.clone()));
+        id
+    }
+}
+
+/// `a.b.c` for the expression `a.b.c`, `None` if it isn't a chain of static members
+fn binding_keypath(expression: &Expression) -> Option<String> {
+    match expression {
+        Expression::Identifier(ident) => Some(ident.name.to_string()),
+        Expression::StaticMemberExpression(member) => binding_keypath(&member.object)
+            .map(|object| format!("{object}.{}", member.property.name)),
+        _ => None,
+    }
+}
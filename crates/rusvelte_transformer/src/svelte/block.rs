@@ -0,0 +1,615 @@
+use oxc_allocator::{CloneIn, Vec as OxcVec};
+use oxc_ecmascript::BoundNames;
+use oxc_span::Atom;
+use rusvelte_analyzer::{ScopeId, SymbolId};
+use rusvelte_ast::{
+    ast::*,
+    ast_kind::SvelteAstType,
+    js_ast::{BindingPattern, BindingPatternKind, Expression, PropertyKey, Statement},
+    visit_mut::JsVisitMut,
+};
+use rusvelte_utils::constants::{
+    EACH_INDEX_REACTIVE, EACH_IS_ANIMATED, EACH_IS_CONTROLLED, EACH_ITEM_IMMUTABLE,
+    EACH_ITEM_REACTIVE,
+};
+
+use crate::{ReadTransform, Transformer};
+
+/// A binding declared by a destructured `{#each}` context.
+struct EachPath<'a> {
+    name: Atom<'a>,
+    symbol_id: Option<SymbolId>,
+    /// Reads the binding from the item
+    expression: Expression<'a>,
+    has_default_value: bool,
+}
+
+impl<'a> Transformer<'a> {
+    /// ```js
+    /// {
+    ///     var consequent = ($$anchor) => { ... };
+    ///     var alternate = ($$anchor) => { ... };
+    ///     $.if(node, ($$render) => {
+    ///         if (test) $$render(consequent);
+    ///         else $$render(alternate, false);
+    ///     });
+    /// }
+    /// ```
+    pub(crate) fn transform_if_block(&mut self, it: &mut IfBlock<'a>) -> OxcVec<'a, Statement<'a>> {
+        self.state.template.push("<!>".to_string());
+        let mut statements = self.ast.vec([]);
+
+        let consequent = self.transform_fragment(SvelteAstType::IfBlock, &mut it.consequent);
+        let consequent_id = self.scopes.generate("consequent", self.current_scope_id);
+        statements.push(self.ast.statement_var(
+            self.ast.binding_pattern_identifier(consequent_id.as_str()),
+            self.build_anchor_arrow(false, consequent),
+        ));
+
+        let mut alternate_id = None;
+        if let Some(alternate) = it.alternate.as_mut() {
+            let id = self.scopes.generate("alternate", self.current_scope_id);
+            // `{:else if ...}` is an alternate fragment with a single if block
+            let is_elseif = matches!(
+                &alternate.nodes[..],
+                [FragmentNode::Block(Block::IfBlock(block))] if block.elseif
+            );
+            let alternate = self.transform_fragment(SvelteAstType::IfBlock, alternate);
+            statements.push(self.ast.statement_var(
+                self.ast.binding_pattern_identifier(id.as_str()),
+                self.build_anchor_arrow(is_elseif, alternate),
+            ));
+            alternate_id = Some(id);
+        }
+
+        self.visit_expression(&mut it.test);
+        let test = self.ast.move_expression(&mut it.test);
+        let ast = self.ast;
+        let render =
+            |args| ast.statement_expression(ast.expression_call_with_atom("$$render", args));
+        let if_stmt = self.ast.statement_if_unbraced(
+            test,
+            render(
+                self.ast.vec([self
+                    .ast
+                    .expression_identifier_reference(&consequent_id)
+                    .into()]),
+            ),
+            alternate_id.map(|id| {
+                render(self.ast.vec([
+                    self.ast.expression_identifier_reference(&id).into(),
+                    self.ast.expression_boolean_literal(false).into(),
+                ]))
+            }),
+        );
+
+        let mut args = self.ast.vec([
+            self.ast
+                .expression_identifier_reference(&self.state.node)
+                .into(),
+            self.ast
+                .expression_arrow(
+                    self.ast.vec([self
+                        .ast
+                        .formal_parameter(self.ast.binding_pattern_identifier("$$render"))]),
+                    self.ast.vec([if_stmt]),
+                )
+                .into(),
+        ]);
+        if it.elseif {
+            args.push(self.ast.expression_identifier_reference("$$elseif").into());
+        }
+        statements.push(
+            self.ast
+                .statement_expression(self.ast.expression_call_with_atom("$.if", args)),
+        );
+
+        self.ast.vec([self.ast.statement_block(statements)])
+    }
+
+    /// ```js
+    /// $.key(node, () => key, ($$anchor) => { ... });
+    /// ```
+    pub(crate) fn transform_key_block(
+        &mut self,
+        it: &mut KeyBlock<'a>,
+    ) -> OxcVec<'a, Statement<'a>> {
+        self.state.template.push("<!>".to_string());
+        self.visit_expression(&mut it.expression);
+        let key = self.ast.expression_arrow(
+            self.ast.vec([]),
+            self.ast.vec([self
+                .ast
+                .statement_expression(self.ast.move_expression(&mut it.expression))]),
+        );
+        let body = self.transform_fragment(SvelteAstType::KeyBlock, &mut it.fragment);
+        let args = self.ast.vec([
+            self.ast
+                .expression_identifier_reference(&self.state.node)
+                .into(),
+            key.into(),
+            self.build_anchor_arrow(false, body).into(),
+        ]);
+        self.ast.vec([self
+            .ast
+            .statement_expression(self.ast.expression_call_with_atom("$.key", args))])
+    }
+
+    /// `($$anchor) => { ... }`, or `($$anchor, $$elseif) => { ... }` for the alternate
+    /// of an `{:else if ...}` chain.
+    fn build_anchor_arrow(
+        &self,
+        is_elseif: bool,
+        body: OxcVec<'a, Statement<'a>>,
+    ) -> Expression<'a> {
+        let mut params = self.ast.vec([self
+            .ast
+            .formal_parameter(self.ast.binding_pattern_identifier("$$anchor"))]);
+        if is_elseif {
+            params.push(
+                self.ast
+                    .formal_parameter(self.ast.binding_pattern_identifier("$$elseif")),
+            );
+        }
+        self.ast.expression_arrow(params, body)
+    }
+
+    /// ```js
+    /// $.each(node, flags, () => collection, key, ($$anchor, item, index) => { ... }, ($$anchor) => { ... });
+    /// ```
+    pub(crate) fn transform_each_block(
+        &mut self,
+        it: &mut EachBlock<'a>,
+    ) -> OxcVec<'a, Statement<'a>> {
+        self.visit_expression(&mut it.expression);
+        let collection = self.ast.move_expression(&mut it.expression);
+        if !it.is_controlled.get() {
+            self.state.template.push("<!>".to_string());
+        }
+
+        let is_keyed = it.is_keyed();
+        let key_is_item = matches!(
+            (&it.key, &it.context.kind),
+            (Some(Expression::Identifier(key)), BindingPatternKind::BindingIdentifier(context))
+                if key.name == context.name
+        );
+        let runes = self.runes;
+
+        let mut flags = 0;
+        if it.is_controlled.get() {
+            flags |= EACH_IS_CONTROLLED;
+        }
+        if is_keyed && it.index.is_some() {
+            flags |= EACH_INDEX_REACTIVE;
+        }
+        // the analyzer only allows `animate:` on the element directly inside a keyed block
+        let is_animated = it.body.nodes.iter().any(|node| match node {
+            FragmentNode::Element(element) => {
+                matches!(
+                    element.as_ref(),
+                    Element::RegularElement(_) | Element::SvelteElement(_)
+                ) && element.attributes().iter().any(|attribute| {
+                    matches!(
+                        attribute,
+                        Attribute::Directive(Directive::AnimateDirective(_))
+                    )
+                })
+            }
+            _ => false,
+        });
+        if is_keyed && is_animated {
+            flags |= EACH_IS_ANIMATED;
+        }
+        // In runes mode a keyed item only changes along with its key
+        if !it.expression_metadata.borrow().dependencies.is_empty() && (!runes || !key_is_item) {
+            flags |= EACH_ITEM_REACTIVE;
+        }
+        if runes {
+            flags |= EACH_ITEM_IMMUTABLE;
+        }
+
+        let parent_scope_id = self.current_scope_id;
+        if let Some(scope_id) = it.scope_id.get() {
+            self.current_scope_id = scope_id;
+        }
+
+        // The key is called with the plain item and index, so it is visited
+        // before their reads are transformed
+        let key_function = match it.key.as_mut() {
+            Some(key) if is_keyed => {
+                self.visit_expression(key);
+                let index = it
+                    .index
+                    .map_or_else(|| self.scopes.unique("$$index"), Into::into);
+                self.ast.expression_arrow(
+                    self.ast.vec([
+                        self.ast
+                            .formal_parameter(it.context.clone_in(self.allocator)),
+                        self.ast
+                            .formal_parameter(self.ast.binding_pattern_identifier(index.as_str())),
+                    ]),
+                    self.ast
+                        .vec([self.ast.statement_expression(self.ast.move_expression(key))]),
+                )
+            }
+            _ => self.ast.expression_identifier_reference("$.index"),
+        };
+
+        let item_is_reactive = flags & EACH_ITEM_REACTIVE != 0;
+        let mut body = self.ast.vec([]);
+        let item = match &it.context.kind {
+            BindingPatternKind::BindingIdentifier(ident) => {
+                if let Some(symbol_id) = ident.symbol_id.get().filter(|_| item_is_reactive) {
+                    self.read_transforms.insert(symbol_id, ReadTransform::Get);
+                }
+                ident.name.as_str()
+            }
+            _ => {
+                let mut unwrapped = self.ast.expression_identifier_reference("$$item");
+                if item_is_reactive {
+                    unwrapped = self
+                        .ast
+                        .expression_call_with_atom("$.get", self.ast.vec([unwrapped.into()]));
+                }
+                self.build_destructured_declarations(&it.context, unwrapped, &mut body);
+                "$$item"
+            }
+        };
+
+        if let Some(index) = it.index.filter(|_| flags & EACH_INDEX_REACTIVE != 0) {
+            if let Some(symbol_id) = self.scopes.find_symbol_id(self.current_scope_id, index) {
+                self.read_transforms.insert(symbol_id, ReadTransform::Get);
+            }
+        }
+
+        // `bind:group` reads the index, which is only named when the block doesn't declare it
+        self.each_block_indexes
+            .push((it.index.map(Into::into), flags & EACH_INDEX_REACTIVE != 0));
+        body.extend(self.transform_fragment(SvelteAstType::EachBlock, &mut it.body));
+        let (index, _) = self.each_block_indexes.pop().unwrap();
+        let mut params = self.ast.vec([
+            self.ast
+                .formal_parameter(self.ast.binding_pattern_identifier("$$anchor")),
+            self.ast
+                .formal_parameter(self.ast.binding_pattern_identifier(item)),
+        ]);
+        if let Some(index) = index {
+            params.push(
+                self.ast
+                    .formal_parameter(self.ast.binding_pattern_identifier(index.as_str())),
+            );
+        }
+        self.current_scope_id = parent_scope_id;
+
+        let mut args = self.ast.vec([
+            self.ast
+                .expression_identifier_reference(&self.state.node)
+                .into(),
+            self.ast.expression_numeric_literal(flags as f64).into(),
+            self.ast
+                .expression_arrow(
+                    self.ast.vec([]),
+                    self.ast.vec([self.ast.statement_expression(collection)]),
+                )
+                .into(),
+            key_function.into(),
+            self.ast.expression_arrow(params, body).into(),
+        ]);
+        if let Some(fallback) = it.fallback.as_mut() {
+            let fallback = self.transform_fragment(SvelteAstType::EachBlock, fallback);
+            args.push(self.build_anchor_arrow(false, fallback).into());
+        }
+
+        self.ast.vec([self
+            .ast
+            .statement_expression(self.ast.expression_call_with_atom("$.each", args))])
+    }
+
+    /// Declares a thunk for every binding of a destructuring `pattern`, reading it from `value`.
+    /// The bindings are read by calling the thunk, or with `$.get` when a default value has to
+    /// be evaluated only once.
+    pub(crate) fn build_destructured_declarations(
+        &mut self,
+        pattern: &BindingPattern<'a>,
+        value: Expression<'a>,
+        declarations: &mut OxcVec<'a, Statement<'a>>,
+    ) {
+        let mut paths = vec![];
+        self.extract_paths(pattern, value, false, &mut paths);
+        for path in paths {
+            let mut expression = path.expression;
+            self.visit_expression(&mut expression);
+            let thunk = self.ast.expression_arrow(
+                self.ast.vec([]),
+                self.ast.vec([self.ast.statement_expression(expression)]),
+            );
+            let (init, read_transform) = if path.has_default_value {
+                let derived = self.ast.expression_call_with_atom(
+                    "$.derived_safe_equal",
+                    self.ast.vec([thunk.into()]),
+                );
+                (derived, ReadTransform::Get)
+            } else {
+                (thunk, ReadTransform::Call)
+            };
+            declarations.push(self.ast.statement_let(
+                self.ast.binding_pattern_identifier(path.name.as_str()),
+                init,
+            ));
+            if let Some(symbol_id) = path.symbol_id {
+                self.read_transforms.insert(symbol_id, read_transform);
+            }
+        }
+    }
+
+    /// Collects the bindings declared by a destructuring `pattern` together
+    /// with the expressions reading them from `value`.
+    fn extract_paths(
+        &self,
+        pattern: &BindingPattern<'a>,
+        value: Expression<'a>,
+        has_default_value: bool,
+        paths: &mut Vec<EachPath<'a>>,
+    ) {
+        match &pattern.kind {
+            BindingPatternKind::BindingIdentifier(ident) => paths.push(EachPath {
+                name: ident.name.clone(),
+                symbol_id: ident.symbol_id.get(),
+                expression: value,
+                has_default_value,
+            }),
+            BindingPatternKind::ObjectPattern(object) => {
+                let mut keys = self.ast.vec([]);
+                for property in &object.properties {
+                    let expression = match &property.key {
+                        PropertyKey::StaticIdentifier(key) => {
+                            keys.push(self.ast.expression_string_literal(key.name.as_str()).into());
+                            self.ast
+                                .expression_static_member(value.clone_in(self.allocator), &key.name)
+                        }
+                        key => {
+                            let key = key
+                                .as_expression()
+                                .expect("binding pattern keys are identifiers or expressions");
+                            if !property.computed {
+                                keys.push(key.clone_in(self.allocator).into());
+                            }
+                            self.ast.expression_computed_member(
+                                value.clone_in(self.allocator),
+                                key.clone_in(self.allocator),
+                            )
+                        }
+                    };
+                    self.extract_paths(&property.value, expression, has_default_value, paths);
+                }
+                if let Some(rest) = &object.rest {
+                    let expression = self.ast.expression_call_with_atom(
+                        "$.exclude_from_object",
+                        self.ast
+                            .vec([value.into(), self.ast.expression_array(keys).into()]),
+                    );
+                    self.extract_paths(&rest.argument, expression, has_default_value, paths);
+                }
+            }
+            BindingPatternKind::ArrayPattern(array) => {
+                for (i, element) in array.elements.iter().enumerate() {
+                    let Some(element) = element else { continue };
+                    let expression = self.ast.expression_computed_member(
+                        value.clone_in(self.allocator),
+                        self.ast.expression_numeric_literal(i as f64),
+                    );
+                    self.extract_paths(element, expression, has_default_value, paths);
+                }
+                if let Some(rest) = &array.rest {
+                    let expression = self.ast.expression_call(
+                        self.ast.expression_static_member(value, "slice"),
+                        self.ast.vec([self
+                            .ast
+                            .expression_numeric_literal(array.elements.len() as f64)
+                            .into()]),
+                    );
+                    self.extract_paths(&rest.argument, expression, has_default_value, paths);
+                }
+            }
+            BindingPatternKind::AssignmentPattern(assignment) => {
+                let fallback = assignment.right.clone_in(self.allocator);
+                let is_simple = fallback.is_literal()
+                    || matches!(
+                        fallback,
+                        Expression::Identifier(_)
+                            | Expression::ArrowFunctionExpression(_)
+                            | Expression::FunctionExpression(_)
+                    );
+                let args = if is_simple {
+                    self.ast.vec([value.into(), fallback.into()])
+                } else {
+                    // `$.fallback(value, () => fallback, true)` only evaluates the default when needed
+                    let thunk = self.ast.expression_arrow(
+                        self.ast.vec([]),
+                        self.ast.vec([self.ast.statement_expression(fallback)]),
+                    );
+                    self.ast.vec([
+                        value.into(),
+                        thunk.into(),
+                        self.ast.expression_boolean_literal(true).into(),
+                    ])
+                };
+                let expression = self.ast.expression_call_with_atom("$.fallback", args);
+                self.extract_paths(&assignment.left, expression, true, paths);
+            }
+        }
+    }
+
+    /// ```js
+    /// $.await(node, () => promise, ($$anchor) => { ... }, ($$anchor, value) => { ... }, ($$anchor, error) => { ... });
+    /// ```
+    pub(crate) fn transform_await_block(
+        &mut self,
+        it: &mut AwaitBlock<'a>,
+    ) -> OxcVec<'a, Statement<'a>> {
+        self.state.template.push("<!>".to_string());
+        self.visit_expression(&mut it.expression);
+        let expression = self.ast.expression_arrow(
+            self.ast.vec([]),
+            self.ast.vec([self
+                .ast
+                .statement_expression(self.ast.move_expression(&mut it.expression))]),
+        );
+
+        let then_block = match it.then.as_mut() {
+            Some(then) => {
+                Some(self.build_await_branch(it.then_scope_id.get(), it.value.as_ref(), then))
+            }
+            None => None,
+        };
+        let catch_block = match it.catch.as_mut() {
+            Some(catch) => {
+                Some(self.build_await_branch(it.catch_scope_id.get(), it.error.as_ref(), catch))
+            }
+            None => None,
+        };
+        let pending_block = match it.pending.as_mut() {
+            Some(pending) => {
+                let pending = self.transform_fragment(SvelteAstType::AwaitBlock, pending);
+                self.build_anchor_arrow(false, pending)
+            }
+            None => self.ast.expression_null(),
+        };
+
+        let mut args = self.ast.vec([
+            self.ast
+                .expression_identifier_reference(&self.state.node)
+                .into(),
+            expression.into(),
+            pending_block.into(),
+        ]);
+        match (then_block, catch_block) {
+            (then_block, Some(catch_block)) => {
+                let then_block = then_block
+                    .unwrap_or_else(|| self.ast.expression_identifier_reference("undefined"));
+                args.push(then_block.into());
+                args.push(catch_block.into());
+            }
+            (Some(then_block), None) => args.push(then_block.into()),
+            (None, None) => {}
+        }
+
+        self.ast.vec([self
+            .ast
+            .statement_expression(self.ast.expression_call_with_atom("$.await", args))])
+    }
+
+    /// `($$anchor, value) => { ... }` for the `then` and `catch` branches of an `{#await}` block
+    fn build_await_branch(
+        &mut self,
+        scope_id: Option<ScopeId>,
+        pattern: Option<&BindingPattern<'a>>,
+        fragment: &mut Fragment<'a>,
+    ) -> Expression<'a> {
+        let parent_scope_id = self.current_scope_id;
+        if let Some(scope_id) = scope_id {
+            self.current_scope_id = scope_id;
+        }
+
+        let mut params = self.ast.vec([self
+            .ast
+            .formal_parameter(self.ast.binding_pattern_identifier("$$anchor"))]);
+        let mut body = self.ast.vec([]);
+        if let Some(pattern) = pattern {
+            let argument = self.build_derived_block_argument(pattern, &mut body);
+            params.push(self.ast.formal_parameter(argument));
+        }
+        body.extend(self.transform_fragment(SvelteAstType::AwaitBlock, fragment));
+
+        self.current_scope_id = parent_scope_id;
+        self.ast.expression_arrow(params, body)
+    }
+
+    /// The parameter receiving the source of a block argument. A destructured
+    /// pattern is turned into one derived per binding:
+    ///
+    /// ```js
+    /// var $$value = $.derived(() => {
+    ///     var { a, b } = $.get($$source);
+    ///     return { a, b };
+    /// });
+    /// var a = $.derived(() => $.get($$value).a);
+    /// ```
+    fn build_derived_block_argument(
+        &mut self,
+        pattern: &BindingPattern<'a>,
+        declarations: &mut OxcVec<'a, Statement<'a>>,
+    ) -> BindingPattern<'a> {
+        if let BindingPatternKind::BindingIdentifier(ident) = &pattern.kind {
+            if let Some(symbol_id) = ident.symbol_id.get() {
+                self.read_transforms.insert(symbol_id, ReadTransform::Get);
+            }
+            return self.ast.binding_pattern_identifier(ident.name.as_str());
+        }
+
+        let mut bindings = vec![];
+        pattern
+            .bound_names(&mut |ident| bindings.push((ident.name.clone(), ident.symbol_id.get())));
+
+        // default values are evaluated before the bindings are transformed
+        let mut destructured = pattern.clone_in(self.allocator);
+        self.visit_binding_pattern(&mut destructured);
+        let source = self.ast.expression_call_with_atom(
+            "$.get",
+            self.ast
+                .vec([self.ast.expression_identifier_reference("$$source").into()]),
+        );
+        let object = self.ast.expression_object(
+            self.ast.vec_from_iter(
+                bindings
+                    .iter()
+                    .map(|(name, _)| self.ast.object_property_shorthand(name)),
+            ),
+        );
+        let block = self.ast.expression_arrow(
+            self.ast.vec([]),
+            self.ast.vec([
+                self.ast.statement_var(destructured, source),
+                self.ast.statement_return(object),
+            ]),
+        );
+        declarations.push(self.ast.statement_var(
+            self.ast.binding_pattern_identifier("$$value"),
+            self.build_derived(block),
+        ));
+
+        for (name, symbol_id) in bindings {
+            if let Some(symbol_id) = symbol_id {
+                self.read_transforms.insert(symbol_id, ReadTransform::Get);
+            }
+            let value = self.ast.expression_call_with_atom(
+                "$.get",
+                self.ast
+                    .vec([self.ast.expression_identifier_reference("$$value").into()]),
+            );
+            let thunk = self.ast.expression_arrow(
+                self.ast.vec([]),
+                self.ast.vec([self
+                    .ast
+                    .statement_expression(self.ast.expression_static_member(value, &name))]),
+            );
+            declarations.push(self.ast.statement_var(
+                self.ast.binding_pattern_identifier(name.as_str()),
+                self.build_derived(thunk),
+            ));
+        }
+
+        self.ast.binding_pattern_identifier("$$source")
+    }
+
+    /// `$.derived(thunk)`, or `$.derived_safe_equal(thunk)` outside of runes mode
+    pub(super) fn build_derived(&self, thunk: Expression<'a>) -> Expression<'a> {
+        let callee = if self.runes {
+            "$.derived"
+        } else {
+            "$.derived_safe_equal"
+        };
+        self.ast
+            .expression_call_with_atom(callee, self.ast.vec([thunk.into()]))
+    }
+}
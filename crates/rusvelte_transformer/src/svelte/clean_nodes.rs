@@ -1,10 +1,13 @@
 use oxc_allocator::IntoIn;
+use rusvelte_analyzer::Namespace;
 use rusvelte_ast::{
-    ast::{Block, Element, FragmentNode, Tag},
+    ast::{Block, Element, Fragment, FragmentNode, Tag},
     ast_kind::SvelteAstType,
-    traits::{get_ast_type::GetAstType, get_fragment::GetFragmentMut, get_name::GetName},
+    traits::get_ast_type::GetAstType,
+};
+use rusvelte_utils::regex_pattern::{
+    REGEX_ENDS_WITH_WHITESPACES, REGEX_NOT_WHITESPACE, REGEX_STARTS_WITH_WHITESPACES,
 };
-use rusvelte_utils::regex_pattern::{REGEX_ENDS_WITH_WHITESPACES, REGEX_NOT_WHITESPACE};
 
 use crate::Transformer;
 
@@ -16,13 +19,17 @@ pub struct CleanNodesReturn<'a> {
 }
 
 impl<'a> Transformer<'a> {
-    pub fn clean_nodes<T>(&self, parent: &mut T) -> CleanNodesReturn<'a>
-    where
-        T: GetFragmentMut<'a> + GetAstType + GetName,
-    {
+    /// `parent` is the type of the node that owns `fragment`, and `parent_name` is its tag name
+    /// when it is an element.
+    pub fn clean_nodes(
+        &self,
+        parent: SvelteAstType,
+        parent_name: &str,
+        fragment: &mut Fragment<'a>,
+    ) -> CleanNodesReturn<'a> {
         let mut hoisted = vec![];
         let mut regular = vec![];
-        let nodes = self.ast.move_fragment_nodes(parent.get_fragment_mut());
+        let nodes = self.ast.move_fragment_nodes(fragment);
         for node in nodes {
             match &node {
                 FragmentNode::Element(element)
@@ -40,7 +47,11 @@ impl<'a> Transformer<'a> {
                 FragmentNode::Tag(Tag::ConstTag(_) | Tag::DebugTag(_)) => {
                     hoisted.push(node);
                 }
-                FragmentNode::Comment(_) => todo!("should check preserve_comment option"),
+                FragmentNode::Comment(_) => {
+                    if self.options.preserve_comments {
+                        regular.push(node);
+                    }
+                }
                 FragmentNode::Block(Block::SnippetBlock(_)) => {
                     hoisted.push(node);
                 }
@@ -50,103 +61,111 @@ impl<'a> Transformer<'a> {
             }
         }
 
-        // TODO: check option.preserve_whitespace
-        let first_not_whitespace_node_pos = regular
-            .iter()
-            .position(|node| {
-                if let FragmentNode::Text(text) = node {
-                    REGEX_NOT_WHITESPACE.is_match(&text.data)
-                } else {
-                    true
-                }
-            })
-            .unwrap_or(regular.len());
-        let last_not_whitespace_node_pos = regular
-            .iter()
-            .rposition(|node| {
-                if let FragmentNode::Text(text) = node {
-                    REGEX_NOT_WHITESPACE.is_match(&text.data)
-                } else {
-                    true
-                }
-            })
-            .unwrap_or(0);
-        if first_not_whitespace_node_pos <= last_not_whitespace_node_pos {
-            regular = regular
-                .into_iter()
-                .skip(first_not_whitespace_node_pos)
-                .take(last_not_whitespace_node_pos - first_not_whitespace_node_pos + 1)
-                .collect();
-        }
+        let trimmed: Vec<_> = if self.options.preserve_whitespace {
+            regular
+        } else {
+            let first_not_whitespace_node_pos = regular
+                .iter()
+                .position(|node| {
+                    if let FragmentNode::Text(text) = node {
+                        REGEX_NOT_WHITESPACE.is_match(&text.data)
+                    } else {
+                        true
+                    }
+                })
+                .unwrap_or(regular.len());
+            let last_not_whitespace_node_pos = regular
+                .iter()
+                .rposition(|node| {
+                    if let FragmentNode::Text(text) = node {
+                        REGEX_NOT_WHITESPACE.is_match(&text.data)
+                    } else {
+                        true
+                    }
+                })
+                .unwrap_or(0);
+            if first_not_whitespace_node_pos <= last_not_whitespace_node_pos {
+                regular = regular
+                    .into_iter()
+                    .skip(first_not_whitespace_node_pos)
+                    .take(last_not_whitespace_node_pos - first_not_whitespace_node_pos + 1)
+                    .collect();
+            }
 
-        if let Some(text) = regular.first_mut().and_then(FragmentNode::as_text_mut) {
-            text.raw = REGEX_ENDS_WITH_WHITESPACES
-                .replace(&text.raw, "")
-                .into_in(self.allocator);
-            text.data = REGEX_ENDS_WITH_WHITESPACES
-                .replace(&text.data, "")
-                .to_string()
-                .into();
-        }
+            if let Some(text) = regular.first_mut().and_then(FragmentNode::as_text_mut) {
+                text.raw = REGEX_STARTS_WITH_WHITESPACES
+                    .replace(&text.raw, "")
+                    .into_in(self.allocator);
+                text.data = REGEX_STARTS_WITH_WHITESPACES
+                    .replace(&text.data, "")
+                    .to_string()
+                    .into();
+            }
 
-        if let Some(text) = regular.last_mut().and_then(FragmentNode::as_text_mut) {
-            text.raw = REGEX_ENDS_WITH_WHITESPACES
-                .replace(&text.raw, "")
-                .into_in(self.allocator);
-            text.data = REGEX_ENDS_WITH_WHITESPACES
-                .replace(&text.data, "")
-                .to_string()
-                .into();
-        }
+            if let Some(text) = regular.last_mut().and_then(FragmentNode::as_text_mut) {
+                text.raw = REGEX_ENDS_WITH_WHITESPACES
+                    .replace(&text.raw, "")
+                    .into_in(self.allocator);
+                text.data = REGEX_ENDS_WITH_WHITESPACES
+                    .replace(&text.data, "")
+                    .to_string()
+                    .into();
+            }
 
-        // TODO: check namespace
-        let can_remove_entirely = parent.ast_type() == SvelteAstType::RegularElement
-            && matches!(
-                parent.name(),
-                "select" | "tr" | "table" | "tbody" | "thead" | "tfoot" | "colgroup" | "datalist"
-            );
-        let mut trimmed = vec![];
-        regular.reverse();
-        while let Some(mut node) = regular.pop() {
-            let prev = trimmed.last();
-            let next = regular.first();
-            if let FragmentNode::Text(text) = &mut node {
-                if !prev.map_or(false, FragmentNode::is_expression_tag) {
-                    let whitespace = if prev.map_or(false, |node| {
-                        if let FragmentNode::Text(text) = node {
-                            REGEX_ENDS_WITH_WHITESPACES.is_match(&text.data)
-                        } else {
-                            false
-                        }
-                    }) {
-                        ""
-                    } else {
-                        " "
-                    };
-                    text.data = REGEX_ENDS_WITH_WHITESPACES
-                        .replace(&text.data, whitespace)
-                        .to_string()
-                        .into();
-                    text.raw = REGEX_ENDS_WITH_WHITESPACES
-                        .replace(&text.raw, whitespace)
-                        .into_in(self.allocator);
-                }
-                if next.map_or(true, FragmentNode::is_expression_tag) {
-                    text.data = REGEX_ENDS_WITH_WHITESPACES
-                        .replace(&text.data, " ")
-                        .to_string()
-                        .into();
-                    text.raw = REGEX_ENDS_WITH_WHITESPACES
-                        .replace(&text.raw, " ")
-                        .into_in(self.allocator);
-                }
-                if !text.data.is_empty() && (text.data != " " || !can_remove_entirely) {
-                    trimmed.push(node);
+            // TODO: check whether one of the ancestors is a `<text>` element
+            let can_remove_entirely = (self.namespace == Namespace::Svg
+                && (parent != SvelteAstType::RegularElement || parent_name != "text"))
+                || (parent == SvelteAstType::RegularElement
+                    && matches!(
+                        parent_name,
+                        "select"
+                            | "tr"
+                            | "table"
+                            | "tbody"
+                            | "thead"
+                            | "tfoot"
+                            | "colgroup"
+                            | "datalist"
+                    ));
+            let mut keep = vec![true; regular.len()];
+            for i in 0..regular.len() {
+                let (before, rest) = regular.split_at_mut(i);
+                let (node, after) = rest.split_first_mut().unwrap();
+                let prev = before.last();
+                let next = after.first();
+                if let FragmentNode::Text(text) = node {
+                    if !prev.map_or(false, FragmentNode::is_expression_tag) {
+                        let prev_ends_with_whitespace =
+                            prev.and_then(FragmentNode::as_text).map_or(false, |prev| {
+                                REGEX_ENDS_WITH_WHITESPACES.is_match(&prev.data)
+                            });
+                        let whitespace = if prev_ends_with_whitespace { "" } else { " " };
+                        text.data = REGEX_STARTS_WITH_WHITESPACES
+                            .replace(&text.data, whitespace)
+                            .to_string()
+                            .into();
+                        text.raw = REGEX_STARTS_WITH_WHITESPACES
+                            .replace(&text.raw, whitespace)
+                            .into_in(self.allocator);
+                    }
+                    if !next.map_or(false, FragmentNode::is_expression_tag) {
+                        text.data = REGEX_ENDS_WITH_WHITESPACES
+                            .replace(&text.data, " ")
+                            .to_string()
+                            .into();
+                        text.raw = REGEX_ENDS_WITH_WHITESPACES
+                            .replace(&text.raw, " ")
+                            .into_in(self.allocator);
+                    }
+                    keep[i] = !text.data.is_empty() && (text.data != " " || !can_remove_entirely);
                 }
-            } else {
-                trimmed.push(node);
             }
-        }
+            regular
+                .into_iter()
+                .zip(keep)
+                .filter_map(|(node, keep)| keep.then_some(node))
+                .collect()
+        };
 
         // TODO: handle a long script tag case
 
@@ -164,8 +183,9 @@ impl<'a> Transformer<'a> {
         let is_standalone = if let Some(first) = first {
             trimmed.len() == 1
                 && ((first.ast_type() == SvelteAstType::RenderTag && !first.dynamic())
-                    || (first.ast_type() == SvelteAstType::Component && !first.dynamic()/* &&
-                    !state.options.hmr &&
+                    || (first.ast_type() == SvelteAstType::Component
+                        && !first.dynamic()
+                        && !self.options.hmr/* &&
                     !first.attributes.some(
                       (attribute) => attribute.type === 'Attribute' && attribute.name.startsWith('--')
                     ) */))
@@ -174,7 +194,7 @@ impl<'a> Transformer<'a> {
         };
 
         let is_text_first = matches!(
-            parent.ast_type(),
+            parent,
             SvelteAstType::Fragment
                 | SvelteAstType::SnippetBlock
                 | SvelteAstType::EachBlock
@@ -0,0 +1,281 @@
+use oxc_allocator::Vec as OxcVec;
+use rusvelte_ast::{
+    ast::*,
+    ast_kind::SvelteAstType,
+    js_ast::{Expression, Statement},
+    visit_mut::JsVisitMut,
+};
+
+use crate::Transformer;
+
+impl<'a> Transformer<'a> {
+    /// ```js
+    /// Child(node, { get prop() { return $.get(value); }, children: ($$anchor, $$slotProps) => { ... }, $$slots: { default: true } });
+    /// ```
+    /// A component whose name refers to a state or a prop is mounted through `$.component`
+    /// so that it is replaced along with the value.
+    pub(crate) fn transform_component(
+        &mut self,
+        it: &mut Component<'a>,
+    ) -> OxcVec<'a, Statement<'a>> {
+        self.state.template.push("<!>".to_string());
+
+        // `<ui.Button>` reads `ui` like any other expression
+        let mut parts = it.name.split('.');
+        let mut callee = self
+            .ast
+            .expression_identifier_reference(parts.next().unwrap_or(it.name));
+        self.visit_expression(&mut callee);
+        for part in parts {
+            callee = self.ast.expression_static_member(callee, part);
+        }
+
+        let (props, snippet_declarations) =
+            self.build_component_props(&mut it.attributes, &mut it.fragment);
+
+        // `$.bind_this(Child(node, props), ($$value) => child = $$value, () => child)`
+        let bind_this = it
+            .attributes
+            .iter_mut()
+            .find_map(|attribute| match attribute {
+                Attribute::Directive(Directive::BindDirective(directive))
+                    if directive.name == "this" =>
+                {
+                    Some(&mut directive.expression)
+                }
+                _ => None,
+            });
+        let node = self.ast.expression_identifier_reference(&self.state.node);
+        let call = if it.dynamic.get() {
+            let mut mount = self.ast.expression_call_with_atom(
+                "$$component",
+                self.ast.vec([
+                    self.ast.expression_identifier_reference("$$anchor").into(),
+                    props.into(),
+                ]),
+            );
+            if let Some(expression) = bind_this {
+                mount = self.build_bind_this(mount, expression);
+            }
+            self.ast.expression_call_with_atom(
+                "$.component",
+                self.ast.vec([
+                    node.into(),
+                    self.ast
+                        .expression_arrow(
+                            self.ast.vec([]),
+                            self.ast.vec([self.ast.statement_expression(callee)]),
+                        )
+                        .into(),
+                    self.ast
+                        .expression_arrow_block(
+                            self.ast.vec([
+                                self.ast.formal_parameter(
+                                    self.ast.binding_pattern_identifier("$$anchor"),
+                                ),
+                                self.ast.formal_parameter(
+                                    self.ast.binding_pattern_identifier("$$component"),
+                                ),
+                            ]),
+                            self.ast.vec([self.ast.statement_expression(mount)]),
+                        )
+                        .into(),
+                ]),
+            )
+        } else {
+            let call = self
+                .ast
+                .expression_call(callee, self.ast.vec([node.into(), props.into()]));
+            match bind_this {
+                Some(expression) => self.build_bind_this(call, expression),
+                None => call,
+            }
+        };
+
+        let statement = self.ast.statement_expression(call);
+        if snippet_declarations.is_empty() {
+            return self.ast.vec([statement]);
+        }
+        // the snippets passed as props are scoped to the component
+        let mut block = snippet_declarations;
+        block.push(statement);
+        self.ast.vec([self.ast.statement_block(block)])
+    }
+
+    /// Builds the props of a component from its attributes and children, returning them
+    /// along with the declarations of the snippets it receives.
+    fn build_component_props(
+        &mut self,
+        attributes: &mut OxcVec<'a, Attribute<'a>>,
+        fragment: &mut Fragment<'a>,
+    ) -> (Expression<'a>, OxcVec<'a, Statement<'a>>) {
+        // each item is either an object of props or a spread expression
+        let mut props_and_spreads = vec![];
+        let mut props = self.ast.vec([]);
+        let mut has_children_prop = false;
+        // the handlers of each event, in the order of the `on:` directives
+        let mut events: Vec<(&'a str, Vec<Expression<'a>>)> = vec![];
+        for attribute in attributes.iter_mut() {
+            match attribute {
+                Attribute::NormalAttribute(attribute) => {
+                    has_children_prop |= attribute.name == "children";
+                    let has_state = attribute.expression_metadata.borrow().has_state;
+                    let value = self.build_attribute_value(&mut attribute.value);
+                    // a getter reads the latest value whenever the child accesses the prop
+                    let prop = if has_state {
+                        self.ast.object_property_getter(attribute.name, value)
+                    } else {
+                        self.ast.object_property(attribute.name, value)
+                    };
+                    props.push(prop);
+                }
+                Attribute::SpreadAttribute(spread) => {
+                    if !props.is_empty() {
+                        let props = std::mem::replace(&mut props, self.ast.vec([]));
+                        props_and_spreads.push(self.ast.expression_object(props));
+                    }
+                    self.visit_expression(&mut spread.expression);
+                    let expression = self.ast.move_expression(&mut spread.expression);
+                    // the spread is evaluated again whenever one of its props is read
+                    props_and_spreads.push(self.ast.expression_arrow(
+                        self.ast.vec([]),
+                        self.ast.vec([self.ast.statement_expression(expression)]),
+                    ));
+                }
+                // `get value() { return $.get(value); }, set value($$value) { $.set(value, $$value); }`
+                Attribute::Directive(Directive::BindDirective(directive))
+                    if directive.name != "this" =>
+                {
+                    let assignment = self.build_binding_assignment(&directive.expression);
+                    self.visit_expression(&mut directive.expression);
+                    let value = self.ast.move_expression(&mut directive.expression);
+                    props.push(self.ast.object_property_getter(directive.name, value));
+                    props.push(self.ast.object_property_setter(
+                        directive.name,
+                        "$$value",
+                        self.ast.vec([self.ast.statement_expression(assignment)]),
+                    ));
+                }
+                // `on:click={handler}` listens to the events dispatched by the child
+                Attribute::Directive(Directive::OnDirective(directive)) => {
+                    let mut handler =
+                        self.build_event_handler(directive.expression.as_mut(), false);
+                    if directive.modifiers.contains(&"once") {
+                        handler = self
+                            .ast
+                            .expression_call_with_atom("$.once", self.ast.vec([handler.into()]));
+                    }
+                    match events.iter_mut().find(|(name, _)| *name == directive.name) {
+                        Some((_, handlers)) => handlers.push(handler),
+                        None => events.push((directive.name, vec![handler])),
+                    }
+                }
+                // the analyzer rejects the `let:` directives
+                Attribute::Directive(_) => {}
+            }
+        }
+        if !events.is_empty() {
+            // `$$events: { click: handler, focus: [a, b] }`
+            let events = self
+                .ast
+                .vec_from_iter(events.into_iter().map(|(name, mut handlers)| {
+                    let value = if handlers.len() == 1 {
+                        handlers.pop().unwrap()
+                    } else {
+                        self.ast.expression_array(
+                            self.ast.vec_from_iter(handlers.into_iter().map(Into::into)),
+                        )
+                    };
+                    self.ast.object_property(name, value)
+                }));
+            props.push(
+                self.ast
+                    .object_property("$$events", self.ast.expression_object(events)),
+            );
+        }
+
+        let parent_scope_id = self.current_scope_id;
+        let scope_id = fragment.scope_id.get();
+        if let Some(scope_id) = scope_id {
+            self.current_scope_id = scope_id;
+        }
+        let mut snippet_declarations = self.ast.vec([]);
+        let mut children: Vec<(&'a str, OxcVec<'a, FragmentNode<'a>>)> =
+            vec![("default", self.ast.vec([]))];
+        for node in self.ast.move_fragment_nodes(fragment) {
+            if let FragmentNode::Block(Block::SnippetBlock(mut snippet)) = node {
+                props.push(self.ast.object_property_shorthand(&snippet.expression.name));
+                snippet_declarations.push(self.transform_snippet_block(&mut snippet));
+                continue;
+            }
+            let slot_name = match &node {
+                FragmentNode::Element(element) => {
+                    element
+                        .attributes()
+                        .iter()
+                        .find_map(|attribute| match attribute {
+                            Attribute::NormalAttribute(attribute) if attribute.name == "slot" => {
+                                attribute.value.get_static_value()
+                            }
+                            _ => None,
+                        })
+                }
+                _ => None,
+            }
+            .unwrap_or("default");
+            match children.iter_mut().find(|(name, _)| *name == slot_name) {
+                Some((_, nodes)) => nodes.push(node),
+                None => children.push((slot_name, self.ast.vec([node]))),
+            }
+        }
+        self.current_scope_id = parent_scope_id;
+
+        let mut serialized_slots = self.ast.vec([]);
+        for (slot_name, nodes) in children {
+            let mut fragment = self.ast.fragment(nodes, false);
+            fragment.scope_id.set(scope_id);
+            let body = self.transform_fragment(SvelteAstType::Component, &mut fragment);
+            if body.is_empty() {
+                continue;
+            }
+            let slot_fn = self.ast.expression_arrow(
+                self.ast.vec([
+                    self.ast
+                        .formal_parameter(self.ast.binding_pattern_identifier("$$anchor")),
+                    self.ast
+                        .formal_parameter(self.ast.binding_pattern_identifier("$$slotProps")),
+                ]),
+                body,
+            );
+            if slot_name == "default" && !has_children_prop {
+                props.push(self.ast.object_property("children", slot_fn));
+                serialized_slots.push(
+                    self.ast
+                        .object_property("default", self.ast.expression_boolean_literal(true)),
+                );
+            } else {
+                serialized_slots.push(self.ast.object_property(slot_name, slot_fn));
+            }
+        }
+        if !serialized_slots.is_empty() {
+            props.push(
+                self.ast
+                    .object_property("$$slots", self.ast.expression_object(serialized_slots)),
+            );
+        }
+
+        let props = if props_and_spreads.is_empty() {
+            self.ast.expression_object(props)
+        } else {
+            if !props.is_empty() {
+                props_and_spreads.push(self.ast.expression_object(props));
+            }
+            self.ast.expression_call_with_atom(
+                "$.spread_props",
+                self.ast
+                    .vec_from_iter(props_and_spreads.into_iter().map(Into::into)),
+            )
+        };
+        (props, snippet_declarations)
+    }
+}
@@ -0,0 +1,19 @@
+use rusvelte_ast::{js_ast::Expression, visit_mut::JsVisitMut};
+
+use crate::Transformer;
+
+impl<'a> Transformer<'a> {
+    /// The function referenced by the name of the directive, e.g. `transitions.fade` for
+    /// `transition:transitions.fade`, transformed like any other read
+    pub(super) fn build_directive_name(&mut self, name: &str) -> Expression<'a> {
+        let mut parts = name.split('.');
+        let mut expression = self
+            .ast
+            .expression_identifier_reference(parts.next().expect("the name is not empty"));
+        for part in parts {
+            expression = self.ast.expression_member(expression, part);
+        }
+        self.visit_expression(&mut expression);
+        expression
+    }
+}
@@ -0,0 +1,699 @@
+use oxc_allocator::{CloneIn, Vec as OxcVec};
+use rusvelte_analyzer::Namespace;
+use rusvelte_ast::{
+    ast::*,
+    ast_kind::SvelteAstType,
+    js_ast::{BinaryOperator, Expression, LogicalOperator, Statement},
+    visit_mut::{JsVisitMut, VisitMut},
+};
+use rusvelte_utils::{
+    attribute::{is_dom_property, normalize_attribute},
+    boolean_attribute::is_boolean_attribute,
+    escape::{escape_html, sanitize_template_string},
+    void_element::is_void,
+};
+
+use crate::Transformer;
+
+use super::{clean_nodes::CleanNodesReturn, fragment::ChildAnchor};
+
+impl<'a> Transformer<'a> {
+    /// Adds the element to the template. Static attributes and children are serialized
+    /// into the template as is, the others are set once the element is created.
+    pub(crate) fn transform_regular_element(
+        &mut self,
+        it: &mut RegularElement<'a>,
+    ) -> OxcVec<'a, Statement<'a>> {
+        let mut result = self.ast.vec([]);
+        let parent_namespace = self.namespace;
+        // `<svg>` and `<math>` are in their own namespace along with their attributes
+        match it.name {
+            "svg" => self.namespace = Namespace::Svg,
+            "math" => self.namespace = Namespace::Mathml,
+            _ => {}
+        }
+
+        self.state.template.push(format!("<{}", it.name));
+        let mut has_spread = false;
+        let mut needs_input_reset = false;
+        // options and grouped inputs keep their value in `__value` so that it can be of any type
+        let mut has_special_value = it.name == "option";
+        for attribute in it.attributes.iter() {
+            match attribute {
+                Attribute::SpreadAttribute(_) => {
+                    has_spread = true;
+                    needs_input_reset = true;
+                }
+                Attribute::NormalAttribute(attribute)
+                    if matches!(attribute.name, "value" | "checked")
+                        && !attribute.value.is_text() =>
+                {
+                    needs_input_reset = true;
+                }
+                Attribute::Directive(Directive::BindDirective(directive)) => match directive.name {
+                    "group" | "checked" => {
+                        needs_input_reset = true;
+                        has_special_value = true;
+                    }
+                    "value" => needs_input_reset = true,
+                    _ => {}
+                },
+                _ => {}
+            }
+        }
+        // read before the attributes are moved out of the element
+        let mut group_value = self.collect_group_value(&it.attributes);
+        if needs_input_reset && it.name == "input" {
+            // the `value` and `checked` attributes must not reset the state of the input
+            result.push(
+                self.ast.statement_expression(
+                    self.ast.expression_call_with_atom(
+                        "$.remove_input_defaults",
+                        self.ast.vec([self
+                            .ast
+                            .expression_identifier_reference(&self.state.node)
+                            .into()]),
+                    ),
+                ),
+            );
+        }
+        for attribute in it.attributes.iter_mut() {
+            if let Attribute::NormalAttribute(attribute) = attribute {
+                if attribute.is_event_attribute() {
+                    let mut statements = self.build_event_attribute(attribute);
+                    result.append(&mut statements);
+                }
+            }
+        }
+        if has_spread {
+            // the spread may override any other attribute, they are all set at runtime
+            result.push(self.build_set_attributes(&mut it.attributes));
+        } else {
+            let (mut classes, styles) = fold_static_directives(&mut it.attributes);
+            // the elements are scoped to the styles of the component
+            if !self.css_hash.is_empty() {
+                classes.push(self.css_hash.clone());
+            }
+            let has_attribute = |name: &str| {
+                it.attributes.iter().any(|attribute| match attribute {
+                    Attribute::NormalAttribute(attribute) => attribute.name == name,
+                    _ => false,
+                })
+            };
+            let has_class_attribute = has_attribute("class");
+            let has_style_attribute = has_attribute("style");
+            for attribute in it.attributes.iter_mut() {
+                // TODO: support the other directives
+                let Attribute::NormalAttribute(attribute) = attribute else {
+                    continue;
+                };
+                if attribute.is_event_attribute() {
+                    continue;
+                }
+                let is_static = (attribute.value.is_true() || attribute.value.is_text())
+                    && !cannot_be_set_statically(attribute.name)
+                    && !(has_special_value && attribute.name == "value");
+                if !is_static {
+                    let mut statements = self.build_attribute_update(has_special_value, attribute);
+                    result.append(&mut statements);
+                    continue;
+                }
+                let name = attribute.name;
+                let mut value = match &attribute.value {
+                    AttributeValue::Quoted(values) => match &values[0] {
+                        QuotedAttributeValue::Text(text) => Some(text.data.to_string()),
+                        QuotedAttributeValue::ExpressionTag(_) => None,
+                    },
+                    AttributeValue::True | AttributeValue::ExpressionTag(_) => None,
+                };
+                match name {
+                    "class" if !classes.is_empty() => {
+                        value = Some(join_folded_directives(name, value, &classes));
+                    }
+                    "style" if !styles.is_empty() => {
+                        value = Some(join_folded_directives(name, value, &styles));
+                    }
+                    _ => {}
+                }
+                if name == "class" && value.as_ref().is_some_and(|value| value.is_empty()) {
+                    continue;
+                }
+                let string = match value {
+                    Some(value) => format!(" {}=\"{}\"", name, escape_html(&value, true)),
+                    None if is_boolean_attribute(name) => format!(" {}", name),
+                    None => format!(" {}=\"\"", name),
+                };
+                self.state.template.push(string);
+            }
+            if !has_class_attribute && !classes.is_empty() {
+                let value = join_folded_directives("class", None, &classes);
+                self.state
+                    .template
+                    .push(format!(" class=\"{}\"", escape_html(&value, true)));
+            }
+            if !has_style_attribute && !styles.is_empty() {
+                let value = join_folded_directives("style", None, &styles);
+                self.state
+                    .template
+                    .push(format!(" style=\"{}\"", escape_html(&value, true)));
+            }
+        }
+        self.state.template.push(">".to_string());
+
+        // the directives are applied last so that they override the `class` and `style` attributes
+        let mut statements = self.build_class_and_style_directives(&mut it.attributes, has_spread);
+        result.append(&mut statements);
+
+        // listeners, bindings, transitions and actions are set up in order after the attributes
+        for attribute in it.attributes.iter_mut() {
+            match attribute {
+                Attribute::Directive(Directive::OnDirective(directive)) => {
+                    let statement = self.build_on_directive(directive);
+                    self.state.after_update.push(statement);
+                }
+                Attribute::Directive(Directive::BindDirective(directive)) => {
+                    let statement = self.build_bind_directive(
+                        it.name,
+                        std::mem::take(&mut group_value),
+                        directive,
+                    );
+                    // `bind:this` is one-way, the element is available as soon as it is created
+                    if directive.name == "this" {
+                        result.push(statement);
+                    } else {
+                        self.state.after_update.push(statement);
+                    }
+                }
+                Attribute::Directive(Directive::TransitionDirective(directive)) => {
+                    let statement = self.build_transition_directive(directive);
+                    self.state.after_update.push(statement);
+                }
+                Attribute::Directive(Directive::AnimateDirective(directive)) => {
+                    let statement = self.build_animate_directive(directive);
+                    self.state.after_update.push(statement);
+                }
+                Attribute::Directive(Directive::UseDirective(directive)) => {
+                    let statement = self.build_use_directive(directive);
+                    self.state.after_update.push(statement);
+                }
+                _ => {}
+            }
+        }
+
+        let parent_scope_id = self.current_scope_id;
+        if let Some(scope_id) = it.scope_id.get() {
+            self.current_scope_id = scope_id;
+        }
+        // the children of `<foreignObject>` are HTML again
+        if it.name == "foreignObject" {
+            self.namespace = Namespace::Html;
+        }
+
+        let CleanNodesReturn {
+            hoisted, trimmed, ..
+        } = self.clean_nodes(SvelteAstType::RegularElement, it.name, &mut it.fragment);
+
+        // TODO: visit the other hoisted nodes
+        for node in hoisted {
+            match node {
+                FragmentNode::Block(Block::SnippetBlock(mut snippet)) => {
+                    result.push(self.transform_snippet_block(&mut snippet));
+                }
+                FragmentNode::Tag(Tag::DebugTag(mut tag)) => {
+                    result.extend(self.transform_debug_tag(&mut tag));
+                }
+                _ => {}
+            }
+        }
+
+        if it.fragment.metadata.borrow().dynamic {
+            let parent_init = std::mem::replace(&mut self.state.init, self.ast.vec([]));
+            let needs_reset = trimmed.iter().any(|node| !node.is_text());
+            self.process_children(trimmed, ChildAnchor::Child(self.state.node.clone()));
+            if needs_reset {
+                self.state.init.push(
+                    self.ast.statement_expression(
+                        self.ast.expression_call_with_atom(
+                            "$.reset",
+                            self.ast.vec([self
+                                .ast
+                                .expression_identifier_reference(&self.state.node)
+                                .into()]),
+                        ),
+                    ),
+                );
+            }
+            result.extend(std::mem::replace(&mut self.state.init, parent_init));
+        } else {
+            // nothing to traverse, the children only contribute to the template
+            for mut node in trimmed {
+                if let FragmentNode::Text(text) = &node {
+                    self.state.template.push(text.raw.to_string());
+                } else {
+                    result.extend(self.visit_fragment_node(&mut node));
+                }
+            }
+        }
+
+        self.current_scope_id = parent_scope_id;
+        self.namespace = parent_namespace;
+
+        if !is_void(it.name) {
+            self.state.template.push(format!("</{}>", it.name));
+        }
+
+        result
+    }
+
+    /// ```js
+    /// $.set_attribute(div, "title", $.get(title));
+    /// ```
+    /// Returns the statements run when the element is created, the update is added to the
+    /// template effect when the value depends on a state.
+    fn build_attribute_update(
+        &mut self,
+        has_special_value: bool,
+        attribute: &mut NormalAttribute<'a>,
+    ) -> OxcVec<'a, Statement<'a>> {
+        // the attributes of svg and mathml elements are case sensitive, e.g. `viewBox`
+        let name = if self.namespace == Namespace::Html {
+            normalize_attribute(attribute.name)
+        } else {
+            attribute.name.to_string()
+        };
+        let (has_state, has_call) = {
+            let metadata = attribute.expression_metadata.borrow();
+            (metadata.has_state, metadata.has_call)
+        };
+        let value = self.build_attribute_value(&mut attribute.value);
+        let node = self.ast.expression_identifier_reference(&self.state.node);
+        let update = match name.as_str() {
+            "autofocus" => {
+                return self.ast.vec([self.ast.statement_expression(
+                    self.ast.expression_call_with_atom(
+                        "$.autofocus",
+                        self.ast.vec([node.into(), value.into()]),
+                    ),
+                )]);
+            }
+            // Firefox only applies `muted` when it is set as a property
+            "muted" => {
+                return self.ast.vec([self.ast.statement_expression(
+                    self.ast.expression_assignment(
+                        self.ast.expression_static_member(node, "muted"),
+                        value,
+                    ),
+                )]);
+            }
+            "class" => {
+                // `${value ?? ""} svelte-xyz`
+                let value = if self.css_hash.is_empty() {
+                    value
+                } else {
+                    self.ast.expression_template_literal(
+                        &[String::new(), format!(" {}", self.css_hash)],
+                        self.ast.vec([self.ast.expression_logical(
+                            value,
+                            LogicalOperator::Coalesce,
+                            self.ast.expression_string_literal(""),
+                        )]),
+                    )
+                };
+                self.ast.expression_call_with_atom(
+                    "$.set_class",
+                    self.ast.vec([node.into(), value.into()]),
+                )
+            }
+            // `option.value = null == (option.__value = value) ? "" : value`, a `<select>` reads
+            // `__value` to find the selected option
+            "value" if has_special_value => {
+                let inner = self.ast.expression_assignment(
+                    self.ast.expression_static_member(
+                        self.ast.expression_identifier_reference(&self.state.node),
+                        "__value",
+                    ),
+                    value.clone_in(self.allocator),
+                );
+                self.ast.expression_assignment(
+                    self.ast.expression_static_member(node, "value"),
+                    self.ast.expression_conditional(
+                        self.ast.expression_binary(
+                            self.ast.expression_null(),
+                            BinaryOperator::Equality,
+                            inner,
+                        ),
+                        self.ast.expression_string_literal(""),
+                        value,
+                    ),
+                )
+            }
+            "value" => self.ast.expression_call_with_atom(
+                "$.set_value",
+                self.ast.vec([node.into(), value.into()]),
+            ),
+            "checked" => self.ast.expression_call_with_atom(
+                "$.set_checked",
+                self.ast.vec([node.into(), value.into()]),
+            ),
+            "selected" => self.ast.expression_call_with_atom(
+                "$.set_selected",
+                self.ast.vec([node.into(), value.into()]),
+            ),
+            name if is_dom_property(name) => self
+                .ast
+                .expression_assignment(self.ast.expression_static_member(node, name), value),
+            name => self.ast.expression_call_with_atom(
+                if name.starts_with("xlink") {
+                    "$.set_xlink_attribute"
+                } else {
+                    "$.set_attribute"
+                },
+                self.ast.vec([
+                    node.into(),
+                    self.ast.expression_string_literal(name).into(),
+                    value.into(),
+                ]),
+            ),
+        };
+
+        let update = self.ast.statement_expression(update);
+        if has_call {
+            // calls are isolated in their own effect so they don't rerun with the other updates
+            self.ast
+                .vec([self.build_template_effect(self.ast.vec([update]))])
+        } else if has_state {
+            self.state.update.push(update);
+            self.ast.vec([])
+        } else {
+            self.ast.vec([update])
+        }
+    }
+
+    /// ```js
+    /// $.toggle_class(div, "active", $.get(active));
+    /// $.set_style(div, "color", $.get(color), true);
+    /// ```
+    /// The directives are updated along with the attributes that depend on a state, since
+    /// setting the `class` or `style` attribute resets them.
+    fn build_class_and_style_directives(
+        &mut self,
+        attributes: &mut OxcVec<'a, Attribute<'a>>,
+        has_spread: bool,
+    ) -> OxcVec<'a, Statement<'a>> {
+        let mut result = self.ast.vec([]);
+        let mut is_attributes_reactive = has_spread;
+        let mut has_style_attribute = has_spread;
+        for attribute in attributes.iter() {
+            if let Attribute::NormalAttribute(attribute) = attribute {
+                is_attributes_reactive |= !attribute.is_event_attribute()
+                    && attribute.expression_metadata.borrow().has_state;
+                has_style_attribute |= attribute.name == "style";
+            }
+        }
+
+        for attribute in attributes.iter_mut() {
+            let (update, metadata) = match attribute {
+                Attribute::Directive(Directive::ClassDirective(directive)) => {
+                    self.visit_expression(&mut directive.expression);
+                    let value = self.ast.move_expression(&mut directive.expression);
+                    let update = self.ast.expression_call_with_atom(
+                        "$.toggle_class",
+                        self.ast.vec([
+                            self.ast
+                                .expression_identifier_reference(&self.state.node)
+                                .into(),
+                            self.ast.expression_string_literal(directive.name).into(),
+                            value.into(),
+                        ]),
+                    );
+                    (update, &directive.expression_metadata)
+                }
+                Attribute::Directive(Directive::StyleDirective(directive)) => {
+                    let value = if directive.value.is_true() {
+                        // `style:color` is a shorthand for `style:color={color}`
+                        let mut value = self.ast.expression_identifier_reference(directive.name);
+                        self.visit_expression(&mut value);
+                        value
+                    } else {
+                        self.build_attribute_value(&mut directive.value)
+                    };
+                    let mut args = self.ast.vec([
+                        self.ast
+                            .expression_identifier_reference(&self.state.node)
+                            .into(),
+                        self.ast.expression_string_literal(directive.name).into(),
+                        value.into(),
+                    ]);
+                    if directive.important || has_style_attribute {
+                        let important = if directive.important {
+                            self.ast.expression_boolean_literal(true)
+                        } else {
+                            self.ast.expression_void_zero()
+                        };
+                        args.push(important.into());
+                    }
+                    // the style attribute may have been set with the same property
+                    if has_style_attribute {
+                        args.push(self.ast.expression_boolean_literal(true).into());
+                    }
+                    let update = self.ast.expression_call_with_atom("$.set_style", args);
+                    (update, &directive.expression_metadata)
+                }
+                _ => continue,
+            };
+
+            let (has_state, has_call) = {
+                let metadata = metadata.borrow();
+                (metadata.has_state, metadata.has_call)
+            };
+            let update = self.ast.statement_expression(update);
+            if has_call && !is_attributes_reactive {
+                result.push(self.build_template_effect(self.ast.vec([update])));
+            } else if has_state || has_call || is_attributes_reactive {
+                self.state.update.push(update);
+            } else {
+                result.push(update);
+            }
+        }
+        result
+    }
+
+    /// ```js
+    /// let attributes;
+    /// $.template_effect(() => attributes = $.set_attributes(div, attributes, { ...rest, title: "a" }));
+    /// ```
+    /// The update is always part of the template effect because a spread can't be analyzed.
+    fn build_set_attributes(
+        &mut self,
+        attributes: &mut OxcVec<'a, Attribute<'a>>,
+    ) -> Statement<'a> {
+        let mut properties = self.ast.vec([]);
+        for attribute in attributes.iter_mut() {
+            match attribute {
+                Attribute::NormalAttribute(attribute) => {
+                    if attribute.is_event_attribute() {
+                        continue;
+                    }
+                    let value = self.build_attribute_value(&mut attribute.value);
+                    properties.push(self.ast.object_property(attribute.name, value));
+                }
+                Attribute::SpreadAttribute(spread) => {
+                    self.visit_expression(&mut spread.expression);
+                    properties.push(
+                        self.ast
+                            .spread_property(self.ast.move_expression(&mut spread.expression)),
+                    );
+                }
+                // the directives are applied on their own, after the attributes
+                Attribute::Directive(_) => {}
+            }
+        }
+
+        let id = self.scopes.generate("attributes", self.current_scope_id);
+        let mut args = self.ast.vec([
+            self.ast
+                .expression_identifier_reference(&self.state.node)
+                .into(),
+            self.ast.expression_identifier_reference(&id).into(),
+            self.ast.expression_object(properties).into(),
+        ]);
+        // the class of the scoped styles is added to the spread `class`
+        if !self.css_hash.is_empty() {
+            args.push(
+                self.ast
+                    .expression_string_literal(self.css_hash.as_str())
+                    .into(),
+            );
+        }
+        let update = self.ast.expression_assignment(
+            self.ast.expression_identifier_reference(&id),
+            self.ast.expression_call_with_atom("$.set_attributes", args),
+        );
+        self.state
+            .update
+            .push(self.ast.statement_expression(update));
+        self.ast
+            .statement_let_uninit(self.ast.binding_pattern_identifier(id.as_str()))
+    }
+
+    /// The value of an attribute with its expressions visited, e.g. `` `a ${$.stringify(b)}` ``
+    /// for `name="a {b}"`
+    pub(super) fn build_attribute_value(
+        &mut self,
+        value: &mut AttributeValue<'a>,
+    ) -> Expression<'a> {
+        match value {
+            AttributeValue::True => self.ast.expression_boolean_literal(true),
+            AttributeValue::ExpressionTag(tag) => {
+                self.visit_expression(&mut tag.expression);
+                self.ast.move_expression(&mut tag.expression)
+            }
+            AttributeValue::Quoted(values) => match values.as_mut_slice() {
+                [] => self.ast.expression_string_literal(""),
+                [QuotedAttributeValue::Text(text)] => {
+                    self.ast.expression_string_literal(text.data.as_ref())
+                }
+                [QuotedAttributeValue::ExpressionTag(tag)] => {
+                    self.visit_expression(&mut tag.expression);
+                    self.ast.move_expression(&mut tag.expression)
+                }
+                values => {
+                    let mut quasis = vec![String::new()];
+                    let mut expressions = self.ast.vec([]);
+                    for value in values.iter_mut() {
+                        match value {
+                            QuotedAttributeValue::Text(text) => quasis
+                                .last_mut()
+                                .unwrap()
+                                .push_str(&sanitize_template_string(&text.data)),
+                            QuotedAttributeValue::ExpressionTag(tag) => {
+                                self.visit_expression(&mut tag.expression);
+                                let expression = self.ast.move_expression(&mut tag.expression);
+                                expressions.push(self.ast.expression_call_with_atom(
+                                    "$.stringify",
+                                    self.ast.vec([expression.into()]),
+                                ));
+                                quasis.push(String::new());
+                            }
+                        }
+                    }
+                    self.ast.expression_template_literal(&quasis, expressions)
+                }
+            },
+        }
+    }
+}
+
+/// Whether the node can be created from the template alone, without being traversed.
+pub(super) fn is_static_element(node: &FragmentNode) -> bool {
+    let FragmentNode::Element(element) = node else {
+        return false;
+    };
+    let Element::RegularElement(element) = element.as_ref() else {
+        return false;
+    };
+    if element.fragment.metadata.borrow().dynamic {
+        return false;
+    }
+    // all attributes of custom elements are set through properties
+    if element.name.contains('-') {
+        return false;
+    }
+    element.attributes.iter().all(|attribute| {
+        let Attribute::NormalAttribute(attribute) = attribute else {
+            return false;
+        };
+        !attribute.is_event_attribute()
+            && !cannot_be_set_statically(attribute.name)
+            && !(element.name == "option" && attribute.name == "value")
+            && (attribute.value.is_true() || attribute.value.is_text())
+    })
+}
+
+/// Removes the class and style directives whose value is known at compile time, returning
+/// them to be serialized into the `class` and `style` attributes of the template. They are
+/// left untouched when the attribute itself is set at runtime.
+fn fold_static_directives(attributes: &mut OxcVec<Attribute>) -> (Vec<String>, Vec<String>) {
+    let is_static_attribute = |name: &str| {
+        attributes.iter().all(|attribute| match attribute {
+            Attribute::NormalAttribute(attribute) if attribute.name == name => {
+                attribute.value.is_true() || attribute.value.is_text()
+            }
+            _ => true,
+        })
+    };
+    let fold_classes = is_static_attribute("class");
+    let fold_styles = is_static_attribute("style");
+
+    let mut classes = vec![];
+    let mut styles = vec![];
+    attributes.retain(|attribute| match attribute {
+        Attribute::Directive(Directive::ClassDirective(directive)) if fold_classes => {
+            match static_truthiness(&directive.expression) {
+                Some(true) => {
+                    classes.push(directive.name.to_string());
+                    false
+                }
+                Some(false) => false,
+                None => true,
+            }
+        }
+        Attribute::Directive(Directive::StyleDirective(directive)) if fold_styles => {
+            let value = match &directive.value {
+                AttributeValue::Quoted(values) => match values.as_slice() {
+                    [QuotedAttributeValue::Text(text)] => Some(text.data.to_string()),
+                    _ => None,
+                },
+                AttributeValue::ExpressionTag(tag) => tag.get_static_value().map(str::to_string),
+                AttributeValue::True => None,
+            };
+            let Some(value) = value else {
+                return true;
+            };
+            let important = if directive.important {
+                " !important"
+            } else {
+                ""
+            };
+            styles.push(format!("{}: {value}{important};", directive.name));
+            false
+        }
+        _ => true,
+    });
+    (classes, styles)
+}
+
+/// The truthiness of a literal expression
+fn static_truthiness(expression: &Expression) -> Option<bool> {
+    match expression {
+        Expression::BooleanLiteral(literal) => Some(literal.value),
+        Expression::NullLiteral(_) => Some(false),
+        Expression::NumericLiteral(literal) => {
+            Some(literal.value != 0.0 && !literal.value.is_nan())
+        }
+        Expression::StringLiteral(literal) => Some(!literal.value.is_empty()),
+        _ => None,
+    }
+}
+
+/// Appends the folded directives to the static value of the `class` or `style` attribute
+fn join_folded_directives(name: &str, value: Option<String>, folded: &[String]) -> String {
+    let mut parts = vec![];
+    if let Some(value) = value.as_deref().map(str::trim) {
+        if name == "style" && !value.is_empty() && !value.ends_with(';') {
+            parts.push(format!("{value};"));
+        } else if !value.is_empty() {
+            parts.push(value.to_string());
+        }
+    }
+    parts.extend(folded.iter().cloned());
+    parts.join(" ")
+}
+
+/// Attributes that are only applied when set as properties.
+fn cannot_be_set_statically(name: &str) -> bool {
+    matches!(
+        name,
+        "autofocus" | "muted" | "defaultValue" | "defaultChecked"
+    )
+}
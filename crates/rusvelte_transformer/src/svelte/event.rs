@@ -0,0 +1,224 @@
+use oxc_allocator::Vec as OxcVec;
+use rusvelte_ast::{
+    ast::*,
+    js_ast::{Expression, Statement},
+    visit_mut::JsVisitMut,
+};
+use rusvelte_utils::event::{is_capture_event, is_delegated_event, is_passive_event};
+
+use crate::Transformer;
+
+/// The modifiers of `on:` directives that wrap the handler, in the order they are applied
+const EVENT_MODIFIERS: [&str; 6] = [
+    "stopPropagation",
+    "stopImmediatePropagation",
+    "preventDefault",
+    "self",
+    "trusted",
+    "once",
+];
+
+impl<'a> Transformer<'a> {
+    /// ```js
+    /// button.__click = handler;
+    /// ```
+    /// for the events that are delegated, the others are listened to with `$.event` once the
+    /// template effect is set up.
+    pub(super) fn build_event_attribute(
+        &mut self,
+        attribute: &mut NormalAttribute<'a>,
+    ) -> OxcVec<'a, Statement<'a>> {
+        let name: &'a str = attribute.name;
+        let mut event_name = &name[2..];
+        let capture = is_capture_event(event_name);
+        if capture {
+            event_name = &event_name[..event_name.len() - "capture".len()];
+        }
+        let has_call = attribute.expression_metadata.borrow().has_call;
+        let AttributeValue::ExpressionTag(tag) = &mut attribute.value else {
+            unreachable!("the value of an event attribute is a single expression")
+        };
+        let handler = self.build_event_handler(Some(&mut tag.expression), has_call);
+
+        if capture || !is_delegated_event(event_name) {
+            let passive = is_passive_event(event_name).then_some(true);
+            let event = self.build_event(event_name, handler, capture, passive);
+            self.state.after_update.push(event);
+            return self.ast.vec([]);
+        }
+
+        if !self.delegated_events.contains(&event_name) {
+            self.delegated_events.push(event_name);
+        }
+        // TODO: hoist the handlers that don't reference the instance script
+        self.ast.vec([self
+            .ast
+            .statement_expression(self.ast.expression_assignment(
+                self.ast.expression_static_member(
+                    self.ast.expression_identifier_reference(&self.state.node),
+                    &format!("__{event_name}"),
+                ),
+                handler,
+            ))])
+    }
+
+    /// ```js
+    /// $.event("click", button, $.once($.preventDefault(handler)), true);
+    /// ```
+    /// for `on:click|preventDefault|once|capture={handler}`
+    pub(super) fn build_on_directive(&mut self, directive: &mut OnDirective<'a>) -> Statement<'a> {
+        let mut handler = self.build_event_handler(directive.expression.as_mut(), false);
+        for modifier in EVENT_MODIFIERS {
+            if directive.modifiers.contains(&modifier) {
+                handler = self.ast.expression_call_with_atom(
+                    &format!("$.{modifier}"),
+                    self.ast.vec([handler.into()]),
+                );
+            }
+        }
+        let capture = directive.modifiers.contains(&"capture");
+        let passive = if directive.modifiers.contains(&"passive") {
+            Some(true)
+        } else if directive.modifiers.contains(&"nonpassive") {
+            Some(false)
+        } else {
+            None
+        };
+        self.build_event(directive.name, handler, capture, passive)
+    }
+
+    /// ```js
+    /// $.delegate(["click", "input"]);
+    /// ```
+    pub(crate) fn build_delegate(&self) -> Statement<'a> {
+        let events = self.ast.vec_from_iter(
+            self.delegated_events
+                .iter()
+                .map(|event| self.ast.expression_string_literal(*event).into()),
+        );
+        self.ast
+            .statement_expression(self.ast.expression_call_with_atom(
+                "$.delegate",
+                self.ast.vec([self.ast.expression_array(events).into()]),
+            ))
+    }
+
+    /// Wraps the handler in a function so that the expression is evaluated again on each
+    /// event, unless it is a function already. Without expression, the event is forwarded to
+    /// the listeners of the component.
+    pub(super) fn build_event_handler(
+        &mut self,
+        expression: Option<&mut Expression<'a>>,
+        has_call: bool,
+    ) -> Expression<'a> {
+        let Some(expression) = expression else {
+            self.uses_props = true;
+            let bubble = self.ast.expression_call(
+                self.ast.expression_static_member(
+                    self.ast.expression_identifier_reference("$.bubble_event"),
+                    "call",
+                ),
+                self.ast.vec([
+                    self.ast.expression_this().into(),
+                    self.ast.expression_identifier_reference("$$props").into(),
+                    self.ast.expression_identifier_reference("$$args").into(),
+                ]),
+            );
+            return self.ast.expression_function_with_rest(
+                "$$args",
+                self.ast.vec([self.ast.statement_expression(bubble)]),
+            );
+        };
+
+        if matches!(
+            expression,
+            Expression::ArrowFunctionExpression(_) | Expression::FunctionExpression(_)
+        ) {
+            self.visit_expression(expression);
+            return self.ast.move_expression(expression);
+        }
+
+        let is_function = match &*expression {
+            Expression::Identifier(ident) => self
+                .references
+                .get_reference(ident.reference_id())
+                .symbol_id()
+                .is_some_and(|symbol_id| self.symbols.get_binding(symbol_id).is_function()),
+            _ => false,
+        };
+        self.visit_expression(expression);
+        let mut handler = self.ast.move_expression(expression);
+        if is_function {
+            return handler;
+        }
+
+        if has_call {
+            // the handler is only computed again when the state it reads changes
+            let id = self.scopes.generate("event_handler", self.current_scope_id);
+            self.state.init.push(
+                self.ast.statement_var(
+                    self.ast.binding_pattern_identifier(id.as_str()),
+                    self.ast.expression_call_with_atom(
+                        "$.derived",
+                        self.ast.vec([self
+                            .ast
+                            .expression_arrow(
+                                self.ast.vec([]),
+                                self.ast.vec([self.ast.statement_expression(handler)]),
+                            )
+                            .into()]),
+                    ),
+                ),
+            );
+            handler = self.ast.expression_call_with_atom(
+                "$.get",
+                self.ast
+                    .vec([self.ast.expression_identifier_reference(&id).into()]),
+            );
+        }
+
+        // function(...$$args) { handler?.apply(this, $$args); }
+        let apply = self.ast.expression_optional_method_call(
+            handler,
+            "apply",
+            self.ast.vec([
+                self.ast.expression_this().into(),
+                self.ast.expression_identifier_reference("$$args").into(),
+            ]),
+        );
+        self.ast.expression_function_with_rest(
+            "$$args",
+            self.ast.vec([self.ast.statement_expression(apply)]),
+        )
+    }
+
+    /// `$.event("click", node, handler, capture, passive)`
+    fn build_event(
+        &self,
+        event_name: &str,
+        handler: Expression<'a>,
+        capture: bool,
+        passive: Option<bool>,
+    ) -> Statement<'a> {
+        let mut args = self.ast.vec([
+            self.ast.expression_string_literal(event_name).into(),
+            self.ast
+                .expression_identifier_reference(&self.state.node)
+                .into(),
+            handler.into(),
+        ]);
+        if capture || passive.is_some() {
+            let capture = if capture {
+                self.ast.expression_boolean_literal(true)
+            } else {
+                self.ast.expression_void_zero()
+            };
+            args.push(capture.into());
+        }
+        if let Some(passive) = passive {
+            args.push(self.ast.expression_boolean_literal(passive).into());
+        }
+        self.ast
+            .statement_expression(self.ast.expression_call_with_atom("$.event", args))
+    }
+}
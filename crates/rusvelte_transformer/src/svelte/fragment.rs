@@ -0,0 +1,473 @@
+use oxc_allocator::Vec as OxcVec;
+use oxc_span::CompactStr;
+use rusvelte_ast::{
+    ast::*,
+    ast_kind::SvelteAstType,
+    js_ast::{Expression, Statement},
+    visit_mut::{JsVisitMut, VisitMut},
+};
+use rusvelte_utils::{constants::TEMPLATE_FRAGMENT, escape::sanitize_template_string};
+
+use crate::{TransformState, Transformer};
+
+use super::{clean_nodes::CleanNodesReturn, element::is_static_element};
+
+/// How to reach the first node of a sequence of children.
+pub(super) enum ChildAnchor {
+    /// The node is already stored in a variable, e.g. `$$anchor`
+    Identifier(CompactStr),
+    /// `$.first_child(fragment)`
+    FirstChild(CompactStr),
+    /// `$.child(element)`
+    Child(CompactStr),
+}
+
+/// Keeps track of the position while walking through the children of a fragment.
+struct Cursor {
+    prev: ChildAnchor,
+    /// The number of nodes since `prev`
+    skipped: usize,
+}
+
+impl<'a> Transformer<'a> {
+    /// Creates the DOM of a fragment owned by a node of type `parent`.
+    pub(crate) fn transform_fragment(
+        &mut self,
+        parent: SvelteAstType,
+        fragment: &mut Fragment<'a>,
+    ) -> OxcVec<'a, Statement<'a>> {
+        let mut body = self.ast.vec([]);
+        let mut close = None;
+
+        let CleanNodesReturn {
+            hoisted,
+            mut trimmed,
+            is_standalone,
+            is_text_first,
+        } = self.clean_nodes(parent, "", fragment);
+
+        if hoisted.is_empty() && trimmed.is_empty() {
+            return body;
+        }
+
+        let parent_scope_id = self.current_scope_id;
+        if let Some(scope_id) = fragment.scope_id.get() {
+            self.current_scope_id = scope_id;
+        }
+        let parent_state = std::mem::replace(&mut self.state, TransformState::new(self.allocator));
+
+        // TODO: visit the other hoisted nodes
+        for node in hoisted {
+            match node {
+                FragmentNode::Block(Block::SnippetBlock(mut snippet)) => {
+                    let declaration = self.transform_snippet_block(&mut snippet);
+                    // top-level snippets are hoisted so they can be referenced in the `<script>`
+                    if parent == SvelteAstType::Fragment {
+                        self.instance_level_snippets.push(declaration);
+                    } else {
+                        self.state.init.push(declaration);
+                    }
+                }
+                FragmentNode::Tag(Tag::ConstTag(mut tag)) => {
+                    // declared first so that the whole fragment can read them
+                    body.extend(self.transform_const_tag(&mut tag));
+                }
+                FragmentNode::Tag(Tag::DebugTag(mut tag)) => {
+                    if let Some(statement) = self.transform_debug_tag(&mut tag) {
+                        self.state.init.push(statement);
+                    }
+                }
+                _ => {}
+            }
+        }
+
+        if trimmed.is_empty() {
+            body.extend(self.state.take_init());
+            self.state = parent_state;
+            self.current_scope_id = parent_scope_id;
+            return body;
+        }
+
+        if is_text_first {
+            body.push(
+                self.ast.statement_expression(
+                    self.ast
+                        .expression_call_with_atom("$.next", self.ast.vec([])),
+                ),
+            );
+        }
+
+        let is_single_element = trimmed.len() == 1 && trimmed[0].is_regular_element();
+        if is_single_element {
+            let element = trimmed[0].as_regular_element_mut().unwrap();
+            let id = self.scopes.generate(element.name, self.current_scope_id);
+            let parent_node = std::mem::replace(&mut self.state.node, id.clone());
+            let mut statements = self.transform_regular_element(element);
+            self.state.init.append(&mut statements);
+            self.state.node = parent_node;
+
+            let template_name = self.add_template(0);
+            body.push(
+                self.ast.statement_var(
+                    self.ast.binding_pattern_identifier(id.as_str()),
+                    self.ast
+                        .expression_call_with_atom(&template_name, self.vec([])),
+                ),
+            );
+            close = Some(self.build_append(&id));
+        } else if trimmed.len() == 1 && trimmed[0].is_text() {
+            // special case — we can use `$.text` instead of creating a unique template
+            let text = trimmed[0].as_text().unwrap();
+            let id = self.scopes.generate("text", self.current_scope_id);
+            body.push(
+                self.ast.statement_var(
+                    self.ast.binding_pattern_identifier(id.as_str()),
+                    self.ast.expression_call_with_atom(
+                        "$.text",
+                        self.ast
+                            .vec([self.ast.expression_string_literal(&*text.data).into()]),
+                    ),
+                ),
+            );
+            close = Some(self.build_append(&id));
+        } else {
+            let use_space_template = trimmed.iter().any(FragmentNode::is_expression_tag)
+                && trimmed
+                    .iter()
+                    .all(|node| node.is_expression_tag() || node.is_text());
+            if use_space_template {
+                // special case — we can use `$.text` instead of creating a unique template
+                let id = self.scopes.generate("text", self.current_scope_id);
+                self.process_children(trimmed, ChildAnchor::Identifier(id.clone()));
+                body.push(
+                    self.ast.statement_var(
+                        self.ast.binding_pattern_identifier(id.as_str()),
+                        self.ast
+                            .expression_call_with_atom("$.text", self.ast.vec([])),
+                    ),
+                );
+                close = Some(self.build_append(&id));
+            } else if is_standalone {
+                // no need to create a template, we can just use the existing block's anchor
+                self.process_children(trimmed, ChildAnchor::Identifier("$$anchor".into()));
+            } else {
+                let id = self.scopes.generate("fragment", self.current_scope_id);
+                self.process_children(trimmed, ChildAnchor::FirstChild(id.clone()));
+                let init = if self.state.template.len() == 1 && self.state.template[0] == "<!>" {
+                    // special case — we can use `$.comment` instead of creating a unique template
+                    self.ast
+                        .expression_call_with_atom("$.comment", self.ast.vec([]))
+                } else {
+                    let template_name = self.add_template(TEMPLATE_FRAGMENT);
+                    self.ast
+                        .expression_call_with_atom(&template_name, self.ast.vec([]))
+                };
+                body.push(
+                    self.ast
+                        .statement_var(self.ast.binding_pattern_identifier(id.as_str()), init),
+                );
+                close = Some(self.build_append(&id));
+            }
+        }
+
+        body.extend(self.state.take_init());
+
+        if !self.state.update.is_empty() {
+            let update = self.state.take_update();
+            body.push(self.build_template_effect(update));
+        }
+        body.extend(self.state.take_after_update());
+
+        if let Some(stmt) = close {
+            body.push(stmt);
+        }
+
+        self.state = parent_state;
+        self.current_scope_id = parent_scope_id;
+
+        body
+    }
+
+    /// Processes an array of template nodes, joining sibling text/expression nodes
+    /// (e.g. `{a} b {c}`) into a single update function. Along the way it creates
+    /// corresponding template node references these updates are applied to.
+    pub(super) fn process_children(&mut self, nodes: Vec<FragmentNode<'a>>, initial: ChildAnchor) {
+        let mut cursor = Cursor {
+            prev: initial,
+            skipped: 0,
+        };
+        let mut sequence = vec![];
+
+        for mut node in nodes {
+            if node.is_text() || node.is_expression_tag() {
+                sequence.push(node);
+                continue;
+            }
+
+            if !sequence.is_empty() {
+                self.flush_sequence(std::mem::take(&mut sequence), &mut cursor);
+            }
+
+            if is_static_element(&node) {
+                // a static element is only part of the template, it is never traversed
+                cursor.skipped += 1;
+                let mut statements = self.visit_fragment_node(&mut node);
+                self.state.init.append(&mut statements);
+                continue;
+            }
+
+            let is_controlled = matches!(
+                &node,
+                FragmentNode::Block(Block::EachBlock(block)) if block.is_controlled.get()
+            );
+            if is_controlled {
+                // the each block uses the element as its anchor
+                let mut statements = self.visit_fragment_node(&mut node);
+                self.state.init.append(&mut statements);
+                continue;
+            }
+
+            let name = match &node {
+                FragmentNode::Element(element) => match element.as_ref() {
+                    Element::RegularElement(element) => element.name,
+                    _ => "node",
+                },
+                _ => "node",
+            };
+            let id = self.flush_node(false, name, &mut cursor);
+            let parent_node = std::mem::replace(&mut self.state.node, id);
+            let mut statements = self.visit_fragment_node(&mut node);
+            self.state.init.append(&mut statements);
+            self.state.node = parent_node;
+        }
+
+        if !sequence.is_empty() {
+            self.flush_sequence(sequence, &mut cursor);
+        }
+
+        // if there are trailing static text nodes/elements,
+        // traverse to the last (n - 1) one when hydrating
+        if cursor.skipped > 1 {
+            cursor.skipped -= 1;
+            let node = self.get_node(false, &cursor);
+            self.state.init.push(self.ast.statement_expression(node));
+        }
+    }
+
+    fn flush_sequence(&mut self, sequence: Vec<FragmentNode<'a>>, cursor: &mut Cursor) {
+        if sequence.iter().all(FragmentNode::is_text) {
+            cursor.skipped += 1;
+            self.state.template.push(
+                sequence
+                    .iter()
+                    .filter_map(FragmentNode::as_text)
+                    .map(|text| text.raw.as_str())
+                    .collect(),
+            );
+            return;
+        }
+
+        self.state.template.push(" ".to_string());
+
+        // if this is a standalone `{expression}`, make sure we handle the case where
+        // no text node was created because the expression was empty during SSR
+        let is_text = sequence.len() == 1;
+        let (value, has_state, has_call) = self.build_template_literal(sequence);
+        let id = self.flush_node(is_text, "text", cursor);
+
+        if has_call || has_state {
+            let update = self
+                .ast
+                .statement_expression(self.ast.expression_call_with_atom(
+                    "$.set_text",
+                    self.ast.vec([
+                        self.ast.expression_identifier_reference(&id).into(),
+                        value.into(),
+                    ]),
+                ));
+            if has_call {
+                self.state
+                    .init
+                    .push(self.build_template_effect(self.ast.vec([update])));
+            } else {
+                self.state.update.push(update);
+            }
+        } else {
+            self.state.init.push(
+                self.ast
+                    .statement_expression(self.ast.expression_assignment(
+                        self.ast.expression_static_member(
+                            self.ast.expression_identifier_reference(&id),
+                            "nodeValue",
+                        ),
+                        value,
+                    )),
+            );
+        }
+    }
+
+    /// Declares a variable for the next node unless it is already an identifier.
+    fn flush_node(&mut self, is_text: bool, name: &str, cursor: &mut Cursor) -> CompactStr {
+        let id =
+            match (&cursor.prev, cursor.skipped) {
+                (ChildAnchor::Identifier(id), 0) => id.clone(),
+                _ => {
+                    let expression = self.get_node(is_text, cursor);
+                    let id = self.scopes.generate(name, self.current_scope_id);
+                    self.state.init.push(self.ast.statement_var(
+                        self.ast.binding_pattern_identifier(id.as_str()),
+                        expression,
+                    ));
+                    id
+                }
+            };
+        cursor.prev = ChildAnchor::Identifier(id.clone());
+        // the next node is `$.sibling(id)`
+        cursor.skipped = 1;
+        id
+    }
+
+    fn get_node(&self, is_text: bool, cursor: &Cursor) -> Expression<'a> {
+        if cursor.skipped == 0 {
+            return self.build_child_anchor(&cursor.prev, is_text);
+        }
+
+        let mut args = self
+            .ast
+            .vec([self.build_child_anchor(&cursor.prev, false).into()]);
+        if is_text || cursor.skipped != 1 {
+            args.push(
+                self.ast
+                    .expression_numeric_literal(cursor.skipped as f64)
+                    .into(),
+            );
+        }
+        if is_text {
+            args.push(self.ast.expression_boolean_literal(true).into());
+        }
+        self.ast.expression_call_with_atom("$.sibling", args)
+    }
+
+    fn build_child_anchor(&self, anchor: &ChildAnchor, is_text: bool) -> Expression<'a> {
+        match anchor {
+            ChildAnchor::Identifier(id) => self.ast.expression_identifier_reference(id),
+            ChildAnchor::FirstChild(id) | ChildAnchor::Child(id) => {
+                let mut args = self
+                    .ast
+                    .vec([self.ast.expression_identifier_reference(id).into()]);
+                if is_text {
+                    args.push(self.ast.expression_boolean_literal(true).into());
+                }
+                let callee = if matches!(anchor, ChildAnchor::Child(_)) {
+                    "$.child"
+                } else {
+                    "$.first_child"
+                };
+                self.ast.expression_call_with_atom(callee, args)
+            }
+        }
+    }
+
+    /// Joins a sequence of text and expression tags into a single value,
+    /// returning it along with whether it `has_state` and `has_call`.
+    fn build_template_literal(
+        &mut self,
+        sequence: Vec<FragmentNode<'a>>,
+    ) -> (Expression<'a>, bool, bool) {
+        let is_single_expression = sequence.len() == 1;
+        let mut has_state = false;
+        let mut has_call = false;
+        let mut quasis = vec![String::new()];
+        let mut expressions = self.ast.vec([]);
+
+        for node in sequence {
+            match node {
+                FragmentNode::Text(text) => quasis
+                    .last_mut()
+                    .unwrap()
+                    .push_str(&sanitize_template_string(&text.data)),
+                FragmentNode::Tag(Tag::ExpressionTag(mut tag)) => {
+                    if let Some(value) = tag.get_static_value() {
+                        quasis
+                            .last_mut()
+                            .unwrap()
+                            .push_str(&sanitize_template_string(value));
+                        continue;
+                    }
+                    {
+                        let metadata = tag.expression_metadata.borrow();
+                        has_state |= metadata.has_state;
+                        has_call |= metadata.has_call;
+                    }
+
+                    self.visit_expression(&mut tag.expression);
+                    let expression = self.ast.move_expression(&mut tag.expression);
+                    if is_single_expression {
+                        return (expression, has_state, has_call);
+                    }
+                    expressions.push(self.ast.expression_call_with_atom(
+                        "$.stringify",
+                        self.ast.vec([expression.into()]),
+                    ));
+                    quasis.push(String::new());
+                }
+                _ => unreachable!("a sequence only contains text and expression tags"),
+            }
+        }
+
+        (
+            self.ast.expression_template_literal(&quasis, expressions),
+            has_state,
+            has_call,
+        )
+    }
+
+    /// ```js
+    /// var root = $.template(`...`, flags);
+    /// ```
+    fn add_template(&mut self, flags: u8) -> CompactStr {
+        let template = sanitize_template_string(&self.state.template.concat());
+        let mut args = self.ast.vec([self
+            .ast
+            .expression_template_literal(&[template], self.ast.vec([]))
+            .into()]);
+        if flags != 0 {
+            args.push(self.ast.expression_numeric_literal(flags as f64).into());
+        }
+        // the name is only reserved once the template is hoisted, so that there are no gaps
+        let name = self.scopes.unique("root");
+        let stmt = self.ast.statement_var(
+            self.ast.binding_pattern_identifier(name.as_str()),
+            self.ast.expression_call_with_atom("$.template", args),
+        );
+        self.hoisted.push(stmt);
+        name
+    }
+
+    /// ```js
+    /// $.template_effect(() => { ... });
+    /// ```
+    pub(super) fn build_template_effect(&self, update: OxcVec<'a, Statement<'a>>) -> Statement<'a> {
+        self.ast.statement_expression(
+            self.ast.expression_call_with_atom(
+                "$.template_effect",
+                self.ast
+                    .vec([self.ast.expression_arrow(self.ast.vec([]), update).into()]),
+            ),
+        )
+    }
+
+    /// ```js
+    /// $.append($$anchor, id);
+    /// ```
+    fn build_append(&self, id: &str) -> Statement<'a> {
+        self.ast
+            .statement_expression(self.ast.expression_call_with_atom(
+                "$.append",
+                self.ast.vec([
+                    self.ast.expression_identifier_reference("$$anchor").into(),
+                    self.ast.expression_identifier_reference(id).into(),
+                ]),
+            ))
+    }
+}
@@ -0,0 +1,136 @@
+use oxc_allocator::Vec as OxcVec;
+use rusvelte_ast::{
+    ast::*,
+    ast_kind::SvelteAstType,
+    js_ast::{BindingPatternKind, LogicalOperator, Statement},
+    visit_mut::JsVisitMut,
+};
+
+use crate::{ReadTransform, Transformer};
+
+impl<'a> Transformer<'a> {
+    /// ```js
+    /// const snippet = ($$anchor, a = $.noop, $$arg1) => { ... };
+    /// ```
+    pub(crate) fn transform_snippet_block(&mut self, it: &mut SnippetBlock<'a>) -> Statement<'a> {
+        let parent_scope_id = self.current_scope_id;
+        if let Some(scope_id) = it.scope_id.get() {
+            self.current_scope_id = scope_id;
+        }
+
+        let mut params = self.ast.vec([self
+            .ast
+            .formal_parameter(self.ast.binding_pattern_identifier("$$anchor"))]);
+        let mut body = self.ast.vec([]);
+        // every argument is passed as a thunk
+        for (i, parameter) in it.parameters.iter().enumerate() {
+            if let BindingPatternKind::BindingIdentifier(ident) = &parameter.kind {
+                params.push(
+                    self.ast
+                        .formal_parameter(self.ast.binding_pattern_assignment(
+                            ident.name.as_str(),
+                            self.ast.expression_identifier_reference("$.noop"),
+                        )),
+                );
+                if let Some(symbol_id) = ident.symbol_id.get() {
+                    self.read_transforms.insert(symbol_id, ReadTransform::Call);
+                }
+                continue;
+            }
+
+            let alias = format!("$$arg{i}");
+            params.push(
+                self.ast
+                    .formal_parameter(self.ast.binding_pattern_identifier(alias.as_str())),
+            );
+            let value = self.ast.expression_optional_call(
+                self.ast.expression_identifier_reference(&alias),
+                self.ast.vec([]),
+            );
+            self.build_destructured_declarations(parameter, value, &mut body);
+        }
+        body.extend(self.transform_fragment(SvelteAstType::SnippetBlock, &mut it.body));
+        self.current_scope_id = parent_scope_id;
+
+        let mut snippet = self.ast.expression_arrow(params, body);
+        if self.options.dev {
+            snippet = self.ast.expression_call_with_atom(
+                "$.wrap_snippet",
+                self.ast.vec([
+                    self.ast.expression_identifier_reference(&self.name).into(),
+                    snippet.into(),
+                ]),
+            );
+        }
+        self.ast.statement_const(
+            self.ast
+                .binding_pattern_identifier(it.expression.name.as_str()),
+            snippet,
+        )
+    }
+
+    /// ```js
+    /// snippet(node, () => arg);
+    /// // when the snippet may change
+    /// $.snippet(node, () => snippet, () => arg);
+    /// ```
+    pub(crate) fn transform_render_tag(
+        &mut self,
+        it: &mut RenderTag<'a>,
+    ) -> OxcVec<'a, Statement<'a>> {
+        self.state.template.push("<!>".to_string());
+        let is_optional = matches!(it.expression, RenderTagExpression::ChainExpression(_));
+        let call = it.expression.call_mut();
+
+        let mut args = vec![];
+        for argument in call.arguments.iter_mut() {
+            // spread arguments are not allowed in a render tag
+            let Some(argument) = argument.as_expression_mut() else {
+                continue;
+            };
+            self.visit_expression(argument);
+            let argument = self.ast.move_expression(argument);
+            args.push(self.ast.expression_arrow(
+                self.ast.vec([]),
+                self.ast.vec([self.ast.statement_expression(argument)]),
+            ));
+        }
+        self.visit_expression(&mut call.callee);
+        let mut snippet_function = self.ast.move_expression(&mut call.callee);
+
+        let node = self.ast.expression_identifier_reference(&self.state.node);
+        let expression = if it.dynamic.get() {
+            if is_optional {
+                // a nullish snippet renders nothing
+                snippet_function = self.ast.expression_logical(
+                    snippet_function,
+                    LogicalOperator::Coalesce,
+                    self.ast.expression_identifier_reference("$.noop"),
+                );
+            }
+            let snippet_function = self.ast.expression_arrow(
+                self.ast.vec([]),
+                self.ast
+                    .vec([self.ast.statement_expression(snippet_function)]),
+            );
+            let args = self.ast.vec_from_iter(
+                [node, snippet_function]
+                    .into_iter()
+                    .chain(args)
+                    .map(Into::into),
+            );
+            self.ast.expression_call_with_atom("$.snippet", args)
+        } else {
+            let args = self
+                .ast
+                .vec_from_iter(std::iter::once(node).chain(args).map(Into::into));
+            if is_optional {
+                self.ast.expression_optional_call(snippet_function, args)
+            } else {
+                self.ast.expression_call(snippet_function, args)
+            }
+        };
+
+        self.ast.vec([self.ast.statement_expression(expression)])
+    }
+}
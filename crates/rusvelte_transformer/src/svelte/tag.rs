@@ -0,0 +1,166 @@
+use oxc_allocator::{CloneIn, Vec as OxcVec};
+use oxc_ecmascript::BoundNames;
+use rusvelte_analyzer::Namespace;
+use rusvelte_ast::{
+    ast::*,
+    js_ast::{BindingPatternKind, Expression, Statement},
+    visit_mut::JsVisitMut,
+};
+
+use crate::{ReadTransform, Transformer};
+
+impl<'a> Transformer<'a> {
+    /// ```js
+    /// $.html(node, () => content, false, false);
+    /// ```
+    /// The last two arguments tell whether the content is SVG or MathML.
+    pub(super) fn transform_html_tag(
+        &mut self,
+        tag: &mut HtmlTag<'a>,
+    ) -> OxcVec<'a, Statement<'a>> {
+        self.state.template.push("<!>".to_string());
+        self.visit_expression(&mut tag.expression);
+        let content = self.ast.move_expression(&mut tag.expression);
+        let html = self.ast.expression_call_with_atom(
+            "$.html",
+            self.ast.vec([
+                self.ast
+                    .expression_identifier_reference(&self.state.node)
+                    .into(),
+                self.build_thunk(&[], content).into(),
+                self.ast
+                    .expression_boolean_literal(self.namespace == Namespace::Svg)
+                    .into(),
+                self.ast
+                    .expression_boolean_literal(self.namespace == Namespace::Mathml)
+                    .into(),
+            ]),
+        );
+        self.ast.vec([self.ast.statement_expression(html)])
+    }
+
+    /// ```js
+    /// const double = $.derived(() => $.get(count) * 2);
+    /// ```
+    /// The destructured bindings are read from a single derived object:
+    /// ```js
+    /// const computed_const = $.derived(() => {
+    ///     const { a, b } = $.get(object);
+    ///     return { a, b };
+    /// });
+    /// ```
+    pub(super) fn transform_const_tag(
+        &mut self,
+        tag: &mut ConstTag<'a>,
+    ) -> OxcVec<'a, Statement<'a>> {
+        let declarator = tag
+            .declaration
+            .declarations
+            .first_mut()
+            .expect("a `{@const}` tag declares a single variable");
+        let mut init = declarator
+            .init
+            .take()
+            .expect("the parser rejects a `{@const}` tag without initializer");
+        self.visit_expression(&mut init);
+
+        let mut result = self.ast.vec([]);
+        if let BindingPatternKind::BindingIdentifier(ident) = &declarator.id.kind {
+            if let Some(symbol_id) = ident.symbol_id.get() {
+                self.read_transforms.insert(symbol_id, ReadTransform::Get);
+            }
+            let derived = self.build_derived(self.ast.expression_arrow(
+                self.ast.vec([]),
+                self.ast.vec([self.ast.statement_expression(init)]),
+            ));
+            result.push(self.ast.statement_const(
+                self.ast.binding_pattern_identifier(ident.name.as_str()),
+                derived,
+            ));
+            if self.options.dev {
+                // evaluate it eagerly so that errors are reported where the tag is
+                result.push(self.build_const_get(ident.name.as_str()));
+            }
+            return result;
+        }
+
+        let id = self
+            .scopes
+            .generate("computed_const", self.current_scope_id);
+        let mut names = vec![];
+        declarator.id.bound_names(&mut |ident| {
+            if let Some(symbol_id) = ident.symbol_id.get() {
+                self.read_transforms
+                    .insert(symbol_id, ReadTransform::ConstMember(id.clone()));
+            }
+            names.push(ident.name);
+        });
+        let object = self.ast.expression_object(
+            self.ast.vec_from_iter(
+                names
+                    .iter()
+                    .map(|name| self.ast.object_property_shorthand(&name)),
+            ),
+        );
+        let body = self.ast.vec([
+            self.ast
+                .statement_const(declarator.id.clone_in(self.allocator), init),
+            self.ast.statement_return(object),
+        ]);
+        let derived = self.build_derived(self.ast.expression_arrow(self.ast.vec([]), body));
+        result.push(
+            self.ast
+                .statement_const(self.ast.binding_pattern_identifier(id.as_str()), derived),
+        );
+        if self.options.dev {
+            result.push(self.build_const_get(&id));
+        }
+        result
+    }
+
+    /// ```js
+    /// $.template_effect(() => {
+    ///     console.log({ count: $.get(count), name });
+    ///     debugger;
+    /// });
+    /// ```
+    /// Only in dev mode, the tag is dropped otherwise.
+    pub(super) fn transform_debug_tag(&mut self, tag: &mut DebugTag<'a>) -> Option<Statement<'a>> {
+        if !self.options.dev {
+            return None;
+        }
+        let mut properties = self.ast.vec([]);
+        for ident in tag.identifiers.drain(..) {
+            let name = ident.name.clone();
+            let mut value = Expression::Identifier(self.ast.alloc(ident));
+            self.visit_expression(&mut value);
+            let property = match &value {
+                Expression::Identifier(value) if value.name == name => {
+                    self.ast.object_property_shorthand(&name)
+                }
+                _ => self.ast.object_property(&name, value),
+            };
+            properties.push(property);
+        }
+        let log = self.ast.expression_call_with_atom(
+            "console.log",
+            self.ast
+                .vec([self.ast.expression_object(properties).into()]),
+        );
+        Some(self.build_template_effect(self.ast.vec([
+            self.ast.statement_expression(log),
+            self.ast.statement_debugger(),
+        ])))
+    }
+
+    /// `$.get(name);`
+    fn build_const_get(&self, name: &str) -> Statement<'a> {
+        self.ast.statement_expression(
+            self.ast.expression_call_with_atom(
+                "$.get",
+                self.ast
+                    .vec([self.ast.expression_identifier_reference(name).into()]),
+            ),
+        )
+    }
+}
@@ -0,0 +1,75 @@
+use rusvelte_ast::{
+    ast::*,
+    js_ast::{Expression, Statement},
+    visit_mut::JsVisitMut,
+};
+use rusvelte_utils::constants::{TRANSITION_GLOBAL, TRANSITION_IN, TRANSITION_OUT};
+
+use crate::Transformer;
+
+impl<'a> Transformer<'a> {
+    /// ```js
+    /// $.transition(3, div, () => fade, () => ({ duration: 200 }));
+    /// ```
+    pub(super) fn build_transition_directive(
+        &mut self,
+        directive: &mut TransitionDirective<'a>,
+    ) -> Statement<'a> {
+        let mut flags = 0;
+        if directive.modifiers.contains(&TransitionModifier::Global) {
+            flags |= TRANSITION_GLOBAL;
+        }
+        if directive.intro {
+            flags |= TRANSITION_IN;
+        }
+        if directive.outro {
+            flags |= TRANSITION_OUT;
+        }
+
+        let transition = self.build_directive_name(directive.name);
+        let mut args = self.ast.vec([
+            self.ast.expression_numeric_literal(flags as f64).into(),
+            self.ast
+                .expression_identifier_reference(&self.state.node)
+                .into(),
+            self.build_thunk(&[], transition).into(),
+        ]);
+        if let Some(expression) = directive.expression.as_mut() {
+            self.visit_expression(expression);
+            let params = self.ast.move_expression(expression);
+            args.push(self.build_thunk(&[], params).into());
+        }
+        self.ast
+            .statement_expression(self.ast.expression_call_with_atom("$.transition", args))
+    }
+
+    /// ```js
+    /// $.animation(li, () => flip, () => ({ duration: 200 }));
+    /// ```
+    pub(super) fn build_animate_directive(
+        &mut self,
+        directive: &mut AnimateDirective<'a>,
+    ) -> Statement<'a> {
+        let animation = self.build_directive_name(directive.name);
+        let params = match directive.expression.as_mut() {
+            Some(expression) => {
+                self.visit_expression(expression);
+                let params = self.ast.move_expression(expression);
+                self.build_thunk(&[], params)
+            }
+            None => self.ast.expression_null(),
+        };
+        self.ast.statement_expression(
+            self.ast.expression_call_with_atom(
+                "$.animation",
+                self.ast.vec([
+                    self.ast
+                        .expression_identifier_reference(&self.state.node)
+                        .into(),
+                    self.build_thunk(&[], animation).into(),
+                    params.into(),
+                ]),
+            ),
+        )
+    }
+}
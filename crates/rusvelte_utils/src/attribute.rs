@@ -0,0 +1,44 @@
+use crate::boolean_attribute::is_boolean_attribute;
+
+/// The attributes whose property has a different casing than the lowercased attribute
+const ATTRIBUTE_ALIASES: [(&str, &str); 12] = [
+    ("formnovalidate", "formNoValidate"),
+    ("ismap", "isMap"),
+    ("nomodule", "noModule"),
+    ("playsinline", "playsInline"),
+    ("readonly", "readOnly"),
+    ("defaultvalue", "defaultValue"),
+    ("defaultchecked", "defaultChecked"),
+    ("srcobject", "srcObject"),
+    ("novalidate", "noValidate"),
+    ("allowfullscreen", "allowFullscreen"),
+    ("disablepictureinpicture", "disablePictureInPicture"),
+    ("disableremoteplayback", "disableRemotePlayback"),
+];
+
+/// The attributes that are set through their property instead of `setAttribute`
+const DOM_PROPERTIES: [&str; 5] = [
+    "value",
+    "volume",
+    "defaultValue",
+    "defaultChecked",
+    "srcObject",
+];
+
+/// Lowercases an HTML attribute name, e.g. `readOnly` for `READONLY`
+pub fn normalize_attribute(name: &str) -> String {
+    let name = name.to_lowercase();
+    ATTRIBUTE_ALIASES
+        .iter()
+        .find(|(alias, _)| *alias == name)
+        .map_or(name, |(_, property)| property.to_string())
+}
+
+/// Whether the normalized attribute is set through the property of the same name
+pub fn is_dom_property(name: &str) -> bool {
+    is_boolean_attribute(name)
+        || DOM_PROPERTIES.contains(&name)
+        || ATTRIBUTE_ALIASES
+            .iter()
+            .any(|(_, property)| *property == name)
+}
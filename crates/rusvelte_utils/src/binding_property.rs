@@ -0,0 +1,55 @@
+/// A property that can be bound with `bind:`
+#[derive(Debug, Clone, Copy)]
+pub struct BindingProperty {
+    /// The elements the property can be bound on, any element if `None`
+    pub valid_elements: Option<&'static [&'static str]>,
+    /// The event notifying a change of the property, if it has no dedicated helper
+    pub event: Option<&'static str>,
+    /// Whether the property is also written to the element when the bound value changes
+    pub bidirectional: bool,
+}
+
+const MEDIA: &[&str] = &["audio", "video"];
+const WINDOW: &[&str] = &["svelte:window"];
+const DOCUMENT: &[&str] = &["svelte:document"];
+
+pub fn get_binding_property(name: &str) -> Option<BindingProperty> {
+    let (valid_elements, event, bidirectional) = match name {
+        // media
+        "currentTime" | "paused" | "buffered" | "seekable" | "played" | "volume" | "muted"
+        | "playbackRate" | "seeking" | "ended" | "readyState" => (Some(MEDIA), None, false),
+        "duration" => (Some(MEDIA), Some("durationchange"), false),
+        "videoHeight" | "videoWidth" => (Some(&["video"][..]), Some("resize"), false),
+        "naturalWidth" | "naturalHeight" => (Some(&["img"][..]), Some("load"), false),
+        // document
+        "activeElement" => (Some(DOCUMENT), None, false),
+        "fullscreenElement" => (Some(DOCUMENT), Some("fullscreenchange"), false),
+        "pointerLockElement" => (Some(DOCUMENT), Some("pointerlockchange"), false),
+        "visibilityState" => (Some(DOCUMENT), Some("visibilitychange"), false),
+        // window
+        "innerWidth" | "innerHeight" | "outerWidth" | "outerHeight" | "scrollX" | "scrollY"
+        | "online" | "devicePixelRatio" => (Some(WINDOW), None, false),
+        // dimensions
+        "clientWidth"
+        | "clientHeight"
+        | "offsetWidth"
+        | "offsetHeight"
+        | "contentRect"
+        | "contentBoxSize"
+        | "borderBoxSize"
+        | "devicePixelContentBoxSize" => (None, None, false),
+        // form elements
+        "indeterminate" => (Some(&["input"][..]), Some("change"), true),
+        "checked" | "group" | "files" => (Some(&["input"][..]), None, false),
+        "value" => (Some(&["input", "textarea", "select"][..]), None, false),
+        "open" => (Some(&["details"][..]), Some("toggle"), true),
+        // various
+        "focused" | "this" | "innerText" | "innerHTML" | "textContent" => (None, None, false),
+        _ => return None,
+    };
+    Some(BindingProperty {
+        valid_elements,
+        event,
+        bidirectional,
+    })
+}
@@ -1,2 +1,20 @@
 pub const NAMESPACE_SVG: &str = "http://www.w3.org/2000/svg";
 pub const NAMESPACE_MATHML: &str = "http://www.w3.org/1998/Math/MathML";
+
+pub const TEMPLATE_FRAGMENT: u8 = 1;
+
+pub const EACH_ITEM_REACTIVE: u8 = 1;
+pub const EACH_INDEX_REACTIVE: u8 = 1 << 1;
+pub const EACH_IS_CONTROLLED: u8 = 1 << 2;
+pub const EACH_IS_ANIMATED: u8 = 1 << 3;
+pub const EACH_ITEM_IMMUTABLE: u8 = 1 << 4;
+
+pub const TRANSITION_IN: u8 = 1;
+pub const TRANSITION_OUT: u8 = 1 << 1;
+pub const TRANSITION_GLOBAL: u8 = 1 << 2;
+
+pub const PROPS_IS_IMMUTABLE: u8 = 1;
+pub const PROPS_IS_RUNES: u8 = 1 << 1;
+pub const PROPS_IS_UPDATED: u8 = 1 << 2;
+pub const PROPS_IS_BINDABLE: u8 = 1 << 3;
+pub const PROPS_IS_LAZY_INITIAL: u8 = 1 << 4;
@@ -0,0 +1,29 @@
+/// Escapes the characters that would otherwise be interpreted as markup, the same as
+/// the `escape_html` of Svelte. Attribute values only need `&` and `"` to be escaped.
+pub fn escape_html(value: &str, is_attr: bool) -> String {
+    let mut result = String::with_capacity(value.len());
+    for ch in value.chars() {
+        match ch {
+            '&' => result.push_str("&amp;"),
+            '"' if is_attr => result.push_str("&quot;"),
+            '<' if !is_attr => result.push_str("&lt;"),
+            _ => result.push(ch),
+        }
+    }
+    result
+}
+
+/// Escapes the characters that have special meanings inside a template literal.
+pub fn sanitize_template_string(value: &str) -> String {
+    let mut result = String::with_capacity(value.len());
+    let mut chars = value.chars().peekable();
+    while let Some(ch) = chars.next() {
+        match ch {
+            '\\' => result.push_str("\\\\"),
+            '`' => result.push_str("\\`"),
+            '$' if chars.peek() == Some(&'{') => result.push_str("\\$"),
+            _ => result.push(ch),
+        }
+    }
+    result
+}
@@ -0,0 +1,43 @@
+/// The events listened to once on the root of the app, the handlers are looked up on the
+/// elements by the listener
+const DELEGATED_EVENTS: [&str; 23] = [
+    "beforeinput",
+    "click",
+    "change",
+    "dblclick",
+    "contextmenu",
+    "focusin",
+    "focusout",
+    "input",
+    "keydown",
+    "keyup",
+    "mousedown",
+    "mousemove",
+    "mouseout",
+    "mouseover",
+    "mouseup",
+    "pointerdown",
+    "pointermove",
+    "pointerout",
+    "pointerover",
+    "pointerup",
+    "touchend",
+    "touchmove",
+    "touchstart",
+];
+
+/// The events that are listened to passively by default
+const PASSIVE_EVENTS: [&str; 2] = ["touchstart", "touchmove"];
+
+pub fn is_delegated_event(name: &str) -> bool {
+    DELEGATED_EVENTS.contains(&name)
+}
+
+pub fn is_passive_event(name: &str) -> bool {
+    PASSIVE_EVENTS.contains(&name)
+}
+
+/// Whether the event attribute listens during the capture phase, e.g. `onclickcapture`
+pub fn is_capture_event(name: &str) -> bool {
+    name.ends_with("capture") && name != "gotpointercapture" && name != "lostpointercapture"
+}
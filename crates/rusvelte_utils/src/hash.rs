@@ -0,0 +1,31 @@
+use std::sync::LazyLock;
+
+use regex::Regex;
+
+static REGEX_RETURN_CHARACTERS: LazyLock<Regex> = LazyLock::new(|| Regex::new(r"\r").unwrap());
+
+/// The same hash function as Svelte's, so that the generated css hashes are interchangeable.
+pub fn hash(s: &str) -> String {
+    let s = REGEX_RETURN_CHARACTERS.replace_all(s, "");
+    let mut hash: i32 = 5381;
+    for code in s.encode_utf16().collect::<Vec<_>>().into_iter().rev() {
+        hash = hash.wrapping_shl(5).wrapping_sub(hash) ^ code as i32;
+    }
+
+    to_base36(hash as u32)
+}
+
+fn to_base36(mut value: u32) -> String {
+    const DIGITS: &[u8] = b"0123456789abcdefghijklmnopqrstuvwxyz";
+    if value == 0 {
+        return "0".to_string();
+    }
+
+    let mut result = vec![];
+    while value > 0 {
+        result.push(DIGITS[(value % 36) as usize]);
+        value /= 36;
+    }
+    result.reverse();
+    String::from_utf8(result).unwrap()
+}
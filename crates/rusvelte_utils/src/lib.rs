@@ -1,4 +1,10 @@
+pub mod attribute;
+pub mod binding_property;
+pub mod boolean_attribute;
 pub mod constants;
+pub mod escape;
+pub mod event;
+pub mod hash;
 pub mod html_tree_validation;
 pub mod regex_pattern;
 pub mod special_element;